@@ -0,0 +1,305 @@
+use crate::i18n::{Message, month_name, weekday_name};
+use crate::pager::outln;
+use crate::render::{Panel, ascii, brick_dot, grid_panel, paint, print_panels, terminal_width};
+use ansi_term::Color;
+use chrono::{Datelike, NaiveDate};
+use solver::{
+    Board, Brick, DateResult, StablePlacement, count_solutions, dates_with_few_solutions,
+    placement_stability, solve_dates,
+};
+use std::collections::BTreeMap;
+
+fn dates_of(year: i32) -> impl Iterator<Item = NaiveDate> {
+    NaiveDate::from_ymd_opt(year, 1, 1)
+        .unwrap()
+        .iter_days()
+        .take_while(move |date| date.year() == year)
+}
+
+fn solution_counts(year: i32) -> Vec<(NaiveDate, usize)> {
+    let bricks = Brick::all_bricks();
+    dates_of(year)
+        .map(|date| {
+            let board = Board::for_date(date.day() as u8, date.month() as u8).unwrap();
+            (date, count_solutions(board, &bricks))
+        })
+        .collect()
+}
+
+// Every date of the year with its solution count and search effort.
+fn date_results(year: i32) -> Vec<(NaiveDate, DateResult)> {
+    let dates: Vec<NaiveDate> = dates_of(year).collect();
+    let month_days: Vec<(u8, u8)> = dates
+        .iter()
+        .map(|date| (date.month() as u8, date.day() as u8))
+        .collect();
+    dates
+        .into_iter()
+        .zip(solve_dates(&month_days, &Brick::all_bricks()))
+        .collect()
+}
+
+// The dates with the fewest and most solutions, and the ones the search tested the most and
+// fewest boards for, `top` of each.
+pub fn run_rank(year: i32, top: usize, csv: bool) {
+    let results = date_results(year);
+    let mut by_solutions = results.clone();
+    by_solutions.sort_by_key(|(date, result)| (result.solutions, *date));
+    let mut by_effort = results;
+    by_effort.sort_by_key(|(date, result)| (result.stats.test_count, *date));
+    let rankings = [
+        (
+            "fewest_solutions",
+            Message::FewestSolutions,
+            by_solutions.clone(),
+        ),
+        (
+            "most_solutions",
+            Message::MostSolutions,
+            by_solutions.into_iter().rev().collect(),
+        ),
+        (
+            "most_boards_tested",
+            Message::MostBoardsTested,
+            by_effort.iter().rev().cloned().collect(),
+        ),
+        (
+            "fewest_boards_tested",
+            Message::FewestBoardsTested,
+            by_effort,
+        ),
+    ];
+    if csv {
+        outln!("ranking,rank,date,solutions,test_count");
+    }
+    for (key, title, ranked) in rankings {
+        if !csv {
+            outln!("\n{title}:");
+        }
+        for (rank, (date, result)) in ranked.iter().take(top).enumerate() {
+            let (solutions, test_count) = (result.solutions, result.stats.test_count);
+            if csv {
+                outln!("{key},{},{date},{solutions},{test_count}", rank + 1);
+            } else {
+                outln!(
+                    "{}",
+                    Message::RankedDate {
+                        rank: rank + 1,
+                        date: *date,
+                        solutions,
+                        test_count
+                    }
+                );
+            }
+        }
+    }
+}
+
+// The dates with fewer than `below` solutions, the hard days of the physical puzzle.
+pub fn run_unique(year: i32, below: usize, csv: bool) {
+    let dates: Vec<(u8, u8)> = dates_of(year)
+        .map(|date| (date.month() as u8, date.day() as u8))
+        .collect();
+    let found = dates_with_few_solutions(&dates, below, &Brick::all_bricks());
+    if csv {
+        outln!("date,solutions,test_count");
+    } else {
+        let title = Message::FewSolutionDates {
+            year,
+            below,
+            found: !found.is_empty(),
+        };
+        outln!("{title}");
+        if found.is_empty() {
+            return;
+        }
+    }
+    for result in found {
+        let date = NaiveDate::from_ymd_opt(year, result.month.into(), result.day.into()).unwrap();
+        let (solutions, test_count) = (result.solutions, result.stats.test_count);
+        if csv {
+            outln!("{date},{solutions},{test_count}");
+        } else {
+            outln!(
+                "  {}",
+                Message::FewSolutionDate {
+                    date,
+                    solutions,
+                    test_count
+                }
+            );
+        }
+    }
+}
+
+pub fn run(year: i32, bucket_size: usize, csv: bool) {
+    let counts = solution_counts(year);
+    let buckets = histogram(&counts, bucket_size);
+    let by_month = averages(&counts, |date| (date.month0(), month_name(*date)));
+    let by_weekday = averages(&counts, |date| {
+        (date.weekday().num_days_from_monday(), weekday_name(*date))
+    });
+    if csv {
+        print_csv(&buckets, bucket_size);
+        print_averages_csv("month", &by_month);
+        print_averages_csv("weekday", &by_weekday);
+    } else {
+        print_bars(&buckets, bucket_size);
+        print_averages(false, &by_month);
+        print_averages(true, &by_weekday);
+    }
+}
+
+// The placements that are part of a solution on the most dates of the year, each with the board
+// and a map of the dates it works on.
+pub fn run_stability(year: i32, top: usize, csv: bool) {
+    let bricks = Brick::all_bricks();
+    let dates: Vec<(u8, u8)> = dates_of(year)
+        .map(|date| (date.day() as u8, date.month() as u8))
+        .collect();
+    let stable = placement_stability(&dates, &bricks);
+    if csv {
+        outln!("rank,brick,placement,dates");
+        for (rank, stable) in stable.iter().take(top).enumerate() {
+            let brick = stable.brick + 1;
+            let (placement, count) = (stable.placement, stable.dates.len());
+            outln!("{},{brick},{placement:016x},{count}", rank + 1);
+        }
+        return;
+    }
+    for (rank, stable) in stable.iter().take(top).enumerate() {
+        outln!(
+            "\n{}",
+            Message::StablePlacement {
+                rank: rank + 1,
+                brick: stable.brick + 1,
+                dates: stable.dates.len(),
+                total: dates.len()
+            }
+        );
+        let board = grid_panel(|i| match stable.placement & 1 << 63 >> i {
+            0 => brick_dot(0),
+            _ => brick_dot(stable.brick as u8 + 1),
+        });
+        print_panels(&[board, coverage_map(stable, year)]);
+    }
+}
+
+// A row per month with a mark for each day the placement works on.
+fn coverage_map(stable: &StablePlacement, year: i32) -> Panel {
+    let lines = (1..=12)
+        .map(|month| {
+            let days: String = (1..=31)
+                .map(|day| {
+                    if NaiveDate::from_ymd_opt(year, month.into(), day.into()).is_none() {
+                        ' '
+                    } else if stable.dates.contains(&(day, month)) {
+                        if ascii() { '#' } else { '█' }
+                    } else if ascii() {
+                        '.'
+                    } else {
+                        '·'
+                    }
+                })
+                .collect();
+            format!("{month:>2} {days}")
+        })
+        .collect();
+    Panel { lines, width: 34 }
+}
+
+// Average number of solutions of the dates in each group. The group is given as an order and a
+// name.
+fn averages(
+    counts: &[(NaiveDate, usize)],
+    group: impl Fn(&NaiveDate) -> (u32, String),
+) -> Vec<(String, usize, f64)> {
+    let mut groups: BTreeMap<(u32, String), (usize, usize)> = BTreeMap::new();
+    for (date, count) in counts {
+        let (dates, total) = groups.entry(group(date)).or_default();
+        *dates += 1;
+        *total += count;
+    }
+    groups
+        .into_iter()
+        .map(|((_, name), (dates, total))| (name, dates, total as f64 / dates as f64))
+        .collect()
+}
+
+// Number of dates with a solution count in each range of `bucket_size`, from the lowest count's
+// bucket to the highest.
+fn histogram(counts: &[(NaiveDate, usize)], bucket_size: usize) -> Vec<(usize, usize)> {
+    let bucket = |count: usize| count / bucket_size;
+    let low = counts
+        .iter()
+        .map(|(_, count)| bucket(*count))
+        .min()
+        .unwrap_or(0);
+    let high = counts
+        .iter()
+        .map(|(_, count)| bucket(*count))
+        .max()
+        .unwrap_or(0);
+    (low..=high)
+        .map(|index| {
+            let dates = counts
+                .iter()
+                .filter(|(_, count)| bucket(*count) == index)
+                .count();
+            (index * bucket_size, dates)
+        })
+        .collect()
+}
+
+fn print_csv(buckets: &[(usize, usize)], bucket_size: usize) {
+    outln!("from,to,dates");
+    for (from, dates) in buckets {
+        outln!("{from},{},{dates}", from + bucket_size - 1);
+    }
+}
+
+fn print_averages_csv(group: &str, averages: &[(String, usize, f64)]) {
+    outln!("\n{group},dates,average_solutions");
+    for (name, dates, average) in averages {
+        outln!("{name},{dates},{average:.2}");
+    }
+}
+
+fn print_averages(weekday: bool, averages: &[(String, usize, f64)]) {
+    let width = averages
+        .iter()
+        .map(|(name, _, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    outln!("\n{}", Message::AveragesHeader { weekday, width });
+    for (name, dates, average) in averages {
+        outln!("{name:<width$}  {dates:>5}  {average:>17.1}");
+    }
+}
+
+fn print_bars(buckets: &[(usize, usize)], bucket_size: usize) {
+    let labels: Vec<String> = buckets
+        .iter()
+        .map(|(from, _)| format!("{from}-{}", from + bucket_size - 1))
+        .collect();
+    let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+    let most = buckets
+        .iter()
+        .map(|(_, dates)| *dates)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    // Room for the label, the bar and the count after it.
+    let bar_room = terminal_width()
+        .unwrap_or(80)
+        .saturating_sub(label_width + 6)
+        .max(10);
+    outln!("{}", Message::HistogramTitle);
+    for (label, (_, dates)) in labels.iter().zip(buckets) {
+        let bar = (if ascii() { "#" } else { "█" }).repeat((dates * bar_room).div_ceil(most));
+        outln!(
+            "{label:>label_width$} {} {dates}",
+            paint(Color::Cyan.normal(), &bar)
+        );
+    }
+}
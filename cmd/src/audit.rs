@@ -0,0 +1,138 @@
+use crate::hooks::exit;
+use crate::i18n::Message;
+use crate::pager::outln;
+use serde::{Deserialize, Serialize};
+use solver::{Board, Brick, all_dates, solve};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// One line of a dataset: every solution of a date, each written like its `Display` with the rows
+// separated by / instead of new lines, so the order the search finds them in doesn't matter.
+#[derive(Serialize, Deserialize)]
+struct DateSolutions {
+    month: u8,
+    day: u8,
+    solutions: BTreeSet<String>,
+}
+
+type Dataset = BTreeMap<(u8, u8), BTreeSet<String>>;
+
+fn solve_all() -> Dataset {
+    let bricks = Brick::all_bricks();
+    all_dates()
+        .map(|(month, day)| {
+            let board = Board::for_date(day, month).unwrap();
+            let solutions = solve(board, &bricks)
+                .map(|solution| solution.to_string().trim_end().replace('\n', "/"))
+                .collect();
+            ((month, day), solutions)
+        })
+        .collect()
+}
+
+// Writes the solutions of every date as JSON lines, to compare later versions against.
+pub fn export(path: &Path) {
+    let dataset = solve_all();
+    let result = File::create(path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        for ((month, day), solutions) in dataset {
+            let line = DateSolutions {
+                month,
+                day,
+                solutions,
+            };
+            serde_json::to_writer(&mut writer, &line)?;
+            writeln!(writer)?;
+        }
+        writer.flush()
+    });
+    if let Err(error) = result {
+        fail(Message::CouldNotWrite {
+            path: &path.display().to_string(),
+            error: &error,
+        });
+    }
+    outln!("{}", Message::DatasetWritten(&path.display().to_string()));
+}
+
+// Compares the solutions found now with a dataset from `export`, listing the solutions added and
+// missing per date. Exits with 1 when anything changed.
+pub fn against(path: &Path) {
+    let expected = read(path).unwrap_or_else(|error| {
+        fail(Message::CouldNotRead {
+            path: &path.display().to_string(),
+            error: &error,
+        })
+    });
+    let found = solve_all();
+    let empty = BTreeSet::new();
+    let (mut added, mut missing, mut changed) = (0, 0, 0);
+    let dates: BTreeSet<&(u8, u8)> = found.keys().chain(expected.keys()).collect();
+    for date in &dates {
+        let now = found.get(date).unwrap_or(&empty);
+        let before = expected.get(date).unwrap_or(&empty);
+        if now == before {
+            continue;
+        }
+        changed += 1;
+        let (month, day) = date;
+        outln!(
+            "{}",
+            Message::SolutionsChanged {
+                month: *month,
+                day: *day,
+                before: before.len(),
+                now: now.len()
+            }
+        );
+        for solution in now.difference(before) {
+            added += 1;
+            outln!("  + {solution}");
+        }
+        for solution in before.difference(now) {
+            missing += 1;
+            outln!("  - {solution}");
+        }
+    }
+    outln!(
+        "{}",
+        Message::DatasetCompared {
+            dates: dates.len(),
+            changed,
+            added,
+            missing
+        }
+    );
+    if changed > 0 {
+        exit(1);
+    }
+}
+
+fn read(path: &Path) -> io::Result<Dataset> {
+    let mut dataset = Dataset::new();
+    for (number, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let date: DateSolutions = serde_json::from_str(&line).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                Message::DatasetLine {
+                    number: number + 1,
+                    error: &error,
+                }
+                .to_string(),
+            )
+        })?;
+        dataset.insert((date.month, date.day), date.solutions);
+    }
+    Ok(dataset)
+}
+
+fn fail(message: Message) -> ! {
+    eprintln!("{}", Message::Error(&message));
+    exit(1);
+}
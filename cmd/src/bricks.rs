@@ -0,0 +1,162 @@
+use crate::i18n::Message;
+use crate::input;
+use serde::Deserialize;
+use solver::Brick;
+use std::path::Path;
+use toml::Spanned;
+
+// The solver keeps the bricks left to place in a 32 bit mask.
+const MAX_BRICKS: usize = 32;
+
+// A brick set file, with a [[brick]] table per piece giving its rows of X for cells and . for
+// gaps:
+//
+//     [[brick]]
+//     name = "T"
+//     shape = ["XXX", ".X."]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BrickSet {
+    brick: Vec<BrickEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BrickEntry {
+    name: Option<String>,
+    shape: Spanned<Vec<String>>,
+}
+
+// Reads the bricks to solve with instead of the standard set. Shapes that are no bricks are
+// reported with the line they start on.
+pub fn load(path: &Path) -> Result<Box<[Brick]>, String> {
+    let shown = path.display().to_string();
+    let content = input::read_to_string(path).map_err(|error| {
+        Message::CouldNotRead {
+            path: &shown,
+            error: &error,
+        }
+        .to_string()
+    })?;
+    parse(&content, &shown)
+}
+
+fn parse(content: &str, shown: &str) -> Result<Box<[Brick]>, String> {
+    let set: BrickSet = toml::from_str(content).map_err(|error| {
+        Message::InvalidBrickSet {
+            path: shown,
+            error: &error,
+        }
+        .to_string()
+    })?;
+    if set.brick.is_empty() || set.brick.len() > MAX_BRICKS {
+        return Err(Message::BrickSetSize {
+            path: shown,
+            bricks: set.brick.len(),
+            max: MAX_BRICKS,
+        }
+        .to_string());
+    }
+    set.brick
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let rows: Vec<&str> = entry.shape.get_ref().iter().map(String::as_str).collect();
+            Brick::from_ascii(&rows).map_err(|error| {
+                Message::InvalidBrickShape {
+                    path: shown,
+                    line: content[..entry.shape.span().start].matches('\n').count() + 1,
+                    brick: index + 1,
+                    name: entry.name.as_deref(),
+                    shape: entry.shape.get_ref(),
+                    error: &error,
+                }
+                .to_string()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::ShapeError;
+
+    #[test]
+    fn standard_set_reads_back() {
+        let standard = Brick::all_bricks();
+        let mut content = String::new();
+        for (index, brick) in standard.iter().enumerate() {
+            let rows: Vec<String> = (0..8)
+                .map(|row| {
+                    let byte = (brick.shape() << (row * 8)) >> 56;
+                    (0..8)
+                        .map(|column| match byte & (0x80 >> column) {
+                            0 => '.',
+                            _ => 'X',
+                        })
+                        .collect::<String>()
+                        .trim_end_matches('.')
+                        .to_string()
+                })
+                .filter(|row| !row.is_empty())
+                .collect();
+            content += &format!("[[brick]]\nname = \"{}\"\nshape = {rows:?}\n", index + 1);
+        }
+        let bricks = parse(&content, "set.toml").unwrap();
+        assert_eq!(bricks.len(), standard.len());
+        for (brick, standard) in bricks.iter().zip(&standard) {
+            assert!(brick.matches(standard.shape()));
+            assert!(standard.matches(brick.shape()));
+        }
+    }
+
+    #[test]
+    fn names_are_optional() {
+        let bricks = parse("[[brick]]\nshape = [\"XX\", \"X.\"]\n", "set.toml").unwrap();
+        assert_eq!(bricks[0].shape().count_ones(), 3);
+    }
+
+    #[test]
+    fn invalid_shape_gives_its_line() {
+        let content =
+            "[[brick]]\nshape = [\"XX\"]\n\n[[brick]]\nname = \"gap\"\nshape = [\"X.X\"]\n";
+        let error = parse(content, "set.toml").unwrap_err();
+        let expected = Message::InvalidBrickShape {
+            path: "set.toml",
+            line: 6,
+            brick: 2,
+            name: Some("gap"),
+            shape: &["X.X".to_string()],
+            error: &ShapeError::Disconnected,
+        };
+        assert_eq!(error, expected.to_string());
+    }
+
+    #[test]
+    fn rejects_invalid_sets() {
+        for content in [
+            "",
+            "[[brick]]\nshape = [\"XX\"]\ncolor = \"red\"\n",
+            "[[brick]]\nname = \"no shape\"\n",
+        ] {
+            let error = parse(content, "set.toml").unwrap_err();
+            let toml_error = toml::from_str::<BrickSet>(content).err().unwrap();
+            let expected = Message::InvalidBrickSet {
+                path: "set.toml",
+                error: &toml_error,
+            };
+            assert_eq!(error, expected.to_string());
+        }
+        let too_many = "[[brick]]\nshape = [\"X\"]\n".repeat(MAX_BRICKS + 1);
+        for (content, bricks) in [("brick = []", 0), (too_many.as_str(), MAX_BRICKS + 1)] {
+            let error = parse(content, "set.toml").unwrap_err();
+            let expected = Message::BrickSetSize {
+                path: "set.toml",
+                bricks,
+                max: MAX_BRICKS,
+            };
+            assert_eq!(error, expected.to_string());
+        }
+    }
+}
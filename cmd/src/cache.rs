@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use solver::{
+    Board, Brick, Hint, SearchProfile, SolverError, hints_from_solutions, puzzle_checksum,
+    solve_with_profile,
+};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+// What the solver has to enumerate every solution for, kept on disk so it only happens once per
+// date and brick set.
+#[derive(Serialize, Deserialize)]
+pub struct CachedDay {
+    pub solutions: usize,
+    pub first_solution: Vec<u64>,
+    pub hints: Vec<CachedHint>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CachedHint {
+    pub brick: u64,
+    pub solutions: usize,
+}
+
+impl CachedDay {
+    pub fn compute(board: Board, bricks: &[Brick]) -> CachedDay {
+        CachedDay::from_solutions(
+            solve_with_profile(board, bricks, SearchProfile::Interactive)
+                .map(|solution| solution.placed_bricks)
+                .collect(),
+        )
+    }
+
+    // Everything is tallied from one list of all the solutions, so a run that searched them all
+    // anyway can keep them without searching again.
+    pub fn from_solutions(solutions: Vec<Vec<u64>>) -> CachedDay {
+        CachedDay {
+            solutions: solutions.len(),
+            first_solution: solutions.first().cloned().unwrap_or_default(),
+            hints: hints_from_solutions(solutions)
+                .iter()
+                .map(|hint| CachedHint {
+                    brick: hint.brick,
+                    solutions: hint.solutions,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn hints(&self) -> Vec<Hint> {
+        self.hints
+            .iter()
+            .map(|hint| Hint {
+                brick: hint.brick,
+                solutions: hint.solutions,
+            })
+            .collect()
+    }
+}
+
+// The cached entry for the date, or a new one worked out and stored for next time.
+pub fn load_or_compute(day: u8, month: u8, bricks: &[Brick]) -> Result<CachedDay, SolverError> {
+    if let Some(cached) = load(day, month, bricks) {
+        return Ok(cached);
+    }
+    let cached = CachedDay::compute(Board::for_date(day, month)?, bricks);
+    let _ = store(day, month, bricks, &cached);
+    Ok(cached)
+}
+
+pub fn load(day: u8, month: u8, bricks: &[Brick]) -> Option<CachedDay> {
+    let text = fs::read_to_string(path(day, month, bricks)?).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+pub fn store(day: u8, month: u8, bricks: &[Brick], cached: &CachedDay) -> io::Result<()> {
+    let path = path(day, month, bricks)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No cache directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string(cached).expect("Cache entry serializes to JSON");
+    fs::write(path, json)
+}
+
+// One file per date and puzzle, named by the checksum of the board and bricks so a changed brick
+// set never reads another set's entries.
+fn path(day: u8, month: u8, bricks: &[Brick]) -> Option<PathBuf> {
+    let board = Board::for_date(day, month).ok()?;
+    let checksum = puzzle_checksum(&board, bricks);
+    Some(cache_dir()?.join(format!("{month:02}-{day:02}-{checksum:016x}.json")))
+}
+
+pub fn cache_dir() -> Option<PathBuf> {
+    let cache_dir = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))?;
+    Some(cache_dir.join("calendar_puzzle_solver"))
+}
@@ -0,0 +1,122 @@
+use crate::i18n::Message;
+use crate::input;
+use serde::{Deserialize, Deserializer};
+use solver::Palette;
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub hooks: Hooks,
+    pub png: Png,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Hooks {
+    /// Shell command run before solving. The date is passed in the environment.
+    pub pre_solve: Option<String>,
+    /// Shell command run after solving, receiving the result as JSON on stdin.
+    pub post_solve: Option<String>,
+}
+
+// How solutions are drawn as PNG images. Colors are written like "#202020".
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Png {
+    /// Each cell is 8 times this many pixels wide.
+    pub scale: u32,
+    #[serde(deserialize_with = "color")]
+    pub background: Option<[u8; 3]>,
+    #[serde(deserialize_with = "color")]
+    pub free: Option<[u8; 3]>,
+    #[serde(deserialize_with = "colors")]
+    pub bricks: Option<Vec<[u8; 3]>>,
+}
+
+impl Default for Png {
+    fn default() -> Png {
+        Png {
+            scale: 4,
+            background: None,
+            free: None,
+            bricks: None,
+        }
+    }
+}
+
+impl Png {
+    pub fn palette(&self) -> Palette {
+        let default = Palette::default();
+        Palette {
+            background: self.background.unwrap_or(default.background),
+            free: self.free.unwrap_or(default.free),
+            bricks: self.bricks.clone().unwrap_or(default.bricks),
+        }
+    }
+}
+
+fn parse_color(text: &str) -> Result<[u8; 3], String> {
+    let invalid = || Message::InvalidColor(text).to_string();
+    let hex = text.strip_prefix('#').ok_or_else(invalid)?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let channel = |at: usize| u8::from_str_radix(&hex[at..at + 2], 16).map_err(|_| invalid());
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+fn color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<[u8; 3]>, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    parse_color(&text)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+fn colors<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<[u8; 3]>>, D::Error> {
+    let texts = Vec::<String>::deserialize(deserializer)?;
+    let colors: Result<Vec<[u8; 3]>, String> = texts.iter().map(|text| parse_color(text)).collect();
+    colors.map(Some).map_err(serde::de::Error::custom)
+}
+
+impl Config {
+    // A missing default config file is fine, a missing explicitly given one is not.
+    pub fn load(path: Option<&Path>) -> Result<Config, String> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Config::default()),
+            },
+        };
+        let shown = path.display().to_string();
+        match input::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content).map_err(|error| {
+                Message::InvalidConfigFile {
+                    path: &shown,
+                    error: &error,
+                }
+                .to_string()
+            }),
+            Err(_) if !required => Ok(Config::default()),
+            Err(error) => Err(Message::CouldNotRead {
+                path: &shown,
+                error: &error,
+            }
+            .to_string()),
+        }
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(
+        config_dir
+            .join("calendar_puzzle_solver")
+            .join("config.toml"),
+    )
+}
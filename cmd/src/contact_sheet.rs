@@ -0,0 +1,145 @@
+use crate::render::{brick_cells, is_board_cell, solution_cells};
+use solver::{Board, Image, Palette, SolvedBoard};
+use std::io;
+use std::path::Path;
+
+// Pixels per cell, and between the cells and around each board.
+const CELL: u32 = 8;
+const FRAME_CELL: u32 = 24;
+const GAP: u32 = 1;
+const MARGIN: u32 = 6;
+
+// Cells still to be covered in a frame of the search.
+const EMPTY: [u8; 3] = [72, 72, 72];
+
+// Draws a rectangle at x, y with the width, height and color.
+type Fill<'a> = dyn FnMut(u32, u32, u32, u32, [u8; 3]) + 'a;
+
+pub fn write(path: &Path, solutions: &[SolvedBoard], palette: &Palette) -> io::Result<()> {
+    std::fs::write(path, draw(solutions, palette).to_png())
+}
+
+// Draws every solution as a small board, in rows about as long as the columns are tall.
+fn draw(solutions: &[SolvedBoard], palette: &Palette) -> Image {
+    let mut image = Image::new(0, 0, palette.background);
+    draw_with(
+        solutions,
+        palette,
+        &mut |x, y, width, height, color| match x + y {
+            0 => image = Image::new(width, height, color),
+            _ => image.fill(x, y, width, height, color),
+        },
+    );
+    image
+}
+
+// Calls `fill` with each rectangle of the contact sheet, the first being the background of the
+// whole sheet at 0, 0.
+fn draw_with(solutions: &[SolvedBoard], palette: &Palette, fill: &mut Fill) {
+    let columns = (solutions.len() as f64).sqrt().ceil().max(1.0) as u32;
+    let rows = (solutions.len() as u32).div_ceil(columns).max(1);
+    let step = board_size(CELL) + MARGIN;
+    let (width, height) = (columns * step + MARGIN, rows * step + MARGIN);
+    fill(0, 0, width, height, palette.background);
+    for (number, solution) in solutions.iter().enumerate() {
+        let number = number as u32;
+        let left = MARGIN + number % columns * step;
+        let top = MARGIN + number / columns * step;
+        draw_board(
+            fill,
+            left,
+            top,
+            CELL,
+            &solution_cells(solution),
+            palette,
+            &|_| false,
+        );
+    }
+}
+
+// A board in the middle of the search with bigger cells, for a frame of an animation.
+pub fn draw_frame(board: &Board, placed_bricks: &[u64], palette: &Palette) -> Image {
+    let size = board_size(FRAME_CELL) + 2 * MARGIN;
+    let mut image = Image::new(size, size, palette.background);
+    let cells = brick_cells(placed_bricks);
+    let empty = |i| board.is_free(i as u8);
+    let mut fill = |x, y, width, height, color| image.fill(x, y, width, height, color);
+    draw_board(
+        &mut fill, MARGIN, MARGIN, FRAME_CELL, &cells, palette, &empty,
+    );
+    image
+}
+
+fn board_size(cell: u32) -> u32 {
+    7 * (cell + GAP) + GAP
+}
+
+// Cells without a brick are the date cells, unless `empty` says they are still to be covered.
+fn draw_board(
+    fill: &mut Fill,
+    left: u32,
+    top: u32,
+    cell: u32,
+    cells: &[u8; 51],
+    palette: &Palette,
+    empty: &dyn Fn(usize) -> bool,
+) {
+    for (i, &brick_number) in cells.iter().enumerate().filter(|(i, _)| is_board_cell(*i)) {
+        let color = match brick_number {
+            0 if empty(i) => EMPTY,
+            0 => palette.free,
+            brick_number => palette.brick(brick_number),
+        };
+        let x = left + GAP + (i % 8) as u32 * (cell + GAP);
+        let y = top + GAP + (i / 8) as u32 * (cell + GAP);
+        fill(x, y, cell, cell, color);
+        // The free cells get a hole, so they don't look like the light gray brick.
+        if brick_number == 0 && !empty(i) {
+            let border = cell / 4;
+            fill(
+                x + border,
+                y + border,
+                cell - 2 * border,
+                cell - 2 * border,
+                palette.background,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::assert_svg;
+    use solver::{Board, Brick, solve};
+
+    // The sheet as an SVG, which is small and shows what moved in a diff.
+    fn svg(solutions: &[SolvedBoard], palette: &Palette) -> String {
+        let mut svg = String::new();
+        draw_with(solutions, palette, &mut |x, y, width, height, [r, g, b]| {
+            if x + y == 0 {
+                svg += &format!(
+                    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\">\n"
+                );
+            }
+            svg += &format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" \
+                 fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n"
+            );
+        });
+        svg + "</svg>\n"
+    }
+
+    #[test]
+    fn contact_sheets() {
+        let bricks = Brick::all_bricks();
+        for (name, day, month) in [("sep_22", 22, 9), ("jan_1", 1, 1), ("dec_31", 31, 12)] {
+            // A few solutions keep the golden files small.
+            let solutions: Vec<SolvedBoard> = solve(Board::for_date(day, month).unwrap(), &bricks)
+                .take(4)
+                .collect();
+            let sheet = svg(&solutions, &Palette::default());
+            assert_svg(&format!("contact_sheet_{name}"), &sheet);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::output::Output;
+use crate::pager::outln;
+use solver::{Brick, Hint, SolvedBoard};
+use std::time::{Duration, Instant};
+
+// What --format csv prints: a row per solution with the number of solutions shown and the search
+// effort, or a row per hint. `shown` counts the rows, not every solution of the date, which
+// --max-solutions or --timeout may cut short.
+pub struct Csv {
+    month: u8,
+    day: u8,
+    solutions: Vec<(u64, Duration, Duration)>,
+    hints: Vec<(usize, usize)>,
+}
+
+impl Csv {
+    pub fn new(day: u8, month: u8) -> Csv {
+        Csv {
+            month,
+            day,
+            solutions: vec![],
+            hints: vec![],
+        }
+    }
+
+    // The header and a row per hint, or per solution when there are no hints.
+    fn rows(&self) -> Vec<String> {
+        let (month, day) = (self.month, self.day);
+        if !self.hints.is_empty() {
+            let hints = self.hints.iter().enumerate();
+            return std::iter::once("month,day,hint,brick,solutions".to_string())
+                .chain(hints.map(|(i, (brick, solutions))| {
+                    format!("{month},{day},{},{brick},{solutions}", i + 1)
+                }))
+                .collect();
+        }
+        let shown = self.solutions.len();
+        let solutions = self.solutions.iter().enumerate();
+        let header = "month,day,solution,shown,test_count,elapsed_ms,solution_ms";
+        std::iter::once(header.to_string())
+            .chain(solutions.map(|(i, (test_count, elapsed, solution_time))| {
+                format!(
+                    "{month},{day},{},{shown},{test_count},{:.3},{:.3}",
+                    i + 1,
+                    elapsed.as_secs_f64() * 1000.0,
+                    solution_time.as_secs_f64() * 1000.0
+                )
+            }))
+            .collect()
+    }
+}
+
+impl Output for Csv {
+    fn push_solution(&mut self, solution: &SolvedBoard) {
+        let stats = &solution.stats;
+        self.solutions
+            .push((stats.test_count, stats.elapsed, stats.solution_time));
+    }
+
+    fn push_hint(&mut self, hint: &Hint, bricks: &[Brick]) {
+        // A hint that places none of the bricks gets no row.
+        let Some(brick) = bricks.iter().position(|brick| brick.matches(hint.brick)) else {
+            return;
+        };
+        self.hints.push((brick + 1, hint.solutions));
+    }
+
+    fn print(self: Box<Self>, _start: Instant) {
+        for row in self.rows() {
+            outln!("{row}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::{Board, hints, solve};
+
+    #[test]
+    fn a_row_per_solution() {
+        let bricks = Brick::all_bricks();
+        let solutions: Vec<SolvedBoard> = solve(Board::for_date(22, 9).unwrap(), &bricks)
+            .take(3)
+            .collect();
+        let mut csv = Csv::new(22, 9);
+        for solution in &solutions {
+            csv.push_solution(solution);
+        }
+        let rows = csv.rows();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[0],
+            "month,day,solution,shown,test_count,elapsed_ms,solution_ms"
+        );
+        for (number, (row, solution)) in rows[1..].iter().zip(&solutions).enumerate() {
+            let columns: Vec<&str> = row.split(',').collect();
+            let test_count = solution.stats.test_count.to_string();
+            assert_eq!(
+                columns[..5],
+                ["9", "22", &(number + 1).to_string(), "3", &test_count]
+            );
+            // The times in milliseconds, with three decimals.
+            for time in &columns[5..] {
+                assert!(time.parse::<f64>().is_ok() && time.split('.').nth(1).unwrap().len() == 3);
+            }
+        }
+    }
+
+    #[test]
+    fn a_row_per_hint() {
+        let bricks = Brick::all_bricks();
+        let all_hints = hints(Board::for_date(1, 1).unwrap(), &bricks);
+        let mut csv = Csv::new(1, 1);
+        for hint in all_hints.iter().take(2) {
+            csv.push_hint(hint, &bricks);
+        }
+        let rows = csv.rows();
+        assert_eq!(rows[0], "month,day,hint,brick,solutions");
+        for (number, (row, hint)) in rows[1..].iter().zip(&all_hints).enumerate() {
+            let brick = bricks
+                .iter()
+                .position(|brick| brick.matches(hint.brick))
+                .unwrap();
+            let expected = format!("1,1,{},{},{}", number + 1, brick + 1, hint.solutions);
+            assert_eq!(row, &expected);
+        }
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[test]
+    fn hints_placing_no_brick_are_left_out() {
+        let mut csv = Csv::new(1, 1);
+        let stray = Hint {
+            brick: 1 << 63,
+            solutions: 5,
+        };
+        csv.push_hint(&stray, &Brick::all_bricks());
+        assert!(csv.hints.is_empty());
+    }
+
+    #[test]
+    fn only_the_header_without_solutions() {
+        let rows = Csv::new(1, 1).rows();
+        assert_eq!(
+            rows,
+            ["month,day,solution,shown,test_count,elapsed_ms,solution_ms"]
+        );
+    }
+}
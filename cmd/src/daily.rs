@@ -0,0 +1,104 @@
+use crate::i18n::Message;
+use crate::pager::outln;
+use crate::render::{print_marked_cell, print_with_tray};
+use crate::results::{HintLevel, PuzzleResult};
+use chrono::{Datelike, Local};
+use solver::{Board, Brick, SearchProfile, hints_with_profile};
+use std::fs;
+use std::io::{BufRead, stdin};
+use std::path::PathBuf;
+use std::time::Instant;
+
+// Times solving today's puzzle on the physical board, giving hints on request, and writes the
+// result to a file that can be shared with others. Each hint about a brick gives away a bit more
+// than the one before: a cell it covers, then which brick it is, then where it goes. At most
+// `hint_budget` hints are given.
+pub fn run(
+    name: Option<String>,
+    output: Option<PathBuf>,
+    secret: Option<String>,
+    hint_budget: u32,
+) {
+    let today = Local::now().date_naive();
+    let board = Board::for_date(today.day() as u8, today.month() as u8).unwrap();
+    let bricks = Brick::all_bricks();
+    let name = name
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let mut result = PuzzleResult {
+        name,
+        date: today,
+        time: Default::default(),
+        hints: 0,
+        spoilers: vec![],
+        checksum: PuzzleResult::expected_checksum(today).unwrap(),
+        signature: None,
+    };
+
+    outln!("{}", Message::DailyStart(today));
+    outln!("{}", Message::DailyPrompt);
+    let start = Instant::now();
+    let mut hints = None;
+    for line in stdin().lock().lines() {
+        match line.as_deref().map(str::trim) {
+            Ok("h") if result.hints >= hint_budget => {
+                outln!("{}", Message::HintBudgetSpent(hint_budget))
+            }
+            Ok("h") => {
+                let hints = hints.get_or_insert_with(|| {
+                    hints_with_profile(board.clone(), &bricks, SearchProfile::Interactive)
+                });
+                let given = result.spoilers.len();
+                let Some(hint) = hints.get(given / HintLevel::ALL.len()) else {
+                    outln!("{}", Message::NoMoreHints);
+                    continue;
+                };
+                let level = HintLevel::ALL[given % HintLevel::ALL.len()];
+                result.hints += 1;
+                result.spoilers.push(level);
+                let message = Message::DailyHint {
+                    number: result.hints,
+                    level,
+                    left: hint_budget - result.hints,
+                };
+                outln!("{message}");
+                let cell = hint.brick.leading_zeros() as usize;
+                let brick_index = bricks.iter().position(|brick| brick.matches(hint.brick));
+                match level {
+                    HintLevel::Cell => print_marked_cell(cell, None),
+                    HintLevel::Piece => print_marked_cell(
+                        cell,
+                        brick_index.map(|brick_index| (brick_index, &bricks[brick_index])),
+                    ),
+                    HintLevel::Placement => print_with_tray(&[hint.brick], &bricks),
+                }
+            }
+            _ => break,
+        }
+    }
+    result.time = start.elapsed();
+    if let Some(secret) = &secret {
+        result.sign(secret);
+    }
+
+    let path = output.unwrap_or_else(|| PathBuf::from(format!("daily-{today}.result")));
+    match fs::write(&path, result.to_text()) {
+        Ok(()) => outln!(
+            "{}",
+            Message::DailyDone {
+                time: &crate::leaderboard::format_time(result.time),
+                hints: result.hints,
+                path: &path.display().to_string(),
+            }
+        ),
+        Err(error) => {
+            let path = path.display().to_string();
+            let message = Message::CouldNotWrite {
+                path: &path,
+                error: &error,
+            };
+            eprintln!("{}", Message::Error(&message))
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::i18n::Message;
+use chrono::{Datelike, Days, Month, NaiveDate, Weekday};
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+const KEYWORDS: [&str; 5] = ["today", "tomorrow", "yesterday", "next", "last"];
+
+// Dates the way people say them: "today", "tomorrow", "next friday", "29 july", "jul 29th", or
+// written with numbers like "2025-07-29" and "29.7". Dates without a year are in the year of
+// `today`.
+pub fn parse(text: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    if let Some(date) = parse_numeric(text.trim(), today) {
+        return date;
+    }
+    let lowercase = text.trim().to_lowercase();
+    let words: Vec<&str> = lowercase
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .collect();
+    match words.as_slice() {
+        ["today"] => Ok(today),
+        ["tomorrow"] => Ok(today + Days::new(1)),
+        ["yesterday"] => Ok(today - Days::new(1)),
+        [weekday] | ["next", weekday] if parse_weekday(weekday).is_some() => {
+            let weekday = parse_weekday(weekday).unwrap();
+            Ok(today + Days::new(days_until(today.weekday(), weekday).into()))
+        }
+        ["last", weekday] if parse_weekday(weekday).is_some() => {
+            let weekday = parse_weekday(weekday).unwrap();
+            Ok(today - Days::new(days_until(weekday, today.weekday()).into()))
+        }
+        [first, second] => {
+            let (day, month) = match (parse_day(first), parse_day(second)) {
+                (Some(day), _) => (day, second),
+                (None, Some(day)) => (day, first),
+                (None, None) => return Err(not_understood(text, &words)),
+            };
+            let month = parse_month(month).ok_or_else(|| not_understood(text, &words))?;
+            NaiveDate::from_ymd_opt(today.year(), month, day).ok_or_else(|| {
+                Message::NoDayInMonthOf {
+                    day,
+                    month,
+                    year: None,
+                    text: None,
+                }
+                .to_string()
+            })
+        }
+        _ => Err(not_understood(text, &words)),
+    }
+}
+
+// Dates of numbers only: 2025-07-29, or with the day first like 29.7, 29.7. and 29.7.2025. None
+// when the text is none of those, so it can be read as words.
+fn parse_numeric(text: &str, today: NaiveDate) -> Option<Result<NaiveDate, String>> {
+    let numbers = |parts: Vec<&str>| -> Option<Vec<u32>> {
+        let all_digits = parts
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()));
+        all_digits.then(|| {
+            parts
+                .iter()
+                .map(|part| part.parse().unwrap_or(u32::MAX))
+                .collect()
+        })
+    };
+    let dashed = numbers(text.split('-').collect());
+    let dotted = numbers(text.strip_suffix('.').unwrap_or(text).split('.').collect());
+    let (year, month, day) = match (dashed.as_deref(), dotted.as_deref()) {
+        (Some(&[year, month, day]), _) => (year as i32, month, day),
+        (_, Some(&[day, month])) => (today.year(), month, day),
+        (_, Some(&[day, month, year])) => (year as i32, month, day),
+        _ => return None,
+    };
+    if !(1..=12).contains(&month) {
+        return Some(Err(Message::InvalidMonthIn { month, text }.to_string()));
+    }
+    Some(NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+        Message::NoDayInMonthOf {
+            day,
+            month,
+            year: Some(year),
+            text: Some(text),
+        }
+        .to_string()
+    }))
+}
+
+// Days from one weekday to the next given weekday, a full week if they're the same.
+fn days_until(from: Weekday, to: Weekday) -> u32 {
+    (to.num_days_from_monday() + 6 - from.num_days_from_monday()) % 7 + 1
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    // Weekday parsing in chrono accepts full names and three letter abbreviations.
+    word.parse().ok()
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    word.parse::<Month>()
+        .ok()
+        .map(|month| month.number_from_month())
+}
+
+fn parse_day(word: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th", "."]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word);
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+fn month_name(month: u32) -> &'static str {
+    Month::try_from(month as u8).unwrap().name()
+}
+
+// Suggests the closest known word for the first word that isn't understood.
+fn not_understood(text: &str, words: &[&str]) -> String {
+    let known: Vec<String> = (1..=12)
+        .map(|month| month_name(month).to_lowercase())
+        .chain(
+            WEEKDAYS
+                .iter()
+                .chain(&KEYWORDS)
+                .map(|word| word.to_string()),
+        )
+        .collect();
+    let unknown = words.iter().find(|word| {
+        parse_day(word).is_none()
+            && parse_month(word).is_none()
+            && parse_weekday(word).is_none()
+            && !KEYWORDS.contains(word)
+    });
+    let suggestion = unknown.and_then(|word| {
+        known
+            .iter()
+            .min_by_key(|candidate| edit_distance(word, candidate))
+            .filter(|candidate| edit_distance(word, candidate) <= 2)
+            .map(|candidate| (word, candidate))
+    });
+    match suggestion {
+        Some((word, suggestion)) => Message::UnknownDateWord {
+            word,
+            text,
+            suggestion,
+        }
+        .to_string(),
+        None => Message::DateNotUnderstood(text).to_string(),
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A Tuesday.
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 7, 29).unwrap()
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn reads_dates_in_words() {
+        for (text, expected) in [
+            ("today", date(7, 29)),
+            (" Tomorrow ", date(7, 30)),
+            ("yesterday", date(7, 28)),
+            ("friday", date(8, 1)),
+            ("next friday", date(8, 1)),
+            ("next tuesday", date(8, 5)),
+            ("last tuesday", date(7, 22)),
+            ("last sun", date(7, 27)),
+            ("29 july", date(7, 29)),
+            ("July 4th", date(7, 4)),
+            ("jul 1st", date(7, 1)),
+            ("2. feb", date(2, 2)),
+            ("dec 31, ", date(12, 31)),
+        ] {
+            assert_eq!(parse(text, today()), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn suggests_the_closest_word() {
+        for (text, word, suggestion) in [
+            ("next fridya", "fridya", "friday"),
+            ("29 jully", "jully", "july"),
+        ] {
+            let expected = Message::UnknownDateWord {
+                word,
+                text,
+                suggestion,
+            };
+            assert_eq!(parse(text, today()), Err(expected.to_string()));
+        }
+        for text in ["", "soon", "29 july 2025 please", "32 july"] {
+            let expected = Message::DateNotUnderstood(text).to_string();
+            assert_eq!(parse(text, today()), Err(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn rejects_days_beyond_the_month() {
+        let expected = Message::NoDayInMonthOf {
+            day: 31,
+            month: 6,
+            year: None,
+            text: None,
+        };
+        assert_eq!(parse("31 june", today()), Err(expected.to_string()));
+    }
+
+    #[test]
+    fn reads_dates_in_numbers() {
+        let next_year = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        for (text, expected) in [
+            ("2025-07-29", date(7, 29)),
+            ("2026-01-02", next_year),
+            ("29.7", date(7, 29)),
+            ("1.12.", date(12, 1)),
+            ("2.1.2026", next_year),
+            (" 05.03 ", date(3, 5)),
+        ] {
+            assert_eq!(parse(text, today()), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn rejects_dates_in_numbers_that_dont_exist() {
+        let expected = Message::InvalidMonthIn {
+            month: 13,
+            text: "2025-13-01",
+        };
+        assert_eq!(parse("2025-13-01", today()), Err(expected.to_string()));
+        for (text, day, month) in [("29.2", 29, 2), ("0.7", 0, 7)] {
+            let expected = Message::NoDayInMonthOf {
+                day,
+                month,
+                year: Some(2025),
+                text: Some(text),
+            };
+            assert_eq!(parse(text, today()), Err(expected.to_string()));
+        }
+        assert_eq!(
+            parse("29.2.2024", today()),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+        // Neither numbers nor words.
+        for text in ["2025-07", "29.7.2025.1", "29/7", "-7-29"] {
+            let expected = Message::DateNotUnderstood(text).to_string();
+            assert_eq!(parse(text, today()), Err(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn errors_name_the_month() {
+        let no_day = Message::NoDayInMonthOf {
+            day: 29,
+            month: 2,
+            year: Some(2025),
+            text: Some("29.2"),
+        };
+        assert_eq!(
+            no_day.to_string(),
+            "February 2025 has no day 29, in date `29.2`"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use crate::hooks::exit;
+use crate::i18n::Message;
+use crate::pager::outln;
+use solver::{Brick, GeneratorOptions, generate_brick_sets};
+
+// The cells a date leaves free on the board, which the bricks have to cover exactly.
+const FREE_CELLS: usize = 41;
+
+// Looks for brick sets of the sizes solving every date, and prints the ones found as brick set
+// files, ready for --bricks.
+pub fn run(sizes: Vec<usize>, count: usize, attempts: usize, seed: u64) {
+    let cells: usize = sizes.iter().sum();
+    if cells != FREE_CELLS {
+        eprintln!(
+            "{}",
+            Message::Error(&Message::SizesDontCover {
+                cells,
+                free: FREE_CELLS
+            })
+        );
+        exit(1);
+    }
+    let options = GeneratorOptions {
+        sizes,
+        seed,
+        attempts,
+    };
+    let mut found = 0;
+    for (index, bricks) in generate_brick_sets(&options).take(count).enumerate() {
+        if index > 0 {
+            outln!();
+        }
+        outln!("# Set {} (seed {seed})", index + 1);
+        for brick in &bricks {
+            outln!();
+            outln!("[[brick]]");
+            outln!("shape = {:?}", rows(brick));
+        }
+        found += 1;
+    }
+    if found == 0 {
+        eprintln!("{}", Message::Error(&Message::NoSetFound(attempts)));
+        exit(1);
+    }
+}
+
+// The shape as rows of X for cells and . for gaps.
+fn rows(brick: &Brick) -> Vec<String> {
+    let shape = brick.shape();
+    let width = (0..8)
+        .filter(|column| (0..8).any(|row| shape & 1 << 63 >> (row * 8 + column) != 0))
+        .count();
+    (0..8)
+        .map(|row| {
+            (0..width)
+                .map(|column| match shape & 1 << 63 >> (row * 8 + column) {
+                    0 => '.',
+                    _ => 'X',
+                })
+                .collect::<String>()
+        })
+        .filter(|row| row.contains('X'))
+        .collect()
+}
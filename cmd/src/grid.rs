@@ -0,0 +1,114 @@
+use crate::i18n::Message;
+use std::collections::BTreeMap;
+
+// The cells of each piece of a board, by the letter or digit it is drawn with.
+pub type Pieces = BTreeMap<char, Vec<(u32, u32)>>;
+
+// Grids of one character per cell, letters or digits for the pieces and . or O for uncovered
+// cells, like the solutions printed with --ascii. The frame around a printed board is skipped,
+// as are lines with anything else on them, and every 7 rows make a board.
+pub fn parse(text: &str) -> Result<Vec<Pieces>, String> {
+    let rows: Vec<&str> = text
+        .lines()
+        .map(|line| line.trim_matches(|c: char| "|+-_".contains(c) || c.is_whitespace()))
+        .filter(|row| !row.is_empty() && row.chars().all(|c| c.is_ascii_alphanumeric() || c == '.'))
+        .collect();
+    if !rows.len().is_multiple_of(7) {
+        return Err(Message::GridRows(rows.len()).to_string());
+    }
+    Ok(rows.chunks(7).map(pieces).collect())
+}
+
+fn pieces(rows: &[&str]) -> Pieces {
+    let mut pieces = Pieces::new();
+    for (row, line) in rows.iter().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            if c.is_ascii_alphanumeric() && c != 'O' {
+                pieces
+                    .entry(c)
+                    .or_default()
+                    .push((row as u32, column as u32));
+            }
+        }
+    }
+    pieces
+}
+
+// The bits of the cells on the 8 by 8 grid of the bitboard, leaving out cells beyond it.
+pub fn mask<'a>(cells: impl IntoIterator<Item = &'a (u32, u32)>) -> u64 {
+    cells
+        .into_iter()
+        .filter(|&&(row, column)| row < 8 && column < 8)
+        .fold(0, |mask, &(row, column)| {
+            mask | 1 << 63 >> (row * 8 + column)
+        })
+}
+
+// The piece moved to the top left corner, the way brick variants are stored, so a piece is
+// compared by its shape wherever it lies.
+pub fn shape(cells: &[(u32, u32)]) -> Option<u64> {
+    let top = cells.iter().map(|&(row, _)| row).min()?;
+    let left = cells.iter().map(|&(_, column)| column).min()?;
+    let moved: Vec<(u32, u32)> = cells
+        .iter()
+        .map(|&(row, column)| (row - top, column - left))
+        .collect();
+    if moved.iter().any(|&(row, column)| row >= 8 || column >= 8) {
+        return None;
+    }
+    Some(mask(&moved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::{Board, Brick, solve};
+
+    #[test]
+    fn reads_printed_boards() {
+        let solution = solve(Board::for_date(22, 9).unwrap(), &Brick::all_bricks())
+            .next()
+            .unwrap();
+        let grid = solution.to_grid();
+        // The way --ascii prints it, with a letter per brick.
+        let printed = include_str!("../snapshots/board_sep_22_ascii.txt");
+        let boards = parse(printed).unwrap();
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].len(), 8);
+        for (&letter, cells) in &boards[0] {
+            let brick = letter as u8 - b'A' + 1;
+            for &(row, column) in cells {
+                assert_eq!(grid[row as usize][column as usize], brick);
+            }
+            assert!(solution.placed_bricks.contains(&mask(cells)));
+        }
+    }
+
+    #[test]
+    fn reads_several_boards_between_other_lines() {
+        let board = "11.2\n1122\n..O2\n3333\n44..\n4...\n4...\n";
+        let text = format!("Solution 1\n{board}\nSolution 2:\n{board}");
+        let boards = parse(&text).unwrap();
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[0], boards[1]);
+        assert_eq!(boards[0][&'1'], [(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(boards[0][&'4'], [(4, 0), (4, 1), (5, 0), (6, 0)]);
+        assert!(!boards[0].contains_key(&'O'));
+    }
+
+    #[test]
+    fn rejects_incomplete_boards() {
+        let error = parse("AAB\nAB.\n").unwrap_err();
+        assert_eq!(error, Message::GridRows(2).to_string());
+    }
+
+    #[test]
+    fn shapes_are_moved_to_the_corner() {
+        let cells = [(3, 2), (3, 3), (4, 3)];
+        assert_eq!(shape(&cells), Some(0b11000000_01000000 << 48));
+        assert_eq!(shape(&[]), None);
+        assert_eq!(shape(&[(0, 0), (8, 0)]), None);
+        // Cells beyond the 8 by 8 grid are no part of the mask.
+        assert_eq!(mask(&[(0, 0), (0, 8), (8, 0)]), 1 << 63);
+    }
+}
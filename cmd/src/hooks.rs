@@ -0,0 +1,90 @@
+use crate::i18n::Message;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+struct PostSolve {
+    command: String,
+    day: u8,
+    month: u8,
+    report: Option<String>,
+}
+
+// Every way out of the program goes through `exit`, so the post-solve hook of a finished solve
+// runs exactly once.
+static POST_SOLVE: Mutex<Option<PostSolve>> = Mutex::new(None);
+
+pub fn shell_command(command: &str) -> Command {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    shell.arg(command);
+    shell
+}
+
+pub fn run(command: &str, day: u8, month: u8, stdin: Option<&str>) {
+    let child = shell_command(command)
+        .env("CALENDAR_PUZZLE_DAY", day.to_string())
+        .env("CALENDAR_PUZZLE_MONTH", month.to_string())
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .spawn();
+    let result = child.and_then(|mut child| {
+        if let (Some(input), Some(mut child_stdin)) = (stdin, child.stdin.take()) {
+            child_stdin.write_all(input.as_bytes())?;
+        }
+        child.wait()
+    });
+    match result {
+        Ok(status) if !status.success() => {
+            let warning = Message::HookFailed {
+                command,
+                status: &status,
+            };
+            eprintln!("{}", Message::Warning(&warning));
+        }
+        Err(error) => {
+            let warning = Message::HookNotRun {
+                command,
+                error: &error,
+            };
+            eprintln!("{}", Message::Warning(&warning));
+        }
+        Ok(_) => {}
+    }
+}
+
+/// Runs `command` as the post-solve hook for the solved date when the program exits, with the
+/// JSON report on its standard input if there is one.
+pub fn post_solve(command: Option<&str>, day: u8, month: u8, report: Option<String>) {
+    *POST_SOLVE.lock().unwrap() = command.map(|command| PostSolve {
+        command: command.to_string(),
+        day,
+        month,
+        report,
+    });
+}
+
+/// Runs the post-solve hook, if any, and exits with `code`.
+pub fn exit(code: i32) -> ! {
+    let post_solve = POST_SOLVE.lock().unwrap().take();
+    if let Some(post_solve) = post_solve {
+        let report = post_solve.report.as_deref();
+        run(
+            &post_solve.command,
+            post_solve.day,
+            post_solve.month,
+            report,
+        );
+    }
+    std::process::exit(code)
+}
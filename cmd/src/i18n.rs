@@ -0,0 +1,1585 @@
+use crate::results::HintLevel;
+use chrono::{Datelike, NaiveDate};
+use solver::{AuditIssue, DifficultyReport, Quadrant, ShapeError, SolverError};
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Lang {
+    /// English
+    En,
+    /// Norwegian (bokmål)
+    #[value(alias = "no")]
+    Nb,
+    /// German board labels, with the messages in English
+    De,
+    /// Swedish board labels, with the messages in English
+    Sv,
+    /// Danish board labels, with the messages in English
+    Da,
+    /// French board labels, with the messages in English
+    Fr,
+}
+
+// Picks the language from --lang, or else from the locale in the environment.
+pub fn init(lang: Option<Lang>) {
+    let _ = LANG.set(lang.unwrap_or_else(detect));
+}
+
+fn detect() -> Lang {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    match locale.get(..2) {
+        Some("nb" | "nn" | "no") => Lang::Nb,
+        Some("de") => Lang::De,
+        Some("sv") => Lang::Sv,
+        Some("da") => Lang::Da,
+        Some("fr") => Lang::Fr,
+        _ => Lang::En,
+    }
+}
+
+pub fn lang() -> Lang {
+    *LANG.get().unwrap_or(&Lang::En)
+}
+
+// The months the way they are printed on the board in the language, three letters each.
+pub fn month_labels() -> [&'static str; 12] {
+    match lang() {
+        Lang::En => [
+            "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+        ],
+        Lang::Nb => [
+            "JAN", "FEB", "MAR", "APR", "MAI", "JUN", "JUL", "AUG", "SEP", "OKT", "NOV", "DES",
+        ],
+        Lang::De => [
+            "JAN", "FEB", "MÄR", "APR", "MAI", "JUN", "JUL", "AUG", "SEP", "OKT", "NOV", "DEZ",
+        ],
+        Lang::Sv | Lang::Da => [
+            "JAN", "FEB", "MAR", "APR", "MAJ", "JUN", "JUL", "AUG", "SEP", "OKT", "NOV", "DEC",
+        ],
+        Lang::Fr => [
+            "JAN", "FÉV", "MAR", "AVR", "MAI", "JUN", "JUL", "AOÛ", "SEP", "OCT", "NOV", "DÉC",
+        ],
+    }
+}
+
+// The full name of the month of the date.
+pub fn month_name(date: NaiveDate) -> String {
+    match lang() {
+        Lang::Nb => NORWEGIAN_MONTHS[date.month0() as usize].to_string(),
+        Lang::En | Lang::De | Lang::Sv | Lang::Da | Lang::Fr => date.format("%B").to_string(),
+    }
+}
+
+// The full name of the weekday of the date.
+pub fn weekday_name(date: NaiveDate) -> String {
+    match lang() {
+        Lang::Nb => NORWEGIAN_WEEKDAYS[date.weekday().num_days_from_monday() as usize].to_string(),
+        Lang::En | Lang::De | Lang::Sv | Lang::Da | Lang::Fr => date.format("%A").to_string(),
+    }
+}
+
+// The month and day of the date, like "July 29".
+pub fn month_day(date: NaiveDate) -> String {
+    match lang() {
+        Lang::Nb => format!("{}. {}", date.day(), month_name(date)),
+        Lang::En | Lang::De | Lang::Sv | Lang::Da | Lang::Fr => date.format("%B %-d").to_string(),
+    }
+}
+
+// The user facing messages, shown in the chosen language with `{}`.
+pub enum Message<'a> {
+    Error(&'a dyn fmt::Display),
+    Warning(&'a dyn fmt::Display),
+    SolvingWeekday(NaiveDate),
+    NoSuchDate {
+        day: u8,
+        month: u8,
+        year: i32,
+    },
+    Solving {
+        day: u8,
+        month: u8,
+    },
+    EasyPlaced {
+        pieces: u8,
+        left: usize,
+    },
+    Solution {
+        number: usize,
+        elapsed: Duration,
+        test_count: u64,
+    },
+    SolutionCount {
+        count: usize,
+        elapsed: Duration,
+    },
+    NoHints,
+    Finished,
+    DeadEnd,
+    Hint {
+        number: usize,
+        solutions: usize,
+    },
+    HintQuadrant(Quadrant),
+    Forced {
+        bricks: usize,
+        solutions: usize,
+    },
+    NoForced {
+        solutions: usize,
+    },
+    DeadPlacements {
+        brick: u8,
+        count: usize,
+    },
+    Difficulty(&'a DifficultyReport),
+    DailyStart(NaiveDate),
+    DailyPrompt,
+    DailyHint {
+        number: u32,
+        level: HintLevel,
+        left: u32,
+    },
+    NoMoreHints,
+    HintBudgetSpent(u32),
+    DailyDone {
+        time: &'a str,
+        hints: u32,
+        path: &'a str,
+    },
+    CouldNotWrite {
+        path: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    CouldNotWriteOutput(&'a dyn fmt::Display),
+    DateChanged(NaiveDate),
+    SolutionFor(NaiveDate),
+    NoSolutionFor(NaiveDate),
+    BricksToMove(usize),
+    LeaderboardHeader,
+    ContactSheet {
+        count: usize,
+        path: &'a str,
+    },
+    ImagesWritten {
+        count: usize,
+        kind: &'a str,
+        path: &'a str,
+    },
+    OutputWritten(&'a str),
+    TimedOut(Duration),
+    ThreadStats {
+        number: usize,
+        solutions: usize,
+        test_count: u64,
+    },
+    OnlySolutionPictures,
+    StandardBricksOnly,
+    DateSummary {
+        date: NaiveDate,
+        solutions: usize,
+        test_count: u64,
+        elapsed: Duration,
+    },
+    BrowseSolution {
+        date: NaiveDate,
+        number: usize,
+        total: usize,
+    },
+    BrowseKeys,
+    RangeSummary {
+        dates: usize,
+        solutions: usize,
+        elapsed: Duration,
+    },
+    Cached {
+        date: NaiveDate,
+        solutions: usize,
+    },
+    AlreadyCached(NaiveDate),
+    Progress {
+        test_count: u64,
+        solutions: usize,
+        elapsed: Duration,
+    },
+    DifferentPuzzle(&'a str),
+    InvalidSignature(&'a str),
+    CouldNotRead {
+        path: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    HookFailed {
+        command: &'a str,
+        status: &'a dyn fmt::Display,
+    },
+    HookNotRun {
+        command: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    PagerNotStarted {
+        command: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    InvalidBrick {
+        brick: usize,
+        count: usize,
+    },
+    ShapeSizeDiffers {
+        brick: usize,
+        area: u32,
+        new_area: u32,
+    },
+    Swapping(usize),
+    SwapHeader,
+    SwapSummary {
+        broken: usize,
+        richer: usize,
+        poorer: usize,
+    },
+    NoRedundantBrick,
+    Replaceable(u8),
+    BrickAudited {
+        brick: usize,
+        issues: usize,
+    },
+    AuditIssue(AuditIssue),
+    ShareTitle(NaiveDate),
+    NoHintsUsed,
+    SolutionPanel(usize),
+    PanelTests(u64),
+    FewestSolutions,
+    MostSolutions,
+    MostBoardsTested,
+    FewestBoardsTested,
+    RankedDate {
+        rank: usize,
+        date: NaiveDate,
+        solutions: usize,
+        test_count: u64,
+    },
+    HistogramTitle,
+    // The header of the table of averages, with the groups in a column `width` wide.
+    AveragesHeader {
+        weekday: bool,
+        width: usize,
+    },
+    StablePlacement {
+        rank: usize,
+        brick: usize,
+        dates: usize,
+        total: usize,
+    },
+    // The dates of the year with fewer than `below` solutions, or that there are none.
+    FewSolutionDates {
+        year: i32,
+        below: usize,
+        found: bool,
+    },
+    FewSolutionDate {
+        date: NaiveDate,
+        solutions: usize,
+        test_count: u64,
+    },
+    NoSolutionIn(&'a str),
+    UnknownDate(usize),
+    Verified {
+        number: usize,
+        date: &'a str,
+        valid: bool,
+    },
+    NoDayInMonth {
+        day: u8,
+        month: u8,
+    },
+    // What is wrong with a solution to verify. Cells are (row, column) from 0.
+    CoversOutside {
+        names: &'a [&'a str],
+        cell: (u32, u32),
+    },
+    CoversDate {
+        names: &'a [&'a str],
+        cell: (u32, u32),
+    },
+    Overlap {
+        names: &'a [&'a str],
+        cell: (u32, u32),
+    },
+    Uncovered((u32, u32)),
+    NotABrick(&'a str),
+    NoBrick(usize),
+    WrongShape(&'a str),
+    UsedTwice {
+        name: &'a str,
+        brick: usize,
+    },
+    BrickName(usize),
+    InvalidBrickSet {
+        path: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    BrickSetSize {
+        path: &'a str,
+        bricks: usize,
+        max: usize,
+    },
+    // A shape of a brick set that is no brick, with the line it starts on.
+    InvalidBrickShape {
+        path: &'a str,
+        line: usize,
+        brick: usize,
+        name: Option<&'a str>,
+        shape: &'a [String],
+        error: &'a ShapeError,
+    },
+    // A day the month doesn't have, in the year when the date gave one.
+    NoDayInMonthOf {
+        day: u32,
+        month: u32,
+        year: Option<i32>,
+        text: Option<&'a str>,
+    },
+    InvalidMonthIn {
+        month: u32,
+        text: &'a str,
+    },
+    UnknownDateWord {
+        word: &'a str,
+        text: &'a str,
+        suggestion: &'a str,
+    },
+    DateNotUnderstood(&'a str),
+    InvalidConfigFile {
+        path: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    InvalidColor(&'a str),
+    InvalidRange(&'a str),
+    ReversedRange {
+        text: &'a str,
+        from: NaiveDate,
+        to: NaiveDate,
+    },
+    InvalidDuration(&'a str),
+    NotAResultFile(&'a str),
+    ExpectedKeyValue(&'a str),
+    InvalidResultField {
+        key: &'a str,
+        value: &'a str,
+        error: &'a dyn fmt::Display,
+    },
+    SpoilerLevels,
+    UnknownResultField(&'a str),
+    MissingResultField(&'a str),
+    GridRows(usize),
+    YearSolved {
+        dates: usize,
+        elapsed: Duration,
+        solutions: usize,
+    },
+    // The fewest or most solutions of any date, and the dates with that many.
+    FewestOfYear {
+        solutions: usize,
+        dates: &'a str,
+    },
+    MostOfYear {
+        solutions: usize,
+        dates: &'a str,
+    },
+    MeanOfYear(f64),
+    SlowestDates,
+    SlowDate {
+        date: &'a str,
+        test_count: u64,
+        elapsed: Duration,
+        solutions: usize,
+    },
+    SearchStatus {
+        test_count: u64,
+        solutions: usize,
+    },
+    SolutionFound {
+        number: usize,
+        test_count: u64,
+    },
+    SearchFinished(usize),
+    FramesWritten {
+        count: usize,
+        path: &'a str,
+    },
+    DatasetWritten(&'a str),
+    SolutionsChanged {
+        month: u8,
+        day: u8,
+        before: usize,
+        now: usize,
+    },
+    DatasetCompared {
+        dates: usize,
+        changed: usize,
+        added: usize,
+        missing: usize,
+    },
+    DatasetLine {
+        number: usize,
+        error: &'a dyn fmt::Display,
+    },
+    BoardCount {
+        path: &'a str,
+        boards: usize,
+    },
+    OutsideBoard(char),
+    TooManyOfBrick,
+    SizesDontCover {
+        cells: usize,
+        free: usize,
+    },
+    NoSetFound(usize),
+}
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match lang() {
+            Lang::Nb => norwegian(self, f),
+            Lang::En | Lang::De | Lang::Sv | Lang::Da | Lang::Fr => english(self, f),
+        }
+    }
+}
+
+fn english(message: &Message, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match message {
+        Message::Error(error) => write!(f, "ERROR: {error}"),
+        Message::Warning(warning) => write!(f, "WARNING: {warning}"),
+        Message::Solving { day, month } => write!(f, "Solving for day {day} and month {month}"),
+        Message::SolvingWeekday(date) => write!(f, "Solving for {}", date.format("%A %B %-d, %Y")),
+        Message::NoSuchDate { day, month, year } => {
+            write!(f, "There is no day {day} in month {month} of {year}")
+        }
+        Message::EasyPlaced { pieces, left } => {
+            write!(
+                f,
+                "{pieces} brick(s) placed for you, {left} solution(s) left:"
+            )
+        }
+        Message::Solution {
+            number,
+            elapsed,
+            test_count,
+        } => write!(
+            f,
+            "Solution {number} (time used:{elapsed:?}, test count: {test_count}):"
+        ),
+        Message::SolutionCount { count, elapsed } => {
+            write!(f, "{count} solution(s) (time used:{elapsed:?})")
+        }
+        Message::NoHints => write!(f, "No hints found!"),
+        Message::Finished => write!(f, "The bricks on the board can be finished like this:"),
+        Message::DeadEnd => write!(
+            f,
+            "Dead end: the bricks on the board can't be finished, some have to be moved"
+        ),
+        Message::Hint { number, solutions } => {
+            write!(f, "Hint {number} has {solutions} possible solutions")
+        }
+        Message::HintQuadrant(quadrant) => {
+            let quadrant = match quadrant {
+                Quadrant::TopLeft => "top left",
+                Quadrant::TopRight => "top right",
+                Quadrant::BottomLeft => "bottom left",
+                Quadrant::BottomRight => "bottom right",
+            };
+            write!(f, "It goes in the {quadrant} of the board")
+        }
+        Message::Forced { bricks, solutions } => write!(
+            f,
+            "{bricks} brick(s) go in the same place in all {solutions} solution(s):"
+        ),
+        Message::NoForced { solutions: 0 } => write!(f, "There are no solutions"),
+        Message::NoForced { solutions } => write!(
+            f,
+            "No brick goes in the same place in all {solutions} solutions"
+        ),
+        Message::DeadPlacements { brick, count } => write!(
+            f,
+            "Brick {brick}: {count} placement(s) in no solution, not worth trying"
+        ),
+        Message::Difficulty(report) => write!(
+            f,
+            "Difficulty {} of 5, {}: {} solution(s), {} forced placement(s), {} board(s) tested \
+             before the first solution",
+            report.rating,
+            ["easy", "fair", "tricky", "hard", "brutal"]
+                [usize::from(report.rating.clamp(1, 5)) - 1],
+            report.solutions,
+            report.forced_placements,
+            report.boards_tested
+        ),
+        Message::DailyStart(date) => write!(
+            f,
+            "Today's puzzle is {}. The clock is running!",
+            date.format("%B %-d")
+        ),
+        Message::DailyPrompt => write!(
+            f,
+            "Press Enter when you are done, or type h and Enter for a hint."
+        ),
+        Message::DailyHint {
+            number,
+            level,
+            left,
+        } => {
+            let shows = match level {
+                HintLevel::Cell => "a cell of a brick",
+                HintLevel::Piece => "the brick covering it",
+                HintLevel::Placement => "where the brick goes",
+            };
+            write!(f, "Hint {number}, {shows} ({left} left):")
+        }
+        Message::NoMoreHints => write!(f, "No more hints."),
+        Message::HintBudgetSpent(budget) => {
+            write!(f, "You have used all {budget} hints for today.")
+        }
+        Message::DailyDone { time, hints, path } => write!(
+            f,
+            "Solved in {time} with {hints} hint(s). Result written to {path}"
+        ),
+        Message::CouldNotWrite { path, error } => write!(f, "Could not write {path}: {error}"),
+        Message::CouldNotWriteOutput(error) => write!(f, "Could not write the output: {error}"),
+        Message::DateChanged(date) => write!(
+            f,
+            "The date changed to {date}. Yesterday's solution no longer fits:"
+        ),
+        Message::SolutionFor(date) => write!(f, "Solution for {date}:"),
+        Message::NoSolutionFor(date) => write!(f, "No solution found for {date}!"),
+        Message::BricksToMove(count) => write!(f, "{count} brick(s) have to move."),
+        Message::LeaderboardHeader => {
+            write!(
+                f,
+                "{:>4}  {:<20} {:>9} {:>6}",
+                "Rank", "Name", "Time", "Hints"
+            )
+        }
+        Message::ContactSheet { count, path } => {
+            write!(f, "Drew {count} solution(s) to {path}")
+        }
+        Message::ImagesWritten { count, kind, path } => {
+            write!(f, "Wrote {count} solution(s) as {kind} to {path}")
+        }
+        Message::OutputWritten(path) => write!(f, "Wrote the output to {path}"),
+        Message::ThreadStats {
+            number,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "Thread {number}: {solutions} solution(s), {test_count} boards tested"
+        ),
+        Message::TimedOut(timeout) => write!(
+            f,
+            "Truncated: the search stopped after {timeout:?}, so there may be more solutions"
+        ),
+        Message::OnlySolutionPictures => {
+            write!(
+                f,
+                "Only solutions can be drawn as PNG or SVG, not hints or ranges of dates"
+            )
+        }
+        Message::StandardBricksOnly => {
+            write!(
+                f,
+                "Only solving and verify can use another brick set with --bricks"
+            )
+        }
+        Message::DateSummary {
+            date,
+            solutions,
+            test_count,
+            elapsed,
+        } => write!(
+            f,
+            "{date}: {solutions:>3} solution(s), {test_count:>6} boards tested in {elapsed:.1?}"
+        ),
+        Message::RangeSummary {
+            dates,
+            solutions,
+            elapsed,
+        } => write!(
+            f,
+            "{dates} date(s), {solutions} solution(s) in total (time used:{elapsed:?})"
+        ),
+        Message::BrowseSolution {
+            date,
+            number,
+            total,
+        } => write!(f, "{}, solution {number} of {total}", date.format("%B %-d")),
+        Message::BrowseKeys => write!(f, "←/→ solution  ↑/↓ date  h hints on/off  q quit"),
+        Message::Progress {
+            test_count,
+            solutions,
+            elapsed,
+        } => write!(
+            f,
+            "Tested {test_count} boards, found {solutions} solution(s) in {:.1}s",
+            elapsed.as_secs_f64()
+        ),
+        Message::Cached { date, solutions } => {
+            write!(f, "{date}: cached {solutions} solution(s)")
+        }
+        Message::AlreadyCached(date) => write!(f, "{date}: already cached"),
+        Message::DifferentPuzzle(path) => {
+            write!(f, "{path} is for a different puzzle, skipping it")
+        }
+        Message::InvalidSignature(path) => {
+            write!(f, "{path} has an invalid signature, skipping it")
+        }
+        Message::CouldNotRead { path, error } => write!(f, "Could not read {path}: {error}"),
+        Message::HookFailed { command, status } => {
+            write!(f, "Hook `{command}` failed: {status}")
+        }
+        Message::HookNotRun { command, error } => {
+            write!(f, "Could not run hook `{command}`: {error}")
+        }
+        Message::PagerNotStarted { command, error } => {
+            write!(f, "Could not start pager `{command}`: {error}")
+        }
+        Message::InvalidBrick { brick, count } => {
+            write!(f, "Invalid brick {brick}. Valid bricks: 1-{count}")
+        }
+        Message::ShapeSizeDiffers {
+            brick,
+            area,
+            new_area,
+        } => write!(
+            f,
+            "Brick {brick} has {area} cells, the new shape has {new_area}"
+        ),
+        Message::Swapping(brick) => write!(f, "Swapping brick {brick}"),
+        Message::SwapHeader => write!(f, "{:<8}{:>7} {:>6}", "Date", "Before", "After"),
+        Message::SwapSummary {
+            broken,
+            richer,
+            poorer,
+        } => write!(
+            f,
+            "{broken} date(s) break, {richer} get more solutions and {poorer} fewer."
+        ),
+        Message::NoRedundantBrick => write!(
+            f,
+            "No brick can be swapped for a shape one cell different without losing some date."
+        ),
+        Message::Replaceable(brick) => {
+            write!(f, "Brick {brick} could be replaced by any of these shapes:")
+        }
+        Message::BrickAudited { brick, issues: 0 } => write!(f, "Brick {brick} ok"),
+        Message::BrickAudited { brick, issues } => {
+            write!(f, "Brick {brick} has {issues} issues:")
+        }
+        Message::AuditIssue(issue) => match issue {
+            AuditIssue::MissingOrientation(pattern) => {
+                write!(f, "missing orientation {pattern:#018x}")
+            }
+            AuditIssue::UnexpectedVariant(pattern) => {
+                write!(
+                    f,
+                    "variant {pattern:#018x} is not an orientation of the brick"
+                )
+            }
+            AuditIssue::Shifted(pattern) => {
+                write!(f, "variant {pattern:#018x} is not in the top left corner")
+            }
+            AuditIssue::DuplicateVariant(pattern) => {
+                write!(f, "variant {pattern:#018x} is listed more than once")
+            }
+        },
+        Message::ShareTitle(date) => write!(f, "Calendar puzzle {}", month_day(*date)),
+        Message::NoHintsUsed => write!(f, "no hints"),
+        Message::SolutionPanel(number) => write!(f, "Solution {number}"),
+        Message::PanelTests(test_count) => write!(f, "{test_count} tests"),
+        Message::FewestSolutions => write!(f, "Fewest solutions"),
+        Message::MostSolutions => write!(f, "Most solutions"),
+        Message::MostBoardsTested => write!(f, "Most boards tested"),
+        Message::FewestBoardsTested => write!(f, "Fewest boards tested"),
+        Message::RankedDate {
+            rank,
+            date,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "{rank:>3}. {:<13} {solutions:>4} solutions {test_count:>7} boards tested",
+            month_day(*date)
+        ),
+        Message::HistogramTitle => write!(f, "Dates by number of solutions:"),
+        Message::AveragesHeader { weekday, width } => write!(
+            f,
+            "{:<width$}  {:>5}  {:>17}",
+            if *weekday { "Weekday" } else { "Month" },
+            "Dates",
+            "Average solutions"
+        ),
+        Message::StablePlacement {
+            rank,
+            brick,
+            dates,
+            total,
+        } => write!(
+            f,
+            "{rank}. Brick {brick} works on {dates} of {total} dates:"
+        ),
+        Message::FewSolutionDates { year, below, found } => {
+            let wanted = match below {
+                2 => "a unique solution".to_string(),
+                below => format!("fewer than {below} solutions"),
+            };
+            match found {
+                true => write!(f, "Dates in {year} with {wanted}:"),
+                false => write!(f, "No date in {year} has {wanted}"),
+            }
+        }
+        Message::FewSolutionDate {
+            date,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "{:<13} {solutions:>4} solution(s) {test_count:>7} boards tested",
+            month_day(*date)
+        ),
+        Message::NoSolutionIn(path) => write!(f, "No solution found in {path}"),
+        Message::UnknownDate(number) => write!(
+            f,
+            "Solution {number}: could not tell the date from the uncovered cells, give it with \
+             --date"
+        ),
+        Message::Verified {
+            number,
+            date,
+            valid: true,
+        } => write!(f, "Solution {number} for {date} is valid"),
+        Message::Verified { number, date, .. } => {
+            write!(f, "Solution {number} for {date} is not valid:")
+        }
+        Message::NoDayInMonth { day, month } => {
+            write!(f, "There is no day {day} in month {month}")
+        }
+        Message::CoversOutside { names, cell } => write!(
+            f,
+            "{} covers {} outside the board",
+            names.join(" and "),
+            english_cell(*cell)
+        ),
+        Message::CoversDate { names, cell } => write!(
+            f,
+            "{} covers the date at {}",
+            names.join(" and "),
+            english_cell(*cell)
+        ),
+        Message::Overlap { names, cell } => write!(
+            f,
+            "{} overlap at {}",
+            names.join(" and "),
+            english_cell(*cell)
+        ),
+        Message::Uncovered(cell) => write!(f, "Nothing covers {}", english_cell(*cell)),
+        Message::NotABrick(name) => write!(f, "{name} is not the shape of any brick"),
+        Message::NoBrick(number) => write!(f, "There is no brick {number}"),
+        Message::WrongShape(name) => write!(f, "{name} has the wrong shape"),
+        Message::UsedTwice { name, brick } => {
+            write!(f, "{name} is brick {brick}, which is used more than once")
+        }
+        Message::BrickName(number) => write!(f, "brick {number}"),
+        Message::InvalidBrickSet { path, error } => write!(f, "Invalid brick set {path}: {error}"),
+        Message::BrickSetSize { path, bricks, max } => write!(
+            f,
+            "Invalid brick set {path}: it has {bricks} bricks, expected 1-{max}"
+        ),
+        Message::InvalidBrickShape {
+            path,
+            line,
+            brick,
+            name,
+            shape,
+            error,
+        } => {
+            let name = name.map_or(String::new(), |name| format!(" ({name})"));
+            write!(
+                f,
+                "Invalid brick set {path}, line {line}: brick {brick}{name} {shape:?}: {error}"
+            )
+        }
+        Message::NoDayInMonthOf {
+            day,
+            month,
+            year,
+            text,
+        } => {
+            write!(f, "{}", english_month(*month))?;
+            if let Some(year) = year {
+                write!(f, " {year}")?;
+            }
+            write!(f, " has no day {day}")?;
+            match text {
+                Some(text) => write!(f, ", in date `{text}`"),
+                None => Ok(()),
+            }
+        }
+        Message::InvalidMonthIn { month, text } => write!(
+            f,
+            "Invalid month {month} in date `{text}`. Valid months: 1-12"
+        ),
+        Message::UnknownDateWord {
+            word,
+            text,
+            suggestion,
+        } => write!(
+            f,
+            "Unknown word `{word}` in date `{text}`, did you mean `{suggestion}`?"
+        ),
+        Message::DateNotUnderstood(text) => write!(
+            f,
+            "Could not understand the date `{text}`. Try something like `today`, `next friday`, \
+             `29 july`, `29.7` or `2025-07-29`"
+        ),
+        Message::InvalidConfigFile { path, error } => {
+            write!(f, "Invalid config file {path}: {error}")
+        }
+        Message::InvalidColor(text) => {
+            write!(f, "Invalid color {text}, expected one like \"#c0ffee\"")
+        }
+        Message::InvalidRange(text) => write!(
+            f,
+            "Invalid range {text}, expected one like 2025-01-01..2025-03-31"
+        ),
+        Message::ReversedRange { text, from, to } => write!(
+            f,
+            "Invalid range {text}: it ends on {to}, before it starts on {from}"
+        ),
+        Message::InvalidDuration(text) => {
+            write!(
+                f,
+                "Invalid duration {text}, expected one like 5s, 500ms or 2m"
+            )
+        }
+        Message::NotAResultFile(header) => {
+            write!(f, "Not a result file, expected it to start with `{header}`")
+        }
+        Message::ExpectedKeyValue(line) => write!(f, "Expected `key = value`, found `{line}`"),
+        Message::InvalidResultField { key, value, error } => {
+            write!(f, "Invalid {key} `{value}`: {error}")
+        }
+        Message::SpoilerLevels => write!(f, "expected cell, piece or placement"),
+        Message::UnknownResultField(key) => write!(f, "Unknown field `{key}`"),
+        Message::MissingResultField(field) => write!(f, "Missing field `{field}`"),
+        Message::GridRows(rows) => {
+            write!(f, "{rows} grid row(s), expected 7 for each board")
+        }
+        Message::YearSolved {
+            dates,
+            elapsed,
+            solutions,
+        } => write!(
+            f,
+            "{dates} dates solved in {elapsed:.1?}, {solutions} solutions in total"
+        ),
+        Message::FewestOfYear { solutions, dates } => {
+            write!(f, "Fewest solutions: {solutions:>4} ({dates})")
+        }
+        Message::MostOfYear { solutions, dates } => {
+            write!(f, "Most solutions:   {solutions:>4} ({dates})")
+        }
+        Message::MeanOfYear(mean) => write!(f, "Mean:             {mean:>4.1} solutions per date"),
+        Message::SlowestDates => write!(f, "Slowest dates:"),
+        Message::SlowDate {
+            date,
+            test_count,
+            elapsed,
+            solutions,
+        } => write!(
+            f,
+            "{date}: {test_count:>6} boards tested in {elapsed:.1?}, {solutions} solutions"
+        ),
+        Message::SearchStatus {
+            test_count,
+            solutions,
+        } => write!(f, "Nodes tested: {test_count}  Solutions: {solutions}"),
+        Message::SolutionFound { number, test_count } => {
+            write!(f, "Solution {number} found after {test_count} nodes")
+        }
+        Message::SearchFinished(solutions) => {
+            write!(f, "Search finished with {solutions} solution(s)")
+        }
+        Message::FramesWritten { count, path } => write!(f, "Wrote {count} frame(s) to {path}"),
+        Message::DatasetWritten(path) => write!(f, "Wrote the solutions of 366 dates to {path}"),
+        Message::SolutionsChanged {
+            month,
+            day,
+            before,
+            now,
+        } => write!(
+            f,
+            "{month:02}-{day:02}: {before} solution(s) before, {now} now"
+        ),
+        Message::DatasetCompared {
+            dates,
+            changed,
+            added,
+            missing,
+        } => write!(
+            f,
+            "{dates} date(s) compared, {changed} changed: {added} solution(s) added, {missing} \
+             missing"
+        ),
+        Message::DatasetLine { number, error } => write!(f, "line {number}: {error}"),
+        Message::BoardCount { path, boards } => {
+            write!(f, "Expected one board in {path}, found {boards}")
+        }
+        Message::OutsideBoard(letter) => write!(f, "{letter} covers cells outside the board"),
+        Message::TooManyOfBrick => write!(
+            f,
+            "The pieces on the board need more of a brick than there is"
+        ),
+        Message::SizesDontCover { cells, free } => write!(
+            f,
+            "The sizes add up to {cells} cells, but a date leaves {free} to cover"
+        ),
+        Message::NoSetFound(attempts) => write!(
+            f,
+            "No set solving every date found in {attempts} attempts, try another --seed"
+        ),
+    }
+}
+
+// The English name of the month numbered from 1.
+fn english_month(month: u32) -> &'static str {
+    chrono::Month::try_from(month as u8).unwrap().name()
+}
+
+// Cells are counted from 1 at the top left, like a person reading the board would.
+fn english_cell((row, column): (u32, u32)) -> String {
+    format!("row {}, column {}", row + 1, column + 1)
+}
+
+const NORWEGIAN_MONTHS: [&str; 12] = [
+    "januar",
+    "februar",
+    "mars",
+    "april",
+    "mai",
+    "juni",
+    "juli",
+    "august",
+    "september",
+    "oktober",
+    "november",
+    "desember",
+];
+
+const NORWEGIAN_WEEKDAYS: [&str; 7] = [
+    "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+];
+
+fn norwegian(message: &Message, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match message {
+        Message::Error(error) => write!(f, "FEIL: {error}"),
+        Message::Warning(warning) => write!(f, "ADVARSEL: {warning}"),
+        Message::Solving { day, month } => write!(f, "Løser for dag {day} og måned {month}"),
+        Message::SolvingWeekday(date) => write!(
+            f,
+            "Løser for {} {}. {} {}",
+            NORWEGIAN_WEEKDAYS[date.weekday().num_days_from_monday() as usize],
+            date.day(),
+            NORWEGIAN_MONTHS[date.month0() as usize],
+            date.year()
+        ),
+        Message::NoSuchDate { day, month, year } => {
+            write!(f, "Det finnes ingen dag {day} i måned {month} i {year}")
+        }
+        Message::EasyPlaced { pieces, left } => {
+            write!(
+                f,
+                "{pieces} brikke(r) lagt for deg, {left} løsning(er) igjen:"
+            )
+        }
+        Message::Solution {
+            number,
+            elapsed,
+            test_count,
+        } => write!(
+            f,
+            "Løsning {number} (tidsbruk:{elapsed:?}, antall forsøk: {test_count}):"
+        ),
+        Message::SolutionCount { count, elapsed } => {
+            write!(f, "{count} løsning(er) (tidsbruk:{elapsed:?})")
+        }
+        Message::NoHints => write!(f, "Fant ingen hint!"),
+        Message::Finished => write!(f, "Brikkene på brettet kan fullføres slik:"),
+        Message::DeadEnd => write!(
+            f,
+            "Blindvei: brikkene på brettet kan ikke fullføres, noen må flyttes"
+        ),
+        Message::Hint { number, solutions } => {
+            write!(f, "Hint {number} har {solutions} mulige løsninger")
+        }
+        Message::HintQuadrant(quadrant) => {
+            let quadrant = match quadrant {
+                Quadrant::TopLeft => "øvre venstre",
+                Quadrant::TopRight => "øvre høyre",
+                Quadrant::BottomLeft => "nedre venstre",
+                Quadrant::BottomRight => "nedre høyre",
+            };
+            write!(f, "Den skal i {quadrant} del av brettet")
+        }
+        Message::Forced { bricks, solutions } => write!(
+            f,
+            "{bricks} brikke(r) ligger på samme sted i alle {solutions} løsning(er):"
+        ),
+        Message::NoForced { solutions: 0 } => write!(f, "Det finnes ingen løsninger"),
+        Message::NoForced { solutions } => write!(
+            f,
+            "Ingen brikke ligger på samme sted i alle {solutions} løsningene"
+        ),
+        Message::DeadPlacements { brick, count } => write!(
+            f,
+            "Brikke {brick}: {count} plassering(er) uten løsning, ikke verdt å prøve"
+        ),
+        Message::Difficulty(report) => write!(
+            f,
+            "Vanskelighet {} av 5, {}: {} løsning(er), {} tvungen(e) plassering(er), {} brett \
+             testet før første løsning",
+            report.rating,
+            ["lett", "grei", "vrien", "vanskelig", "brutal"]
+                [usize::from(report.rating.clamp(1, 5)) - 1],
+            report.solutions,
+            report.forced_placements,
+            report.boards_tested
+        ),
+        Message::DailyStart(date) => write!(
+            f,
+            "Dagens oppgave er {}. {}. Klokken går!",
+            date.day(),
+            NORWEGIAN_MONTHS[date.month0() as usize]
+        ),
+        Message::DailyPrompt => write!(
+            f,
+            "Trykk Enter når du er ferdig, eller skriv h og Enter for et hint."
+        ),
+        Message::DailyHint {
+            number,
+            level,
+            left,
+        } => {
+            let shows = match level {
+                HintLevel::Cell => "en rute under en brikke",
+                HintLevel::Piece => "brikken som dekker den",
+                HintLevel::Placement => "hvor brikken skal ligge",
+            };
+            write!(f, "Hint {number}, {shows} ({left} igjen):")
+        }
+        Message::NoMoreHints => write!(f, "Ingen flere hint."),
+        Message::HintBudgetSpent(budget) => {
+            write!(f, "Du har brukt alle {budget} hintene for i dag.")
+        }
+        Message::DailyDone { time, hints, path } => write!(
+            f,
+            "Løst på {time} med {hints} hint. Resultatet er skrevet til {path}"
+        ),
+        Message::CouldNotWrite { path, error } => {
+            write!(f, "Kunne ikke skrive {path}: {error}")
+        }
+        Message::CouldNotWriteOutput(error) => write!(f, "Kunne ikke skrive utdataene: {error}"),
+        Message::DateChanged(date) => write!(
+            f,
+            "Datoen ble {date}. Gårsdagens løsning passer ikke lenger:"
+        ),
+        Message::SolutionFor(date) => write!(f, "Løsning for {date}:"),
+        Message::NoSolutionFor(date) => write!(f, "Fant ingen løsning for {date}!"),
+        Message::BricksToMove(count) => write!(f, "{count} brikke(r) må flyttes."),
+        Message::LeaderboardHeader => write!(
+            f,
+            "{:>4}  {:<20} {:>9} {:>6}",
+            "Plass", "Navn", "Tid", "Hint"
+        ),
+        Message::ContactSheet { count, path } => {
+            write!(f, "Tegnet {count} løsning(er) til {path}")
+        }
+        Message::ImagesWritten { count, kind, path } => {
+            write!(f, "Skrev {count} løsning(er) som {kind} til {path}")
+        }
+        Message::OutputWritten(path) => write!(f, "Skrev utdataene til {path}"),
+        Message::ThreadStats {
+            number,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "Tråd {number}: {solutions} løsning(er), {test_count} brett testet"
+        ),
+        Message::TimedOut(timeout) => write!(
+            f,
+            "Avkortet: søket stoppet etter {timeout:?}, så det kan finnes flere løsninger"
+        ),
+        Message::OnlySolutionPictures => {
+            write!(
+                f,
+                "Bare løsninger kan tegnes som PNG eller SVG, ikke hint eller datoperioder"
+            )
+        }
+        Message::StandardBricksOnly => {
+            write!(
+                f,
+                "Bare løsing og verify kan bruke et annet brikkesett med --bricks"
+            )
+        }
+        Message::DateSummary {
+            date,
+            solutions,
+            test_count,
+            elapsed,
+        } => write!(
+            f,
+            "{date}: {solutions:>3} løsning(er), {test_count:>6} brett testet på {elapsed:.1?}"
+        ),
+        Message::RangeSummary {
+            dates,
+            solutions,
+            elapsed,
+        } => write!(
+            f,
+            "{dates} dato(er), {solutions} løsning(er) totalt (tidsbruk:{elapsed:?})"
+        ),
+        Message::BrowseSolution {
+            date,
+            number,
+            total,
+        } => write!(
+            f,
+            "{}. {}, løsning {number} av {total}",
+            date.day(),
+            NORWEGIAN_MONTHS[date.month0() as usize]
+        ),
+        Message::BrowseKeys => write!(f, "←/→ løsning  ↑/↓ dato  h hint av/på  q avslutt"),
+        Message::Progress {
+            test_count,
+            solutions,
+            elapsed,
+        } => write!(
+            f,
+            "Testet {test_count} brett, fant {solutions} løsning(er) på {:.1}s",
+            elapsed.as_secs_f64()
+        ),
+        Message::Cached { date, solutions } => {
+            write!(f, "{date}: lagret {solutions} løsning(er)")
+        }
+        Message::AlreadyCached(date) => write!(f, "{date}: allerede lagret"),
+        Message::DifferentPuzzle(path) => {
+            write!(f, "{path} er for en annen oppgave, hopper over den")
+        }
+        Message::InvalidSignature(path) => {
+            write!(f, "{path} har en ugyldig signatur, hopper over den")
+        }
+        Message::CouldNotRead { path, error } => write!(f, "Kunne ikke lese {path}: {error}"),
+        Message::HookFailed { command, status } => {
+            write!(f, "Kroken `{command}` feilet: {status}")
+        }
+        Message::HookNotRun { command, error } => {
+            write!(f, "Kunne ikke kjøre kroken `{command}`: {error}")
+        }
+        Message::PagerNotStarted { command, error } => {
+            write!(f, "Kunne ikke starte sidevisningen `{command}`: {error}")
+        }
+        Message::InvalidBrick { brick, count } => {
+            write!(f, "Ugyldig brikke {brick}. Gyldige brikker: 1-{count}")
+        }
+        Message::ShapeSizeDiffers {
+            brick,
+            area,
+            new_area,
+        } => write!(
+            f,
+            "Brikke {brick} har {area} ruter, den nye formen har {new_area}"
+        ),
+        Message::Swapping(brick) => write!(f, "Bytter brikke {brick}"),
+        Message::SwapHeader => write!(f, "{:<8}{:>7} {:>6}", "Dato", "Før", "Etter"),
+        Message::SwapSummary {
+            broken,
+            richer,
+            poorer,
+        } => write!(
+            f,
+            "{broken} dato(er) mister alle løsninger, {richer} får flere og {poorer} færre."
+        ),
+        Message::NoRedundantBrick => write!(
+            f,
+            "Ingen brikke kan byttes mot en form som er én rute annerledes uten å miste en dato."
+        ),
+        Message::Replaceable(brick) => {
+            write!(
+                f,
+                "Brikke {brick} kan byttes mot hvilken som helst av disse formene:"
+            )
+        }
+        Message::BrickAudited { brick, issues: 0 } => write!(f, "Brikke {brick} ok"),
+        Message::BrickAudited { brick, issues } => {
+            write!(f, "Brikke {brick} har {issues} feil:")
+        }
+        Message::AuditIssue(issue) => match issue {
+            AuditIssue::MissingOrientation(pattern) => {
+                write!(f, "mangler retningen {pattern:#018x}")
+            }
+            AuditIssue::UnexpectedVariant(pattern) => {
+                write!(f, "varianten {pattern:#018x} er ikke en retning av brikken")
+            }
+            AuditIssue::Shifted(pattern) => {
+                write!(f, "varianten {pattern:#018x} er ikke i øvre venstre hjørne")
+            }
+            AuditIssue::DuplicateVariant(pattern) => {
+                write!(f, "varianten {pattern:#018x} står der mer enn én gang")
+            }
+        },
+        Message::ShareTitle(date) => write!(f, "Kalenderpuslespill {}", month_day(*date)),
+        Message::NoHintsUsed => write!(f, "ingen hint"),
+        Message::SolutionPanel(number) => write!(f, "Løsning {number}"),
+        Message::PanelTests(test_count) => write!(f, "{test_count} forsøk"),
+        Message::FewestSolutions => write!(f, "Færrest løsninger"),
+        Message::MostSolutions => write!(f, "Flest løsninger"),
+        Message::MostBoardsTested => write!(f, "Flest brett testet"),
+        Message::FewestBoardsTested => write!(f, "Færrest brett testet"),
+        Message::RankedDate {
+            rank,
+            date,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "{rank:>3}. {:<13} {solutions:>4} løsninger {test_count:>7} brett testet",
+            month_day(*date)
+        ),
+        Message::HistogramTitle => write!(f, "Datoer etter antall løsninger:"),
+        Message::AveragesHeader { weekday, width } => write!(
+            f,
+            "{:<width$}  {:>5}  {:>17}",
+            if *weekday { "Ukedag" } else { "Måned" },
+            "Dager",
+            "Snitt løsninger"
+        ),
+        Message::StablePlacement {
+            rank,
+            brick,
+            dates,
+            total,
+        } => write!(
+            f,
+            "{rank}. Brikke {brick} passer på {dates} av {total} datoer:"
+        ),
+        Message::FewSolutionDates { year, below, found } => {
+            let wanted = match below {
+                2 => "én løsning".to_string(),
+                below => format!("færre enn {below} løsninger"),
+            };
+            match found {
+                true => write!(f, "Datoer i {year} med {wanted}:"),
+                false => write!(f, "Ingen dato i {year} har {wanted}"),
+            }
+        }
+        Message::FewSolutionDate {
+            date,
+            solutions,
+            test_count,
+        } => write!(
+            f,
+            "{:<13} {solutions:>4} løsning(er) {test_count:>7} brett testet",
+            month_day(*date)
+        ),
+        Message::NoSolutionIn(path) => write!(f, "Fant ingen løsning i {path}"),
+        Message::UnknownDate(number) => write!(
+            f,
+            "Løsning {number}: kunne ikke se datoen ut fra rutene som ikke er dekket, oppgi den \
+             med --date"
+        ),
+        Message::Verified {
+            number,
+            date,
+            valid: true,
+        } => write!(f, "Løsning {number} for {date} er gyldig"),
+        Message::Verified { number, date, .. } => {
+            write!(f, "Løsning {number} for {date} er ikke gyldig:")
+        }
+        Message::NoDayInMonth { day, month } => {
+            write!(f, "Det finnes ingen dag {day} i måned {month}")
+        }
+        Message::CoversOutside { names, cell } => write!(
+            f,
+            "{} dekker {} utenfor brettet",
+            names.join(" og "),
+            norwegian_cell(*cell)
+        ),
+        Message::CoversDate { names, cell } => write!(
+            f,
+            "{} dekker datoen i {}",
+            names.join(" og "),
+            norwegian_cell(*cell)
+        ),
+        Message::Overlap { names, cell } => write!(
+            f,
+            "{} overlapper i {}",
+            names.join(" og "),
+            norwegian_cell(*cell)
+        ),
+        Message::Uncovered(cell) => write!(f, "Ingenting dekker {}", norwegian_cell(*cell)),
+        Message::NotABrick(name) => write!(f, "{name} har ikke formen til noen brikke"),
+        Message::NoBrick(number) => write!(f, "Det finnes ingen brikke {number}"),
+        Message::WrongShape(name) => write!(f, "{name} har feil form"),
+        Message::UsedTwice { name, brick } => {
+            write!(f, "{name} er brikke {brick}, som er brukt mer enn én gang")
+        }
+        Message::BrickName(number) => write!(f, "brikke {number}"),
+        Message::InvalidBrickSet { path, error } => {
+            write!(f, "Ugyldig brikkesett {path}: {error}")
+        }
+        Message::BrickSetSize { path, bricks, max } => write!(
+            f,
+            "Ugyldig brikkesett {path}: det har {bricks} brikker, forventet 1-{max}"
+        ),
+        Message::InvalidBrickShape {
+            path,
+            line,
+            brick,
+            name,
+            shape,
+            error,
+        } => {
+            let name = name.map_or(String::new(), |name| format!(" ({name})"));
+            let error = LocalizedShape(error);
+            write!(
+                f,
+                "Ugyldig brikkesett {path}, linje {line}: brikke {brick}{name} {shape:?}: {error}"
+            )
+        }
+        Message::NoDayInMonthOf {
+            day,
+            month,
+            year,
+            text,
+        } => {
+            let month = NORWEGIAN_MONTHS[*month as usize - 1];
+            write!(f, "Det finnes ingen dag {day} i {month}")?;
+            if let Some(year) = year {
+                write!(f, " {year}")?;
+            }
+            match text {
+                Some(text) => write!(f, ", i datoen `{text}`"),
+                None => Ok(()),
+            }
+        }
+        Message::InvalidMonthIn { month, text } => write!(
+            f,
+            "Ugyldig måned {month} i datoen `{text}`. Gyldige måneder: 1-12"
+        ),
+        Message::UnknownDateWord {
+            word,
+            text,
+            suggestion,
+        } => write!(
+            f,
+            "Ukjent ord `{word}` i datoen `{text}`, mente du `{suggestion}`?"
+        ),
+        Message::DateNotUnderstood(text) => write!(
+            f,
+            "Forstod ikke datoen `{text}`. Prøv noe som `today`, `next friday`, `29 july`, `29.7` \
+             eller `2025-07-29`"
+        ),
+        Message::InvalidConfigFile { path, error } => {
+            write!(f, "Ugyldig konfigurasjonsfil {path}: {error}")
+        }
+        Message::InvalidColor(text) => {
+            write!(f, "Ugyldig farge {text}, forventet en som \"#c0ffee\"")
+        }
+        Message::InvalidRange(text) => write!(
+            f,
+            "Ugyldig periode {text}, forventet en som 2025-01-01..2025-03-31"
+        ),
+        Message::ReversedRange { text, from, to } => write!(
+            f,
+            "Ugyldig periode {text}: den slutter {to}, før den starter {from}"
+        ),
+        Message::InvalidDuration(text) => {
+            write!(
+                f,
+                "Ugyldig varighet {text}, forventet en som 5s, 500ms eller 2m"
+            )
+        }
+        Message::NotAResultFile(header) => {
+            write!(
+                f,
+                "Ikke en resultatfil, forventet at den starter med `{header}`"
+            )
+        }
+        Message::ExpectedKeyValue(line) => {
+            write!(f, "Forventet `nøkkel = verdi`, fant `{line}`")
+        }
+        Message::InvalidResultField { key, value, error } => {
+            write!(f, "Ugyldig {key} `{value}`: {error}")
+        }
+        Message::SpoilerLevels => write!(f, "forventet cell, piece eller placement"),
+        Message::UnknownResultField(key) => write!(f, "Ukjent felt `{key}`"),
+        Message::MissingResultField(field) => write!(f, "Mangler feltet `{field}`"),
+        Message::GridRows(rows) => {
+            write!(f, "{rows} rad(er) i rutenettet, ventet 7 for hvert brett")
+        }
+        Message::YearSolved {
+            dates,
+            elapsed,
+            solutions,
+        } => write!(
+            f,
+            "{dates} datoer løst på {elapsed:.1?}, {solutions} løsninger totalt"
+        ),
+        Message::FewestOfYear { solutions, dates } => {
+            write!(f, "Færrest løsninger: {solutions:>4} ({dates})")
+        }
+        Message::MostOfYear { solutions, dates } => {
+            write!(f, "Flest løsninger:   {solutions:>4} ({dates})")
+        }
+        Message::MeanOfYear(mean) => {
+            write!(f, "Snitt:             {mean:>4.1} løsninger per dato")
+        }
+        Message::SlowestDates => write!(f, "Tregeste datoer:"),
+        Message::SlowDate {
+            date,
+            test_count,
+            elapsed,
+            solutions,
+        } => write!(
+            f,
+            "{date}: {test_count:>6} brett testet på {elapsed:.1?}, {solutions} løsninger"
+        ),
+        Message::SearchStatus {
+            test_count,
+            solutions,
+        } => write!(f, "Noder testet: {test_count}  Løsninger: {solutions}"),
+        Message::SolutionFound { number, test_count } => {
+            write!(f, "Løsning {number} funnet etter {test_count} noder")
+        }
+        Message::SearchFinished(solutions) => {
+            write!(f, "Søket er ferdig med {solutions} løsning(er)")
+        }
+        Message::FramesWritten { count, path } => {
+            write!(f, "Skrev {count} bilde(r) til {path}")
+        }
+        Message::DatasetWritten(path) => {
+            write!(f, "Skrev løsningene for 366 datoer til {path}")
+        }
+        Message::SolutionsChanged {
+            month,
+            day,
+            before,
+            now,
+        } => write!(f, "{month:02}-{day:02}: {before} løsning(er) før, {now} nå"),
+        Message::DatasetCompared {
+            dates,
+            changed,
+            added,
+            missing,
+        } => write!(
+            f,
+            "{dates} dato(er) sammenlignet, {changed} endret: {added} løsning(er) lagt til, \
+             {missing} mangler"
+        ),
+        Message::DatasetLine { number, error } => write!(f, "linje {number}: {error}"),
+        Message::BoardCount { path, boards } => {
+            write!(f, "Ventet ett brett i {path}, fant {boards}")
+        }
+        Message::OutsideBoard(letter) => write!(f, "{letter} dekker ruter utenfor brettet"),
+        Message::TooManyOfBrick => write!(
+            f,
+            "Brikkene på brettet trenger flere av en brikke enn det finnes"
+        ),
+        Message::SizesDontCover { cells, free } => write!(
+            f,
+            "Størrelsene blir til sammen {cells} ruter, men en dato har {free} ruter å dekke"
+        ),
+        Message::NoSetFound(attempts) => write!(
+            f,
+            "Fant ikke noe sett som løser alle datoer på {attempts} forsøk, prøv en annen --seed"
+        ),
+    }
+}
+
+fn norwegian_cell((row, column): (u32, u32)) -> String {
+    format!("rad {}, kolonne {}", row + 1, column + 1)
+}
+
+// SolverError in the chosen language.
+pub struct Localized<'a>(pub &'a SolverError);
+
+impl fmt::Display for Localized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if lang() != Lang::Nb {
+            return write!(f, "{}", self.0);
+        }
+        match self.0 {
+            SolverError::InvalidDay(day) => write!(f, "Ugyldig dag {day}. Gyldige dager: 1-31"),
+            SolverError::InvalidMonth(month) => {
+                write!(f, "Ugyldig måned {month}. Gyldige måneder: 1-12")
+            }
+            SolverError::InvalidWeekday(weekday) => {
+                write!(f, "Ugyldig ukedag {weekday}. Gyldige ukedager: 1-7")
+            }
+            SolverError::InvalidCell { row, column } => {
+                write!(f, "Ruten i rad {row}, kolonne {column} er utenfor brettet")
+            }
+            SolverError::NoFreeCells => write!(f, "Brettet har ingen ledige ruter"),
+            SolverError::BoardTooLarge {
+                width,
+                height,
+                bits,
+            } => write!(f, "Et {width}x{height} brett får ikke plass i {bits} bit"),
+            SolverError::AreaMismatch { bricks, free } => write!(
+                f,
+                "Brikkene dekker {bricks} ruter, men brettet har {free} ledige"
+            ),
+            SolverError::BrickPlacedTwice(brick) => {
+                write!(f, "Brikke {} er lagt mer enn én gang", brick + 1)
+            }
+            SolverError::CellTaken(brick) => {
+                write!(
+                    f,
+                    "Brikke {} dekker en rute som allerede er tatt",
+                    brick + 1
+                )
+            }
+        }
+    }
+}
+
+// ShapeError in the chosen language.
+pub struct LocalizedShape<'a>(pub &'a ShapeError);
+
+impl fmt::Display for LocalizedShape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if lang() != Lang::Nb {
+            return write!(f, "{}", self.0);
+        }
+        match self.0 {
+            ShapeError::Empty => write!(f, "Formen har ingen ruter"),
+            ShapeError::TooWide { width } => {
+                write!(f, "Formen er {width} ruter bred, brettet bare 7")
+            }
+            ShapeError::TooTall { height } => {
+                write!(f, "Formen er {height} ruter høy, brettet bare 7")
+            }
+            ShapeError::UnexpectedCharacter(character) => write!(
+                f,
+                "Uventet `{character}` i formen, bruk X eller # for ruter og . eller mellomrom for hull"
+            ),
+            ShapeError::Disconnected => write!(f, "Rutene i formen henger ikke sammen"),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+// Reads a file, or standard input when the path is `-`, so other tools can pipe their output in
+// without a temporary file.
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    if path != Path::new("-") {
+        return fs::read_to_string(path);
+    }
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+    Ok(text)
+}
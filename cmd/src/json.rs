@@ -0,0 +1,193 @@
+use crate::output::Output;
+use crate::pager::outln;
+use serde::Serialize;
+use solver::{Brick, Hint, SearchStats, SolvedBoard};
+use std::time::Instant;
+
+// What --format json prints, one document for the whole run. Bricks are numbered 1-8 and cells
+// are [row, column], counted from 0 at the top left of the board.
+#[derive(Serialize)]
+pub struct Json {
+    date: Date,
+    solutions: Vec<Solution>,
+    hints: Vec<HintOutput>,
+    elapsed_ms: u128,
+}
+
+#[derive(Serialize)]
+struct Date {
+    month: u8,
+    day: u8,
+}
+
+#[derive(Serialize)]
+struct Solution {
+    index: usize,
+    placements: Vec<Placement>,
+    stats: Stats,
+}
+
+#[derive(Serialize)]
+struct Placement {
+    brick: usize,
+    cells: Vec<[u32; 2]>,
+}
+
+#[derive(Serialize)]
+struct Stats {
+    test_count: u64,
+    backtracks: u64,
+    max_depth: usize,
+    elapsed_ms: u128,
+}
+
+#[derive(Serialize)]
+struct HintOutput {
+    index: usize,
+    solutions: usize,
+    placement: Placement,
+}
+
+impl From<&SearchStats> for Stats {
+    fn from(stats: &SearchStats) -> Stats {
+        Stats {
+            test_count: stats.test_count,
+            backtracks: stats.backtracks,
+            max_depth: stats.max_depth,
+            elapsed_ms: stats.elapsed.as_millis(),
+        }
+    }
+}
+
+fn placement(brick: usize, mask: u64) -> Placement {
+    let cells = (0..64)
+        .filter(|index| mask & 1 << 63 >> index != 0)
+        .map(|index| [index / 8, index % 8])
+        .collect();
+    Placement {
+        brick: brick + 1,
+        cells,
+    }
+}
+
+impl Json {
+    pub fn new(day: u8, month: u8) -> Json {
+        Json {
+            date: Date { month, day },
+            solutions: vec![],
+            hints: vec![],
+            elapsed_ms: 0,
+        }
+    }
+}
+
+impl Output for Json {
+    fn push_solution(&mut self, solution: &SolvedBoard) {
+        let placements = solution
+            .bricks
+            .iter()
+            .zip(&solution.placed_bricks)
+            .map(|(&brick, &mask)| placement(brick, mask))
+            .collect();
+        self.solutions.push(Solution {
+            index: self.solutions.len() + 1,
+            placements,
+            stats: Stats::from(&solution.stats),
+        });
+    }
+
+    fn push_hint(&mut self, hint: &Hint, bricks: &[Brick]) {
+        // A hint placing none of the bricks has no brick to name, so it is left out.
+        let Some(brick) = bricks.iter().position(|brick| brick.matches(hint.brick)) else {
+            return;
+        };
+        self.hints.push(HintOutput {
+            index: self.hints.len() + 1,
+            solutions: hint.solutions,
+            placement: placement(brick, hint.brick),
+        });
+    }
+
+    fn print(mut self: Box<Self>, start: Instant) {
+        self.elapsed_ms = start.elapsed().as_millis();
+        let json = serde_json::to_string(&*self).expect("Json serializes to JSON");
+        outln!("{json}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use solver::{Board, hints, solve};
+
+    // The bits of the cells in a placement, the way the solver keeps them.
+    fn mask(placement: &Value) -> u64 {
+        placement["cells"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|cell| cell[0].as_u64().unwrap() * 8 + cell[1].as_u64().unwrap())
+            .fold(0, |mask, index| mask | 1 << 63 >> index)
+    }
+
+    #[test]
+    fn solutions_read_back() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let solutions: Vec<SolvedBoard> = solve(board, &bricks).take(2).collect();
+        let mut json = Json::new(22, 9);
+        for solution in &solutions {
+            json.push_solution(solution);
+        }
+        let value = serde_json::to_value(&json).unwrap();
+        assert_eq!(value["date"], serde_json::json!({ "month": 9, "day": 22 }));
+        assert_eq!(value["hints"], serde_json::json!([]));
+        for (number, solution) in solutions.iter().enumerate() {
+            let written = &value["solutions"][number];
+            assert_eq!(written["index"], number + 1);
+            assert_eq!(written["stats"]["test_count"], solution.stats.test_count);
+            let placements = written["placements"].as_array().unwrap();
+            assert_eq!(placements.len(), solution.placed_bricks.len());
+            for (placement, (&brick, &placed)) in placements
+                .iter()
+                .zip(solution.bricks.iter().zip(&solution.placed_bricks))
+            {
+                assert_eq!(placement["brick"], brick + 1);
+                assert_eq!(mask(placement), placed);
+            }
+        }
+    }
+
+    #[test]
+    fn hints_read_back() {
+        let bricks = Brick::all_bricks();
+        let all_hints = hints(Board::for_date(1, 1).unwrap(), &bricks);
+        let mut json = Json::new(1, 1);
+        for hint in all_hints.iter().take(3) {
+            json.push_hint(hint, &bricks);
+        }
+        let value = serde_json::to_value(&json).unwrap();
+        assert_eq!(value["solutions"], serde_json::json!([]));
+        for (number, hint) in all_hints.iter().take(3).enumerate() {
+            let written = &value["hints"][number];
+            assert_eq!(written["index"], number + 1);
+            assert_eq!(written["solutions"], hint.solutions);
+            let brick = written["placement"]["brick"].as_u64().unwrap() as usize;
+            assert!(bricks[brick - 1].matches(hint.brick));
+            assert_eq!(mask(&written["placement"]), hint.brick);
+        }
+    }
+
+    #[test]
+    fn hints_placing_no_brick_are_left_out() {
+        let mut json = Json::new(1, 1);
+        let stray = Hint {
+            brick: 1 << 63,
+            solutions: 5,
+        };
+        json.push_hint(&stray, &Brick::all_bricks());
+        let value = serde_json::to_value(&json).unwrap();
+        assert_eq!(value["hints"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::i18n::Message;
+use crate::input;
+use crate::pager::outln;
+use crate::results::PuzzleResult;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Ranks the results per date by the least given away by hints, then fastest time. Results for another puzzle than
+// the standard one for their date, or with a bad signature, are left out with a warning.
+pub fn run(files: &[PathBuf], secret: Option<&str>) {
+    let mut by_date: BTreeMap<_, Vec<PuzzleResult>> = BTreeMap::new();
+    for file in files {
+        let path = file.display().to_string();
+        let result = input::read_to_string(file)
+            .map_err(|error| error.to_string())
+            .and_then(|text| PuzzleResult::parse(&text));
+        match result {
+            Ok(result) if PuzzleResult::expected_checksum(result.date) != Some(result.checksum) => {
+                eprintln!("{}", Message::Warning(&Message::DifferentPuzzle(&path)))
+            }
+            Ok(result) if secret.is_some_and(|secret| !result.has_valid_signature(secret)) => {
+                eprintln!("{}", Message::Warning(&Message::InvalidSignature(&path)))
+            }
+            Ok(result) => by_date.entry(result.date).or_default().push(result),
+            Err(error) => {
+                let message = Message::CouldNotRead {
+                    path: &path,
+                    error: &error,
+                };
+                eprintln!("{}", Message::Warning(&message))
+            }
+        }
+    }
+    for (date, mut results) in by_date {
+        results.sort_by_key(|result| (result.spoiler_score(), result.time));
+        outln!("\n{date}");
+        outln!("{}", Message::LeaderboardHeader);
+        for (rank, result) in results.iter().enumerate() {
+            outln!(
+                "{:>4}  {:<20} {:>9} {:>6}",
+                rank + 1,
+                result.name,
+                format_time(result.time),
+                result.hints
+            );
+        }
+    }
+}
+
+pub fn format_time(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
@@ -1,93 +1,1187 @@
-use ansi_term::Color::Fixed;
-use ansi_term::{ANSIGenericString, Color, Style};
-use chrono::Datelike;
-use clap::Parser;
-use solver::{Board, Brick, SolvedBoard, hints, solve};
-use std::time::Instant;
+use chrono::{Datelike, NaiveDate};
+use clap::{Parser, Subcommand};
+use solver::{
+    Board, Brick, DiversityOrder, FoundOrder, HintStrategy, LexicographicOrder, Palette, Progress,
+    STANDARD_BRICK_CELLS, SearchProfile, SolutionOrderer, SolveOptions, SolvedBoard, SymmetryOrder,
+    ThreadStats, audit_brick, brick_redundancy, dead_placements, difficulty_with_bricks,
+    easy_start, solve_par_threads, solve_random, solve_with_options,
+};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod analyze;
+mod audit;
+mod bricks;
+mod cache;
+mod config;
+mod contact_sheet;
+mod csv;
+mod daily;
+mod date;
+mod generate;
+mod grid;
+mod hooks;
+mod i18n;
+mod input;
+mod json;
+mod leaderboard;
+mod output;
+mod pager;
+mod prime;
+mod progress;
+mod range;
+mod render;
+mod report;
+mod results;
+mod share;
+#[cfg(test)]
+mod snapshot;
+mod state;
+mod swap;
+mod tui;
+mod verify;
+mod visualize;
+mod watch;
+mod weekday;
+mod year;
+
+use cache::CachedDay;
+use config::Config;
+use hooks::exit;
+use i18n::{Lang, Localized, Message};
+use output::Output;
+use pager::outln;
+use render::{
+    ColorChoice, Labels, SolutionRows, Theme, print_board, print_brick, print_marked_cells,
+    print_panels, print_placements, print_pre_placed, print_with_tray, shape_panel,
+};
+use report::{HintReport, Report, SolutionReport};
+
 #[derive(Parser)]
 #[command(version, about)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=31))]
     /// Day of month to solve for (1-31). If not specified, the current day of month is used.
     day: Option<u8>,
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
     /// Month to solve for (1-12). If not specified, the current month is used.
     month: Option<u8>,
+    #[arg(long, conflicts_with_all = ["day", "month"])]
+    /// Date to solve for, the way you would say it, like "tomorrow", "next friday" or "29 july", or
+    /// with numbers like 2025-07-29 or 29.7.
+    date: Option<String>,
+    #[arg(long, requires = "to", conflicts_with_all = ["day", "month", "date", "range", "hint",
+        "easy", "first", "max_solutions", "count_only", "contact_sheet", "visualize_search"])]
+    /// First date to solve, like 2025-01-01, with --to. Prints a summary per date.
+    from: Option<String>,
+    #[arg(long, requires = "from")]
+    /// Last date to solve, with --from.
+    to: Option<String>,
+    #[arg(long, conflicts_with_all = ["day", "month", "date", "hint", "easy", "first",
+        "max_solutions", "count_only", "contact_sheet", "visualize_search"])]
+    /// Dates to solve, like 2025-01-01..2025-03-31. Prints a summary per date.
+    range: Option<String>,
     #[arg(short = 'H', long = "hint")]
     /// Just give a brick as a hint without showing the full solution. Default number of hints to give is 1.
     hint: Option<Option<u8>>,
+    #[arg(long, value_name = "LEVEL", requires = "hint",
+        value_parser = clap::value_parser!(u8).range(1..=3))]
+    /// How much each printed hint gives away: 1 only the brick to use next, 2 the brick and the
+    /// quarter of the board it goes in, 3 exactly where it goes. Defaults to 3.
+    hint_level: Option<u8>,
+    #[arg(long, value_enum, default_value_t = HintRanking::MostSolutions, requires = "hint")]
+    /// Which hints to give first.
+    hint_strategy: HintRanking,
+    #[arg(long, conflicts_with_all = ["hint", "easy", "first", "max_solutions", "count_only",
+        "contact_sheet", "output", "format", "visualize_search", "tui", "watch", "weekday",
+        "range", "from", "state"])]
+    /// Show the bricks that are placed the same way in every solution, hints that are sure to be
+    /// right.
+    forced: bool,
+    #[arg(long, value_name = "BRICK", num_args = 0..=1,
+        value_parser = clap::value_parser!(u8).range(1..),
+        conflicts_with_all = ["hint", "easy", "first", "max_solutions", "count_only",
+        "contact_sheet", "output", "format", "visualize_search", "tui", "watch", "weekday",
+        "range", "from", "state", "forced"])]
+    /// Count the placements of each brick that are in no solution, not worth trying, or show
+    /// those of one brick (1-8).
+    dead: Option<Option<u8>>,
+    #[arg(long, conflicts_with_all = ["hint", "easy", "first", "max_solutions", "count_only",
+        "contact_sheet", "output", "format", "visualize_search", "tui", "watch", "weekday",
+        "range", "from", "state", "forced", "dead"])]
+    /// Rate how hard the date is from 1 to 5, from its number of solutions, its forced
+    /// placements and how long the search takes to find a solution.
+    difficulty: bool,
+    #[arg(short, long)]
+    /// Only show the first solution found. Uses a search tuned for finding one solution fast.
+    first: bool,
+    #[arg(short = 'n', long, value_name = "N", conflicts_with_all = ["hint", "easy", "first"],
+        value_parser = clap::value_parser!(u64).range(1..))]
+    /// Stop after this many solutions.
+    max_solutions: Option<u64>,
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration,
+        conflicts_with_all = ["random", "easy", "count_only", "tui", "watch", "weekday", "range",
+        "from", "visualize_search"])]
+    /// Stop searching after this long, like 5s, 500ms or 2m, showing what was found so far. Exits
+    /// with 124 when the search was cut short.
+    timeout: Option<Duration>,
+    #[arg(long, value_name = "N", num_args = 0..=1,
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with_all = ["first", "random", "order", "timeout", "hint", "easy", "count_only",
+        "tui", "watch", "weekday", "range", "from", "visualize_search", "state"])]
+    /// Search with this many threads, or one per logical core when no number is given. The
+    /// solutions come in the order the threads find them, with what each thread did at the end.
+    threads: Option<Option<u64>>,
+    #[arg(long, value_name = "SEED", num_args = 0..=1,
+        conflicts_with_all = ["hint", "easy", "first", "max_solutions", "order"])]
+    /// Show one solution picked at random. The same seed gives the same solution. Defaults to a
+    /// seed from the date, so it only changes from one day to the next.
+    random: Option<Option<u64>>,
+    #[arg(long, conflicts_with_all = ["hint", "first", "max_solutions", "contact_sheet", "output",
+        "format", "visualize_search"])]
+    /// Only print the number of solutions, without drawing them.
+    count_only: bool,
+    #[arg(long, value_name = "PIECES", conflicts_with_all = ["hint", "first", "count_only"],
+        value_parser = clap::value_parser!(u8).range(1..=7))]
+    /// Pre-place this many bricks, keeping as many solutions open as possible, for an easier puzzle.
+    easy: Option<u8>,
+    #[arg(long, conflicts_with_all = ["hint", "easy", "first", "max_solutions", "count_only",
+        "contact_sheet", "output", "format", "visualize_search", "watch", "range", "from"])]
+    /// Page through the solutions with the arrow keys, switching dates and showing hints without
+    /// restarting.
+    tui: bool,
+    #[arg(short, long)]
+    /// Keep running and show a new solution every day, including what changed since yesterday.
+    watch: bool,
+    #[arg(long, conflicts_with_all = ["hint", "easy", "random", "count_only", "contact_sheet",
+        "output", "format", "visualize_search", "tui", "watch", "range", "from"])]
+    /// Solve the board with weekdays, covering everything but the date and its weekday.
+    weekday: bool,
+    #[arg(long, requires = "weekday")]
+    /// Year the weekday is taken from, with --weekday. Defaults to the year of the date.
+    year: Option<i32>,
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["hint", "easy", "first", "max_solutions",
+        "count_only", "contact_sheet", "output", "format", "visualize_search", "tui", "watch",
+        "weekday", "range", "from"])]
+    /// Finish the puzzle from the bricks already on the board, read from a grid with a letter or
+    /// digit per brick cell and . for the free cells, or - for stdin. Says so if it can't be done.
+    state: Option<PathBuf>,
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["redundancy", "swap"])]
+    /// Solve with the bricks of a TOML file instead of the standard set, a [[brick]] table per
+    /// piece with its rows, like shape = ["XXX", ".X."]. Of the subcommands only verify uses it.
+    bricks: Option<PathBuf>,
+    #[arg(long)]
+    /// Config file to use instead of ~/.config/calendar_puzzle_solver/config.toml, or - for stdin.
+    config: Option<PathBuf>,
+    #[arg(short, long)]
+    /// Show the output through $PAGER (or less) when writing to a terminal.
+    pager: bool,
+    #[arg(long, visible_alias = "animate")]
+    /// Animate the search, showing bricks being placed and taken back, at most --frame-rate steps
+    /// per second.
+    visualize_search: bool,
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..))]
+    /// Maximum number of steps per second shown by --visualize-search.
+    frame_rate: u32,
+    #[arg(long, value_name = "DIR", requires = "visualize_search")]
+    /// Write each frame of --visualize-search to the directory as a PNG image instead of showing
+    /// it, for making a GIF or video of the search.
+    capture: Option<PathBuf>,
+    #[arg(long)]
+    /// Check which bricks could be swapped for another shape with every date still solvable.
+    redundancy: bool,
+    #[arg(long, value_name = "BRICK", requires = "with")]
+    /// Swap a brick (1-8) for the shape given by --with and show which dates change.
+    swap: Option<usize>,
+    #[arg(long, value_name = "SHAPE", requires = "swap")]
+    /// Shape to swap in, as rows separated by / with X for cells, like XX./.XX/.X.
+    with: Option<String>,
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    /// When to draw the bricks in color.
+    color: ColorChoice,
+    #[arg(long, value_enum, default_value_t = Theme::Classic)]
+    /// Colors of the bricks.
+    theme: Theme,
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "date")]
+    /// Print the months and days on the solutions like on the physical board: on the date, or on
+    /// every cell with all. The months are abbreviated in the language of --lang.
+    labels: Option<Labels>,
+    #[arg(long)]
+    /// Draw the board with plain ASCII borders and a letter per brick, for terminals and fonts
+    /// without box drawing characters.
+    ascii: bool,
+    #[arg(long, value_enum)]
+    /// Language of the messages. Defaults to the language of the locale, or English.
+    lang: Option<Lang>,
+    #[arg(long, value_name = "PNG", conflicts_with_all = ["hint", "easy", "first"])]
+    /// Draw every solution of the date as a small board in one PNG image, instead of printing them.
+    contact_sheet: Option<PathBuf>,
+    #[arg(long, value_name = "FILE", conflicts_with = "easy")]
+    /// Write the results to the file instead of the terminal, without colors. Unless --format is
+    /// given, the extension picks what to write: .json and .csv like --format, .png and .svg a
+    /// picture of each solution, and the text for anything else. One picture goes to the file,
+    /// more to numbered files next to it, like board-1.png. Colors and size of the pictures are
+    /// set in [png] in the config file.
+    output: Option<PathBuf>,
+    #[arg(long, value_enum, conflicts_with_all = ["easy", "contact_sheet", "visualize_search"])]
+    /// How to print the solutions and hints. Defaults to text, or what the --output file's
+    /// extension names.
+    format: Option<Format>,
+    #[arg(long, value_enum, default_value_t = Order::Found, conflicts_with = "first")]
+    /// Order to show the solutions in. Any order but found waits for every solution first.
+    order: Order,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// Boards drawn in the terminal.
+    Text,
+    /// One JSON document with the date, and the placements and search stats of each solution, or
+    /// the hints.
+    Json,
+    /// A row per solution with the number of solutions shown, test count and times, or a row per hint.
+    Csv,
+}
+
+// The exit code when --timeout cut the search short, the same as timeout(1) uses.
+const EXIT_TIMED_OUT: i32 = 124;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Picture {
+    Png,
+    Svg,
+}
+
+// Where --output sends the results: the text of a format, redirected to the file, or pictures.
+enum OutputFile<'a> {
+    Formatted(&'a Path, Format),
+    Pictures(&'a Path, Picture),
+}
+
+impl OutputFile<'_> {
+    fn new(path: &Path, format: Option<Format>) -> OutputFile<'_> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match (format, extension.as_deref()) {
+            (Some(format), _) => OutputFile::Formatted(path, format),
+            (None, Some("json")) => OutputFile::Formatted(path, Format::Json),
+            (None, Some("csv")) => OutputFile::Formatted(path, Format::Csv),
+            (None, Some("png")) => OutputFile::Pictures(path, Picture::Png),
+            (None, Some("svg")) => OutputFile::Pictures(path, Picture::Svg),
+            (None, _) => OutputFile::Formatted(path, Format::Text),
+        }
+    }
+
+    fn format(&self) -> Format {
+        match self {
+            OutputFile::Formatted(_, format) => *format,
+            OutputFile::Pictures(..) => Format::Text,
+        }
+    }
+
+    fn pictures(&self) -> Option<(&Path, Picture)> {
+        match self {
+            OutputFile::Formatted(..) => None,
+            OutputFile::Pictures(path, picture) => Some((path, *picture)),
+        }
+    }
+
+    // Sends what is printed to the file, or through the pager when there is no file and it is
+    // asked for.
+    fn start(output_file: Option<&OutputFile>, pager: bool) -> Option<pager::Pager> {
+        match output_file {
+            Some(OutputFile::Formatted(path, _)) => {
+                if let Err(error) = pager::to_file(path) {
+                    could_not_write(&path.display().to_string(), &error);
+                }
+                None
+            }
+            Some(OutputFile::Pictures(..)) | None if pager => pager::start(),
+            _ => None,
+        }
+    }
+
+    fn finish(output_file: Option<&OutputFile>, pager: Option<pager::Pager>) {
+        if let Some(OutputFile::Formatted(path, _)) = output_file {
+            let path = path.display().to_string();
+            if let Err(error) = pager::finish_file() {
+                could_not_write(&path, &error);
+            }
+            println!("{}", Message::OutputWritten(&path));
+        }
+        if let Some(pager) = pager {
+            pager.wait();
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Order {
+    /// The order the search finds them in.
+    Found,
+    /// By where each brick lies, bricks nearer the top left first.
+    Lexicographic,
+    /// Fewest bricks turned over first.
+    BySymmetry,
+    /// Each solution differing as much as possible from those before it.
+    ByDiversity,
+}
+
+impl Order {
+    fn orderer(self) -> &'static dyn SolutionOrderer {
+        match self {
+            Order::Found => &FoundOrder,
+            Order::Lexicographic => &LexicographicOrder,
+            Order::BySymmetry => &SymmetryOrder,
+            Order::ByDiversity => &DiversityOrder,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum HintRanking {
+    /// The placements that are part of the most solutions.
+    MostSolutions,
+    /// The placements leaving the fewest wrong ways to go on, for each way that works.
+    LeastBranching,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time yourself solving today's puzzle and write a result file to share with others.
+    Daily {
+        #[arg(long)]
+        /// Name to put in the result. Defaults to the user name.
+        name: Option<String>,
+        #[arg(short, long)]
+        /// File to write the result to. Defaults to daily-<date>.result.
+        output: Option<PathBuf>,
+        #[arg(long)]
+        /// Secret shared by the group, used to sign the result.
+        secret: Option<String>,
+        #[arg(long, default_value_t = 3)]
+        /// Most hints to give. Each hint about a brick shows more of it: a cell, the brick, then
+        /// where it goes.
+        hint_budget: u32,
+    },
+    /// Show how the number of solutions is spread over the dates of a year.
+    Analyze {
+        #[arg(long)]
+        /// Year to analyze the dates of. Defaults to the current year.
+        year: Option<i32>,
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        /// Number of different solution counts grouped in each bar.
+        bucket_size: u32,
+        #[arg(long)]
+        /// Print the histogram as CSV instead of bars.
+        csv: bool,
+        #[arg(long, value_name = "TOP", num_args = 0..=1, default_missing_value = "10")]
+        /// Instead, rank the brick placements by the number of dates they are part of a solution
+        /// on, showing the top ones (10 by default) with a map of those dates.
+        stability: Option<usize>,
+        #[arg(long, value_name = "K", num_args = 0..=1, default_missing_value = "10",
+            conflicts_with = "stability")]
+        /// Instead, rank the dates by number of solutions and by boards tested, showing the top
+        /// and bottom K (10 by default).
+        rank: Option<usize>,
+        #[arg(long, value_name = "K", num_args = 0..=1, default_missing_value = "2",
+            value_parser = clap::value_parser!(u64).range(1..),
+            conflicts_with_all = ["stability", "rank"])]
+        /// Instead, list the dates with a unique solution, or with fewer than K solutions.
+        unique: Option<u64>,
+    },
+    /// Solve every month and day and summarize the solution counts and the slowest dates.
+    Year {
+        #[arg(long, default_value_t = 5)]
+        /// Number of slowest dates to list.
+        slowest: usize,
+    },
+    /// Print a spoiler free summary of a result from `daily` to paste into a chat.
+    ShareText {
+        /// Result file to summarize, or - for stdin. Defaults to today's result from `daily`.
+        file: Option<PathBuf>,
+    },
+    /// Rank the results in a set of result files.
+    Leaderboard {
+        #[arg(long, num_args = 1.., required = true)]
+        /// Result files to merge. A - reads one result from stdin.
+        merge: Vec<PathBuf>,
+        #[arg(long)]
+        /// Secret shared by the group. Results without a matching signature are left out.
+        secret: Option<String>,
+    },
+    /// Check the brick variant tables against the brick shapes.
+    SelfTest,
+    /// Compare the solutions of every date with a dataset written by an earlier version, listing
+    /// the solutions added and missing per date. Exits with 1 if any changed.
+    Audit {
+        #[arg(long, value_name = "DATASET", required_unless_present = "export")]
+        /// JSON lines file from `audit --export` to compare against.
+        against: Option<PathBuf>,
+        #[arg(long, value_name = "DATASET", conflicts_with = "against")]
+        /// Write the solutions of every date to the file instead, to compare against later.
+        export: Option<PathBuf>,
+    },
+    /// Work out the solution counts, first solutions and hints of the coming days ahead of time,
+    /// so asking for them later is instant.
+    PrimeCache {
+        #[arg(long, default_value_t = 7, value_parser = clap::value_parser!(u32).range(1..=366))]
+        /// Number of days to prepare, starting today.
+        days: u32,
+        #[arg(long)]
+        /// Run at the lowest CPU priority, leaving the machine responsive.
+        low_priority: bool,
+    },
+    /// Check a solution, written as a grid with a letter or digit per brick cell and . for the
+    /// uncovered cells, or as the JSON of --format json. Lists the cells left uncovered or covered
+    /// twice and the pieces no brick has the shape of. Exits with 1 if it isn't a solution.
+    Verify {
+        /// File with the solution, or - for stdin.
+        file: PathBuf,
+        #[arg(long)]
+        /// Date the solution is for. Defaults to the date in the JSON, or the only date the grid
+        /// leaves uncovered.
+        date: Option<String>,
+    },
+    /// Look for other sets of bricks that solve every date, by trying random shapes of the given
+    /// sizes. Prints the sets found as brick set files for --bricks.
+    Generate {
+        #[arg(long, value_delimiter = ',', default_value = "6,5,5,5,5,5,5,5",
+            value_parser = clap::value_parser!(u64).range(1..=41))]
+        /// Number of cells of each brick, adding up to 41.
+        sizes: Vec<u64>,
+        #[arg(long, default_value_t = 1)]
+        /// Number of sets to find.
+        count: usize,
+        #[arg(long, default_value_t = 1000)]
+        /// Number of random sets to try before giving up.
+        attempts: usize,
+        #[arg(long, default_value_t = 0)]
+        /// Seed for picking the shapes. The same seed finds the same sets.
+        seed: u64,
+    },
 }
 
 fn main() {
+    run();
+    exit(0);
+}
+
+fn run() {
     let current_date = chrono::Local::now();
     let cli = Cli::parse();
-    let month = cli.month.unwrap_or_else(|| current_date.month() as u8);
-    let day = cli.day.unwrap_or_else(|| current_date.day() as u8);
+    i18n::init(cli.lang);
+    let output_file = cli
+        .output
+        .as_deref()
+        .map(|path| OutputFile::new(path, cli.format));
+    // Escapes in a file are only wanted when asked for.
+    let color = match (&output_file, cli.color) {
+        (Some(OutputFile::Formatted(..)), ColorChoice::Auto) => ColorChoice::Never,
+        (_, color) => color,
+    };
+    render::detect_color_support(color, cli.theme);
+    render::set_ascii(cli.ascii);
+    render::set_labels(cli.labels);
+    let config = Config::load(cli.config.as_deref()).unwrap_or_else(|error| {
+        eprintln!("{}", Message::Error(&error));
+        exit(1);
+    });
+    // The other subcommands are about the standard puzzle.
+    if cli.bricks.is_some() && !matches!(cli.command, None | Some(Command::Verify { .. })) {
+        eprintln!("{}", Message::Error(&Message::StandardBricksOnly));
+        exit(1);
+    }
+    match cli.command {
+        Some(Command::Daily {
+            name,
+            output,
+            secret,
+            hint_budget,
+        }) => return daily::run(name, output, secret, hint_budget),
+        Some(Command::Leaderboard { merge, secret }) => {
+            return leaderboard::run(&merge, secret.as_deref());
+        }
+        Some(Command::Analyze {
+            year,
+            bucket_size,
+            csv,
+            stability,
+            rank,
+            unique,
+        }) => {
+            let year = year.unwrap_or_else(|| current_date.year());
+            if let Some(below) = unique {
+                return analyze::run_unique(year, below as usize, csv);
+            }
+            if let Some(top) = rank {
+                return analyze::run_rank(year, top, csv);
+            }
+            if let Some(top) = stability {
+                return analyze::run_stability(year, top, csv);
+            }
+            return analyze::run(year, bucket_size as usize, csv);
+        }
+        Some(Command::Year { slowest }) => return year::run(slowest),
+        Some(Command::ShareText { file }) => {
+            let default = || PathBuf::from(format!("daily-{}.result", current_date.date_naive()));
+            if let Err(error) = share::run(&file.unwrap_or_else(default)) {
+                eprintln!("{}", Message::Error(&error));
+                exit(1);
+            }
+            return;
+        }
+        Some(Command::SelfTest) => return self_test(),
+        Some(Command::Audit { against, export }) => {
+            if let Some(path) = export {
+                return audit::export(&path);
+            }
+            return audit::against(&against.unwrap());
+        }
+        Some(Command::PrimeCache { days, low_priority }) => {
+            return prime::run(current_date.date_naive(), days, low_priority);
+        }
+        Some(Command::Verify { file, date }) => {
+            let date = date.map(|text| {
+                let date = date::parse(&text, current_date.date_naive()).unwrap_or_else(|error| {
+                    eprintln!("{}", Message::Error(&error));
+                    exit(1);
+                });
+                (date.day() as u8, date.month() as u8)
+            });
+            let bricks = match &cli.bricks {
+                Some(path) => bricks::load(path).unwrap_or_else(|error| {
+                    eprintln!("{}", Message::Error(&error));
+                    exit(1);
+                }),
+                None => Brick::all_bricks(),
+            };
+            return verify::run(&file, date, &bricks);
+        }
+        Some(Command::Generate {
+            sizes,
+            count,
+            attempts,
+            seed,
+        }) => {
+            let sizes = sizes.into_iter().map(|size| size as usize).collect();
+            return generate::run(sizes, count, attempts, seed);
+        }
+        None => {}
+    }
+    let brick_set = cli.bricks.as_deref().map(|path| {
+        bricks::load(path).unwrap_or_else(|error| {
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        })
+    });
+    let all_bricks = &brick_set.clone().unwrap_or_else(Brick::all_bricks);
+    if cli.redundancy {
+        return print_redundancy();
+    }
+    if let (Some(brick), Some(shape)) = (cli.swap, &cli.with) {
+        let pager = if cli.pager { pager::start() } else { None };
+        if let Err(error) = swap::run(brick, shape) {
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        }
+        if let Some(pager) = pager {
+            pager.wait();
+        }
+        return;
+    }
+    if cli.watch {
+        watch::run(all_bricks);
+    }
+    let today = current_date.date_naive();
+    let range = match (&cli.range, &cli.from, &cli.to) {
+        (Some(range), _, _) => Some(range::parse_range(range, today)),
+        (None, Some(from), Some(to)) => {
+            Some(date::parse(from, today).and_then(|from| Ok((from, date::parse(to, today)?))))
+        }
+        _ => None,
+    };
+    if let Some(range) = range {
+        let (from, to) = range.unwrap_or_else(|error| {
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        });
+        if output_file
+            .as_ref()
+            .is_some_and(|file| file.pictures().is_some())
+        {
+            eprintln!("{}", Message::Error(&Message::OnlySolutionPictures));
+            exit(1);
+        }
+        let format = output_file
+            .as_ref()
+            .map_or(cli.format, |file| Some(file.format()));
+        let pager = OutputFile::start(output_file.as_ref(), cli.pager);
+        range::run(from, to, format.unwrap_or(Format::Text), all_bricks);
+        OutputFile::finish(output_file.as_ref(), pager);
+        return;
+    }
+    let date = match &cli.date {
+        Some(text) => date::parse(text, current_date.date_naive()).unwrap_or_else(|error| {
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        }),
+        None => current_date.date_naive(),
+    };
+    let month = cli.month.unwrap_or(date.month() as u8);
+    let day = cli.day.unwrap_or(date.day() as u8);
 
+    if let Some(command) = &config.hooks.pre_solve {
+        hooks::run(command, day, month, None);
+    }
+    // The post-solve hook runs on the way out, once the date is solved.
+    let solved = |report: Option<String>| {
+        hooks::post_solve(config.hooks.post_solve.as_deref(), day, month, report);
+    };
+    if cli.visualize_search {
+        match Board::for_date(day, month) {
+            Ok(board) => {
+                visualize::run(
+                    board,
+                    all_bricks,
+                    cli.frame_rate,
+                    cli.capture.as_deref(),
+                    &config.png.palette(),
+                );
+                solved(None);
+            }
+            Err(error) => eprintln!("{}", Message::Error(&Localized(&error))),
+        }
+        return;
+    }
+    if cli.tui {
+        let date = NaiveDate::from_ymd_opt(date.year(), month.into(), day.into()).unwrap_or(date);
+        if let Err(error) = tui::run(date, all_bricks) {
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        }
+        solved(None);
+        return;
+    }
+    if cli.weekday {
+        let year = cli.year.unwrap_or(date.year());
+        let Some(date) = NaiveDate::from_ymd_opt(year, month.into(), day.into()) else {
+            let error = Message::NoSuchDate { day, month, year };
+            eprintln!("{}", Message::Error(&error));
+            exit(1);
+        };
+        let profile = match cli.first {
+            true => SearchProfile::Interactive,
+            false => SearchProfile::Exhaustive,
+        };
+        let shown = match (cli.first, cli.max_solutions) {
+            (true, _) => 1,
+            (false, max_solutions) => max_solutions.map_or(usize::MAX, |max| max as usize),
+        };
+        let pager = if cli.pager { pager::start() } else { None };
+        let bricks = brick_set.unwrap_or_else(Brick::weekday_bricks);
+        if let Err(error) = weekday::run(date, &bricks, shown, profile) {
+            eprintln!("{}", Message::Error(&Localized(&error)));
+            exit(1);
+        }
+        solved(None);
+        if let Some(pager) = pager {
+            pager.wait();
+        }
+        return;
+    }
+    if let Some(path) = &cli.state {
+        match state::run(path, day, month, all_bricks) {
+            Ok(finished) => {
+                solved(None);
+                exit(if finished { 0 } else { 1 });
+            }
+            Err(error) => {
+                eprintln!("{}", Message::Error(&error));
+                exit(1);
+            }
+        }
+    }
+    if cli.count_only {
+        match cache::load_or_compute(day, month, all_bricks) {
+            Ok(cached) => {
+                outln!("{}", cached.solutions);
+                solved(None);
+            }
+            Err(error) => {
+                eprintln!("{}", Message::Error(&Localized(&error)));
+                exit(1);
+            }
+        }
+        return;
+    }
+    if let Some(brick) = cli.dead {
+        let dead = Board::for_date(day, month)
+            .map(|board| dead_placements(&board, all_bricks))
+            .unwrap_or_else(|error| {
+                eprintln!("{}", Message::Error(&Localized(&error)));
+                exit(1);
+            });
+        outln!("{}", Message::Solving { day, month });
+        let of_brick = |brick_index: usize| -> Vec<u64> {
+            dead.iter()
+                .filter(|placement| placement.brick == brick_index)
+                .map(|placement| placement.mask)
+                .collect()
+        };
+        match brick {
+            Some(brick) if brick as usize > all_bricks.len() => {
+                let error = Message::InvalidBrick {
+                    brick: brick.into(),
+                    count: all_bricks.len(),
+                };
+                eprintln!("{}", Message::Error(&error));
+                exit(1);
+            }
+            Some(brick) => {
+                let placements = of_brick(brick as usize - 1);
+                let count = placements.len();
+                outln!("{}", Message::DeadPlacements { brick, count });
+                print_placements(&placements, brick);
+            }
+            None => {
+                for brick_index in 0..all_bricks.len() {
+                    let brick = brick_index as u8 + 1;
+                    let count = of_brick(brick_index).len();
+                    outln!("{}", Message::DeadPlacements { brick, count });
+                }
+            }
+        }
+        solved(None);
+        return;
+    }
+    if cli.forced {
+        let cached = cache::load_or_compute(day, month, all_bricks).unwrap_or_else(|error| {
+            eprintln!("{}", Message::Error(&Localized(&error)));
+            exit(1);
+        });
+        // The same as `forced_placements`, from the cached hints.
+        let forced: Vec<u64> = cached
+            .hints
+            .iter()
+            .filter(|hint| cached.solutions > 0 && hint.solutions == cached.solutions)
+            .map(|hint| hint.brick)
+            .collect();
+        outln!("{}", Message::Solving { day, month });
+        let solutions = cached.solutions;
+        if forced.is_empty() {
+            outln!("{}", Message::NoForced { solutions });
+        } else {
+            let bricks = forced.len();
+            outln!("{}", Message::Forced { bricks, solutions });
+            print_with_tray(&forced, all_bricks);
+        }
+        solved(None);
+        return;
+    }
+    if cli.difficulty {
+        let report = difficulty_with_bricks(day, month, all_bricks).unwrap_or_else(|error| {
+            eprintln!("{}", Message::Error(&Localized(&error)));
+            exit(1);
+        });
+        outln!("{}", Message::Solving { day, month });
+        outln!("{}", Message::Difficulty(&report));
+        solved(None);
+        return;
+    }
+    let pictures = output_file.as_ref().and_then(OutputFile::pictures);
+    if pictures.is_some() && (cli.hint.is_some() || cli.easy.is_some()) {
+        eprintln!("{}", Message::Error(&Message::OnlySolutionPictures));
+        exit(1);
+    }
+    let format = output_file
+        .as_ref()
+        .map_or(cli.format, |file| Some(file.format()));
+    let pager = OutputFile::start(output_file.as_ref(), cli.pager);
     let start = Instant::now();
-    println!("Solving for day {day} and month {month}");
+    let mut report = Report::new(day, month);
+    let mut output: Option<Box<dyn Output>> = match format.unwrap_or(Format::Text) {
+        Format::Text => None,
+        Format::Json => Some(Box::new(json::Json::new(day, month))),
+        Format::Csv => Some(Box::new(csv::Csv::new(day, month))),
+    };
+    if output.is_none() {
+        outln!("{}", Message::Solving { day, month });
+    }
     let board = Board::for_date(day, month);
-    match cli.hint {
-        None => {
-            for (i, solved_board) in solve(board.unwrap(), &Brick::all_bricks()).enumerate() {
-                println!(
-                    "Solution {} (time used:{:?}, test count: {}):",
-                    i + 1,
-                    start.elapsed(),
-                    solved_board.test_count
+    let mut options = SolveOptions::new();
+    if let Some(timeout) = cli.timeout {
+        options = options.time_budget(timeout);
+    }
+    let mut timed_out = false;
+    match (cli.hint, cli.easy) {
+        (_, Some(pieces)) => {
+            let placed = easy_start(board.unwrap(), all_bricks, pieces as usize);
+            let left = placed.last().map_or(0, |hint| hint.solutions);
+            outln!("{}", Message::EasyPlaced { pieces, left });
+            let placed: Vec<u64> = placed.iter().map(|hint| hint.brick).collect();
+            print_pre_placed(&placed, all_bricks);
+        }
+        (None, None) => {
+            let profile = if cli.first {
+                SearchProfile::Interactive
+            } else {
+                SearchProfile::Exhaustive
+            };
+            options.profile = profile;
+            let board = board.unwrap();
+            let mut parallel = cli.threads.map(|threads| {
+                let threads = threads.map_or_else(
+                    || std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+                    |threads| threads as usize,
                 );
-                print_board(&solved_board);
+                solve_par_threads(board.clone(), all_bricks, threads)
+            });
+            let mut search = solve_with_options(board.clone(), all_bricks, &options);
+            let solutions: Box<dyn Iterator<Item = _>> = match (cli.random, cli.order) {
+                _ if parallel.is_some() => Box::new(parallel.iter_mut().flatten()),
+                // The date is the default seed, so the solution changes from day to day only.
+                (Some(seed), _) => {
+                    let seed = seed.unwrap_or_else(|| {
+                        let year = date.year() as u64;
+                        year * 10_000 + month as u64 * 100 + day as u64
+                    });
+                    Box::new(solve_random(board.clone(), all_bricks, seed).into_iter())
+                }
+                (None, Order::Found) => Box::new(search.by_ref()),
+                (None, order) => Box::new(
+                    order
+                        .orderer()
+                        .order(search.by_ref().collect(), all_bricks)
+                        .into_iter(),
+                ),
+            };
+            let shown = match (cli.first, cli.max_solutions) {
+                (true, _) => 1,
+                (false, max_solutions) => max_solutions.map_or(usize::MAX, |max| max as usize),
+            };
+            let solutions = solutions.take(shown);
+            if let Some(output) = &mut output {
+                for solved_board in solutions {
+                    output.push_solution(&solved_board);
+                    report.solutions.push(SolutionReport {
+                        placed_bricks: solved_board.placed_bricks,
+                        test_count: solved_board.stats.test_count,
+                    });
+                }
+            } else if cli.contact_sheet.is_some() || pictures.is_some() {
+                let solutions: Vec<_> = solutions.collect();
+                let palette = config.png.palette();
+                if let Some(path) = &cli.contact_sheet {
+                    write_contact_sheet(path, &solutions, &palette);
+                }
+                if let Some((path, picture)) = pictures {
+                    write_pictures(path, picture, &solutions, config.png.scale, &palette);
+                }
+                report
+                    .solutions
+                    .extend(solutions.into_iter().map(|solved_board| SolutionReport {
+                        placed_bricks: solved_board.placed_bricks,
+                        test_count: solved_board.stats.test_count,
+                    }));
+            } else {
+                print_solutions(solutions, start, &mut report);
+            }
+            // Only a search the deadline stopped is cut short, not one that finished just before it.
+            timed_out = search.is_truncated();
+            if let (Some(parallel), None) = (&parallel, &output) {
+                print_thread_stats(&parallel.thread_stats());
             }
         }
-        Some(number_of_hints) => {
+        (Some(number_of_hints), None) => {
             let number_of_hints = number_of_hints.unwrap_or(1);
-            let all_bricks = &Brick::all_bricks();
-            let all_hints = hints(board.unwrap(), all_bricks);
+            let board = board.unwrap();
+            let mut all_hints = match cache::load(day, month, all_bricks) {
+                Some(cached) => cached.hints(),
+                None => {
+                    let progress = Progress::new();
+                    options.profile = SearchProfile::Interactive;
+                    let options = options.progress(progress.clone());
+                    let mut search = solve_with_options(board.clone(), all_bricks, &options);
+                    let solutions = progress::with_progress_line(&progress, || {
+                        search
+                            .by_ref()
+                            .map(|solution| solution.placed_bricks)
+                            .collect()
+                    });
+                    let cached = CachedDay::from_solutions(solutions);
+                    // A search stopped early has only some of the solutions, so it isn't kept.
+                    timed_out = search.is_truncated();
+                    if !timed_out {
+                        let _ = cache::store(day, month, all_bricks, &cached);
+                    }
+                    cached.hints()
+                }
+            };
+            let strategy = match cli.hint_strategy {
+                HintRanking::MostSolutions => HintStrategy::MostSolutions,
+                HintRanking::LeastBranching => HintStrategy::LeastBranching,
+            };
+            strategy.rank(&board, all_bricks, &mut all_hints);
             if all_hints.is_empty() {
-                eprintln!("ERROR: No hints found!")
+                eprintln!("{}", Message::Error(&Message::NoHints))
+            } else if let Some(output) = &mut output {
+                for hint in all_hints.iter().take(number_of_hints as usize) {
+                    output.push_hint(hint, all_bricks);
+                    report.hints.push(HintReport {
+                        placed_brick: hint.brick,
+                        solutions: hint.solutions,
+                    });
+                }
             } else {
                 for (i, hint) in all_hints.iter().enumerate().take(number_of_hints as usize) {
-                    println!("\nHint {} has {} possible solutions", i + 1, hint.solutions);
-                    print_bricks(&[hint.brick])
+                    let message = Message::Hint {
+                        number: i + 1,
+                        solutions: hint.solutions,
+                    };
+                    outln!("\n{message}");
+                    let brick = hint
+                        .brick_index(all_bricks)
+                        .map(|brick_index| (brick_index, &all_bricks[brick_index]));
+                    match cli.hint_level {
+                        Some(1) => {
+                            if let Some((brick_index, brick)) = brick {
+                                print_brick(brick_index, brick);
+                            }
+                        }
+                        Some(2) => {
+                            let quadrant = hint.quadrant();
+                            outln!("{}", Message::HintQuadrant(quadrant));
+                            print_marked_cells(|i| quadrant.contains(i as u8), brick);
+                        }
+                        _ => print_with_tray(&[hint.brick], all_bricks),
+                    }
+                    report.hints.push(HintReport {
+                        placed_brick: hint.brick,
+                        solutions: hint.solutions,
+                    });
                 }
             }
         }
     }
+    report.elapsed_ms = start.elapsed().as_millis();
+    if let Some(output) = output {
+        output.print(start);
+    }
+    OutputFile::finish(output_file.as_ref(), pager);
+    solved(Some(
+        serde_json::to_string(&report).expect("Report serializes to JSON"),
+    ));
+    if let (true, Some(timeout)) = (timed_out, cli.timeout) {
+        eprintln!("{}", Message::Warning(&Message::TimedOut(timeout)));
+        exit(EXIT_TIMED_OUT);
+    }
+}
+
+// Durations like 5s, 1.5s, 500ms or 2m. A plain number is seconds.
+fn parse_duration(text: &str) -> Result<Duration, String> {
+    let invalid = || Message::InvalidDuration(text).to_string();
+    let text = text.trim();
+    let unit_start = text
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(unit_start);
+    let number: f64 = number.trim().parse().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "" | "s" => number,
+        "ms" => number / 1000.0,
+        "m" => number * 60.0,
+        _ => return Err(invalid()),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid())
+}
+
+fn print_solutions(
+    solutions: impl Iterator<Item = SolvedBoard>,
+    start: Instant,
+    report: &mut Report,
+) {
+    let mut rows = SolutionRows::new();
+    for (i, solved_board) in solutions.enumerate() {
+        if rows.per_row() > 1 {
+            rows.push(i + 1, &solved_board);
+        } else {
+            let message = Message::Solution {
+                number: i + 1,
+                elapsed: start.elapsed(),
+                test_count: solved_board.stats.test_count,
+            };
+            outln!("{message}");
+            print_board(&solved_board);
+        }
+        report.solutions.push(SolutionReport {
+            placed_bricks: solved_board.placed_bricks,
+            test_count: solved_board.stats.test_count,
+        });
+    }
+    if rows.per_row() > 1 {
+        rows.flush();
+        let message = Message::SolutionCount {
+            count: report.solutions.len(),
+            elapsed: start.elapsed(),
+        };
+        outln!("{message}");
+    }
 }
 
-fn print_bricks(bricks: &[u64]) {
-    let mut result: [u8; 51] = [0; 51];
-    for (brick_number, brick) in bricks.iter().enumerate() {
-        for (i, result) in result.iter_mut().enumerate() {
-            if 1 << 63 >> i & brick > 0 {
-                *result = brick_number as u8 + 1;
-            }
+fn print_thread_stats(threads: &[ThreadStats]) {
+    for (i, thread) in threads.iter().enumerate() {
+        let message = Message::ThreadStats {
+            number: i + 1,
+            solutions: thread.solutions,
+            test_count: thread.test_count,
+        };
+        outln!("{message}");
+    }
+}
+
+// One solution goes to the path, more to the path numbered from 1.
+fn write_pictures(
+    path: &Path,
+    picture: Picture,
+    solutions: &[SolvedBoard],
+    scale: u32,
+    palette: &Palette,
+) {
+    for (i, solution) in solutions.iter().enumerate() {
+        let path = match solutions.len() {
+            1 => path.to_path_buf(),
+            _ => numbered(path, i + 1),
+        };
+        let contents = match picture {
+            Picture::Png => solution.to_png_with(scale, palette),
+            Picture::Svg => solution.to_svg(scale, palette).into_bytes(),
+        };
+        if let Err(error) = std::fs::write(&path, contents) {
+            could_not_write(&path.display().to_string(), &error);
+        }
+    }
+    let path = path.display().to_string();
+    let kind = match picture {
+        Picture::Png => "PNG",
+        Picture::Svg => "SVG",
+    };
+    let count = solutions.len();
+    outln!(
+        "{}",
+        Message::ImagesWritten {
+            count,
+            kind,
+            path: &path
         }
+    );
+}
+
+fn numbered(path: &Path, number: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{stem}-{number}.{}", extension.to_string_lossy()),
+        None => format!("{stem}-{number}"),
+    };
+    path.with_file_name(name)
+}
+
+fn could_not_write(path: &str, error: &std::io::Error) -> ! {
+    let message = Message::CouldNotWrite { path, error };
+    eprintln!("{}", Message::Error(&message));
+    exit(1);
+}
+
+fn write_contact_sheet(path: &Path, solutions: &[SolvedBoard], palette: &Palette) {
+    let shown = path.display().to_string();
+    if let Err(error) = contact_sheet::write(path, solutions, palette) {
+        could_not_write(&shown, &error);
     }
-    println!("╔══════╗");
-    for (y, line) in result.chunks(8).enumerate() {
-        print!("║");
-        for (x, b) in line.iter().enumerate() {
-            if (y < 2 && x < 6) || (y > 1 && x < 7) {
-                print!("{}", brick_dot(*b));
+    let count = solutions.len();
+    outln!(
+        "{}",
+        Message::ContactSheet {
+            count,
+            path: &shown
+        }
+    );
+}
+
+fn self_test() {
+    let mut failed = false;
+    for (index, (brick, cells)) in Brick::all_bricks()
+        .iter()
+        .zip(STANDARD_BRICK_CELLS)
+        .enumerate()
+    {
+        let issues = audit_brick(brick, cells);
+        println!(
+            "{}",
+            Message::BrickAudited {
+                brick: index + 1,
+                issues: issues.len()
             }
+        );
+        failed |= !issues.is_empty();
+        for issue in issues {
+            println!("  {}", Message::AuditIssue(issue));
         }
-        match y {
-            1 => println!("╚╗"),
-            6 => println!("╔═══╝"),
-            _ => println!("║"),
-        };
     }
-    println!("╚═══╝");
+    if failed {
+        exit(1);
+    }
 }
 
-fn print_board(board: &SolvedBoard) {
-    print_bricks(board.placed_bricks.as_slice());
+fn print_redundancy() {
+    let bricks = Brick::all_bricks();
+    let redundancies = brick_redundancy(&bricks);
+    if redundancies.is_empty() {
+        outln!("{}", Message::NoRedundantBrick);
+    }
+    for redundancy in redundancies {
+        let brick_number = redundancy.brick as u8 + 1;
+        outln!("\n{}", Message::Replaceable(brick_number));
+        print_panels(&[shape_panel(bricks[redundancy.brick].shape(), brick_number)]);
+        outln!();
+        let shapes: Vec<_> = redundancy
+            .replacements
+            .iter()
+            .map(|shape| shape_panel(*shape, brick_number))
+            .collect();
+        print_panels(&shapes);
+    }
 }
 
-fn brick_dot<'a>(brick_number: u8) -> ANSIGenericString<'a, str> {
-    match brick_number {
-        0 => Style::new().bold().paint("O"),
-        brick_number => Color::Black.on(Fixed(brick_number)).paint("■"),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_durations() {
+        for (text, expected) in [
+            ("5", Duration::from_secs(5)),
+            ("5s", Duration::from_secs(5)),
+            ("1.5s", Duration::from_millis(1500)),
+            ("500ms", Duration::from_millis(500)),
+            ("2m", Duration::from_secs(120)),
+            (" 0.25 s ", Duration::from_millis(250)),
+            ("0", Duration::ZERO),
+        ] {
+            assert_eq!(parse_duration(text), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for text in ["", "s", "5h", "5 sec", "-1s", "1e400", "five"] {
+            assert_eq!(
+                parse_duration(text),
+                Err(Message::InvalidDuration(text).to_string()),
+                "{text}"
+            );
+        }
     }
 }
@@ -0,0 +1,9 @@
+use solver::{Brick, Hint, SolvedBoard};
+use std::time::Instant;
+
+// Solutions and hints collected for a --format other than text, printed once the run is done.
+pub trait Output {
+    fn push_solution(&mut self, solution: &SolvedBoard);
+    fn push_hint(&mut self, hint: &Hint, bricks: &[Brick]);
+    fn print(self: Box<Self>, start: Instant);
+}
@@ -0,0 +1,91 @@
+use crate::hooks::shell_command;
+use crate::i18n::Message;
+use std::fmt::Arguments;
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Stdio};
+use std::sync::Mutex;
+
+static PAGER_INPUT: Mutex<Option<ChildStdin>> = Mutex::new(None);
+static OUTPUT_FILE: Mutex<Option<BufWriter<File>>> = Mutex::new(None);
+
+// Like println!, but goes to the --output file or through the pager when there is one.
+macro_rules! outln {
+    () => {
+        $crate::pager::write_line(format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::pager::write_line(format_args!($($arg)*))
+    };
+}
+pub(crate) use outln;
+
+pub struct Pager {
+    child: Child,
+}
+
+// Starts $PAGER (or less) when stdout is a terminal. Colors are kept since stdout stays the terminal.
+pub fn start() -> Option<Pager> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    let command = std::env::var("PAGER").unwrap_or_else(|_| "less".to_string());
+    let mut pager = shell_command(&command);
+    if std::env::var_os("LESS").is_none() {
+        pager.env("LESS", "FRX");
+    }
+    match pager.stdin(Stdio::piped()).spawn() {
+        Ok(mut child) => {
+            *PAGER_INPUT.lock().unwrap() = child.stdin.take();
+            Some(Pager { child })
+        }
+        Err(error) => {
+            let warning = Message::PagerNotStarted {
+                command: &command,
+                error: &error,
+            };
+            eprintln!("{}", Message::Warning(&warning));
+            None
+        }
+    }
+}
+
+impl Pager {
+    pub fn wait(mut self) {
+        PAGER_INPUT.lock().unwrap().take();
+        let _ = self.child.wait();
+    }
+}
+
+// Sends everything printed with outln! to the file instead, until `finish_file`.
+pub fn to_file(path: &Path) -> io::Result<()> {
+    *OUTPUT_FILE.lock().unwrap() = Some(BufWriter::new(File::create(path)?));
+    Ok(())
+}
+
+pub fn finish_file() -> io::Result<()> {
+    match OUTPUT_FILE.lock().unwrap().take() {
+        Some(mut file) => file.flush(),
+        None => Ok(()),
+    }
+}
+
+pub fn write_line(line: Arguments) {
+    if let Some(file) = OUTPUT_FILE.lock().unwrap().as_mut() {
+        if let Err(error) = writeln!(file, "{line}") {
+            eprintln!("{}", Message::Error(&Message::CouldNotWriteOutput(&error)));
+            crate::hooks::exit(1);
+        }
+        return;
+    }
+    match PAGER_INPUT.lock().unwrap().as_mut() {
+        // The user quit the pager, so there is no one left to show anything to.
+        Some(input) => {
+            if writeln!(input, "{line}").is_err() {
+                crate::hooks::exit(0);
+            }
+        }
+        None => println!("{line}"),
+    }
+}
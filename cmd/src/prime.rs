@@ -0,0 +1,47 @@
+use crate::cache::{self, CachedDay};
+use crate::i18n::Message;
+use chrono::{Datelike, Days, NaiveDate};
+use solver::{Board, Brick};
+use std::process::{Command, Stdio};
+
+// Fills the cache for the date and the days after it, skipping dates that are cached already.
+pub fn run(from: NaiveDate, days: u32, low_priority: bool) {
+    if low_priority {
+        lower_priority();
+    }
+    let bricks = Brick::all_bricks();
+    for date in (0..days as u64).filter_map(|offset| from.checked_add_days(Days::new(offset))) {
+        let (day, month) = (date.day() as u8, date.month() as u8);
+        if cache::load(day, month, &bricks).is_some() {
+            println!("{}", Message::AlreadyCached(date));
+            continue;
+        }
+        let cached = CachedDay::compute(Board::for_date(day, month).unwrap(), &bricks);
+        match cache::store(day, month, &bricks, &cached) {
+            Ok(()) => println!(
+                "{}",
+                Message::Cached {
+                    date,
+                    solutions: cached.solutions
+                }
+            ),
+            Err(error) => {
+                eprintln!("{}", Message::Error(&error));
+                crate::hooks::exit(1);
+            }
+        }
+    }
+}
+
+// Asks the scheduler to run this process only when nothing else wants the CPU.
+#[cfg(unix)]
+fn lower_priority() {
+    let _ = Command::new("renice")
+        .args(["-n", "19", "-p", &std::process::id().to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+#[cfg(not(unix))]
+fn lower_priority() {}
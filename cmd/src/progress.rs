@@ -0,0 +1,40 @@
+use crate::i18n::Message;
+use solver::Progress;
+use std::io::{IsTerminal, Write, stderr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+const REFRESH: Duration = Duration::from_millis(100);
+
+// Runs the work while showing a live progress line on stderr, when stderr is a terminal. Work
+// done within the first refresh shows nothing.
+pub fn with_progress_line<T>(progress: &Progress, work: impl FnOnce() -> T) -> T {
+    if !stderr().is_terminal() {
+        return work();
+    }
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            let mut shown = false;
+            thread::sleep(REFRESH);
+            while !done.load(Ordering::Relaxed) {
+                let message = Message::Progress {
+                    test_count: progress.test_count(),
+                    solutions: progress.solutions(),
+                    elapsed: progress.elapsed(),
+                };
+                eprint!("\r{message}");
+                let _ = stderr().flush();
+                shown = true;
+                thread::sleep(REFRESH);
+            }
+            if shown {
+                eprint!("\r\x1b[K");
+            }
+        });
+        let result = work();
+        done.store(true, Ordering::Relaxed);
+        result
+    })
+}
@@ -0,0 +1,123 @@
+use crate::Format;
+use crate::date;
+use crate::i18n::Message;
+use crate::pager::outln;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use solver::{Board, Brick, SolveOptions, solve_with_options};
+use std::time::Instant;
+
+#[derive(Serialize)]
+struct DateSummary {
+    date: String,
+    solutions: usize,
+    test_count: u64,
+    elapsed_ms: u128,
+}
+
+// A range like 2025-01-01..2025-03-31, both ends included. It may not end before it starts.
+pub fn parse_range(text: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+    let (from, to) = text
+        .split_once("..")
+        .ok_or_else(|| Message::InvalidRange(text).to_string())?;
+    let (from, to) = (date::parse(from, today)?, date::parse(to, today)?);
+    if from > to {
+        return Err(Message::ReversedRange { text, from, to }.to_string());
+    }
+    Ok((from, to))
+}
+
+// Solves every date from `from` to `to` with the same bricks, printing a summary per date.
+pub fn run(from: NaiveDate, to: NaiveDate, format: Format, bricks: &[Brick]) {
+    let start = Instant::now();
+    let options = SolveOptions::new();
+    if format == Format::Csv {
+        outln!("date,solutions,test_count,elapsed_ms");
+    }
+    let mut summaries = vec![];
+    for date in from.iter_days().take_while(|date| *date <= to) {
+        let board = Board::for_date(date.day() as u8, date.month() as u8).unwrap();
+        let mut search = solve_with_options(board, bricks, &options);
+        let solutions = search.by_ref().count();
+        let stats = search.stats();
+        let summary = DateSummary {
+            date: date.to_string(),
+            solutions,
+            test_count: stats.test_count,
+            elapsed_ms: stats.elapsed.as_millis(),
+        };
+        match format {
+            Format::Text => outln!(
+                "{}",
+                Message::DateSummary {
+                    date,
+                    solutions,
+                    test_count: stats.test_count,
+                    elapsed: stats.elapsed,
+                }
+            ),
+            Format::Csv => outln!(
+                "{date},{solutions},{},{:.3}",
+                stats.test_count,
+                stats.elapsed.as_secs_f64() * 1000.0
+            ),
+            Format::Json => {}
+        }
+        summaries.push(summary);
+    }
+    match format {
+        Format::Text => outln!(
+            "{}",
+            Message::RangeSummary {
+                dates: summaries.len(),
+                solutions: summaries.iter().map(|summary| summary.solutions).sum(),
+                elapsed: start.elapsed(),
+            }
+        ),
+        Format::Json => {
+            let json = serde_json::to_string(&summaries).expect("Summaries serialize to JSON");
+            outln!("{json}");
+        }
+        Format::Csv => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn reads_ranges() {
+        let today = day(2025, 6, 1);
+        assert_eq!(
+            parse_range("2025-01-01..2025-03-31", today),
+            Ok((day(2025, 1, 1), day(2025, 3, 31)))
+        );
+        assert_eq!(
+            parse_range("2025-01-01..2025-01-01", today),
+            Ok((day(2025, 1, 1), day(2025, 1, 1)))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        let today = day(2025, 6, 1);
+        assert_eq!(
+            parse_range("2025-01-01", today),
+            Err(Message::InvalidRange("2025-01-01").to_string())
+        );
+        assert_eq!(
+            parse_range("2025-03-31..2025-01-01", today),
+            Err(Message::ReversedRange {
+                text: "2025-03-31..2025-01-01",
+                from: day(2025, 3, 31),
+                to: day(2025, 1, 1),
+            }
+            .to_string())
+        );
+    }
+}
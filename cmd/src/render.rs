@@ -0,0 +1,676 @@
+use crate::i18n::{Message, month_labels};
+use crate::pager::outln;
+use ansi_term::Color::Fixed;
+use ansi_term::{ANSIGenericString, Color, Style};
+use solver::{Brick, Inventory, SolvedBoard};
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+const TRAY_HEIGHT: usize = 9;
+const PANEL_GAP: usize = 3;
+const SOLUTION_PANEL_WIDTH: usize = 13;
+const GRID_PANEL_WIDTH: usize = 9;
+const LABEL_WIDTH: usize = 3;
+
+static COLORS_ENABLED: AtomicBool = AtomicBool::new(true);
+static THEME: AtomicU8 = AtomicU8::new(Theme::Classic as u8);
+static ASCII: AtomicBool = AtomicBool::new(false);
+static LABELS: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Colors on a terminal, unless NO_COLOR is set.
+    Auto,
+    Always,
+    Never,
+}
+
+// The colors of the bricks. All are drawn as black squares on the color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Theme {
+    /// The first colors of the terminal's own palette.
+    Classic,
+    /// Light colors that stay readable on a light background.
+    Pastel,
+    /// The bright variants of the terminal's palette.
+    Bright,
+}
+
+impl Theme {
+    fn color(self, brick_number: u8) -> Color {
+        let colors: &[u8] = match self {
+            Theme::Classic => return Fixed(brick_number),
+            Theme::Pastel => &[217, 151, 229, 153, 183, 159, 252, 223, 195, 225],
+            Theme::Bright => &[9, 10, 11, 12, 13, 14, 15, 208, 118, 45],
+        };
+        Fixed(colors[(brick_number as usize - 1) % colors.len()])
+    }
+}
+
+// What is printed on the cells of a solution, the way it is on the physical board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Labels {
+    /// The month and day on the cells left uncovered.
+    Date,
+    /// Every month and day, faintly on the cells covered by bricks.
+    All,
+}
+
+// Colors are only used on terminals that understand ANSI escapes, and not when NO_COLOR is set
+// (https://no-color.org). Older Windows consoles need them switched on explicitly, and when that
+// fails the plain letter renderer is used instead.
+pub fn detect_color_support(choice: ColorChoice, theme: Theme) {
+    let enabled = match choice {
+        ColorChoice::Always => {
+            enable_ansi();
+            true
+        }
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            let terminal = std::io::stdout().is_terminal();
+            let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            terminal && !dumb && !no_color && enable_ansi()
+        }
+    };
+    COLORS_ENABLED.store(enabled, Ordering::Relaxed);
+    THEME.store(theme as u8, Ordering::Relaxed);
+}
+
+fn theme() -> Theme {
+    match THEME.load(Ordering::Relaxed) {
+        theme if theme == Theme::Pastel as u8 => Theme::Pastel,
+        theme if theme == Theme::Bright as u8 => Theme::Bright,
+        _ => Theme::Classic,
+    }
+}
+
+#[cfg(windows)]
+fn enable_ansi() -> bool {
+    ansi_term::enable_ansi_support().is_ok()
+}
+
+#[cfg(not(windows))]
+fn enable_ansi() -> bool {
+    true
+}
+
+// Plain ASCII borders and brick letters instead of box drawing characters and squares.
+pub fn set_ascii(ascii: bool) {
+    ASCII.store(ascii, Ordering::Relaxed);
+}
+
+pub fn ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+pub fn set_labels(labels: Option<Labels>) {
+    LABELS.store(
+        labels.map_or(0, |labels| labels as u8 + 1),
+        Ordering::Relaxed,
+    );
+}
+
+fn labels() -> Option<Labels> {
+    match LABELS.load(Ordering::Relaxed) {
+        0 => None,
+        labels if labels == Labels::Date as u8 + 1 => Some(Labels::Date),
+        _ => Some(Labels::All),
+    }
+}
+
+pub fn colors_enabled() -> bool {
+    COLORS_ENABLED.load(Ordering::Relaxed)
+}
+
+// The settings are for the whole process, so tests drawing with them take turns, each setting
+// all of them first.
+#[cfg(test)]
+pub fn with_settings<T>(
+    colors: bool,
+    ascii: bool,
+    labels: Option<Labels>,
+    draw: impl FnOnce() -> T,
+) -> T {
+    static TURN: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    let _turn = TURN.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    COLORS_ENABLED.store(colors, Ordering::Relaxed);
+    THEME.store(Theme::Classic as u8, Ordering::Relaxed);
+    set_ascii(ascii);
+    set_labels(labels);
+    draw()
+}
+
+pub fn paint<'a>(style: Style, text: &'a str) -> ANSIGenericString<'a, str> {
+    if colors_enabled() {
+        style.paint(text)
+    } else {
+        Style::new().paint(text)
+    }
+}
+
+// A block of rendered lines. The width is tracked separately since the lines contain ANSI escapes.
+pub struct Panel {
+    pub lines: Vec<String>,
+    pub width: usize,
+}
+
+pub fn terminal_width() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    terminal_size::terminal_size().map(|(terminal_size::Width(width), _)| width as usize)
+}
+
+// Collects solution panels and prints them as many side by side as the terminal has room for.
+pub struct SolutionRows {
+    per_row: usize,
+    pending: Vec<Panel>,
+}
+
+impl SolutionRows {
+    pub fn new() -> SolutionRows {
+        let panel_width = SOLUTION_PANEL_WIDTH.max(solution_grid_width());
+        let per_row = terminal_width().map_or(1, |width| {
+            ((width + PANEL_GAP) / (panel_width + PANEL_GAP)).max(1)
+        });
+        SolutionRows {
+            per_row,
+            pending: Vec::with_capacity(per_row),
+        }
+    }
+
+    pub fn per_row(&self) -> usize {
+        self.per_row
+    }
+
+    pub fn push(&mut self, number: usize, board: &SolvedBoard) {
+        let grid = grid_panel_for(&solution_cells(board));
+        let width = SOLUTION_PANEL_WIDTH.max(grid.width);
+        let mut lines = vec![Message::SolutionPanel(number).to_string()];
+        lines.extend(grid.lines);
+        lines.push(Message::PanelTests(board.stats.test_count).to_string());
+        self.pending.push(Panel { lines, width });
+        if self.pending.len() == self.per_row {
+            self.flush();
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            print_panels(&self.pending);
+            outln!();
+            self.pending.clear();
+        }
+    }
+}
+
+// The board of a solution, labelled like the physical board when asked for and there is room.
+fn grid_panel_for(cells: &[u8; 51]) -> Panel {
+    match labels() {
+        Some(labels) if solution_grid_width() > GRID_PANEL_WIDTH => {
+            framed_panel(LABEL_WIDTH, |i| labelled_cell(cells[i], i, labels))
+        }
+        _ => grid_panel(|i| brick_dot(cells[i])),
+    }
+}
+
+fn solution_grid_width() -> usize {
+    let labelled = 7 * LABEL_WIDTH + 2;
+    match labels() {
+        Some(_) if terminal_width().is_none_or(|width| width >= labelled) => labelled,
+        _ => GRID_PANEL_WIDTH,
+    }
+}
+
+// The month or day printed on the cell of the physical board, as wide as a labelled cell.
+fn cell_label(i: usize) -> String {
+    let (y, x) = (i / 8, i % 8);
+    match y {
+        0 | 1 => month_labels()[y * 6 + x].to_string(),
+        _ => format!("{:>LABEL_WIDTH$}", (y - 2) * 7 + x + 1),
+    }
+}
+
+fn labelled_cell(brick_number: u8, i: usize, labels: Labels) -> String {
+    let label = cell_label(i);
+    match brick_number {
+        0 => paint(Style::new().bold(), &label).to_string(),
+        brick_number if colors_enabled() => {
+            let style = Color::Black.on(theme().color(brick_number));
+            match labels {
+                Labels::Date => style.paint(" ".repeat(LABEL_WIDTH)).to_string(),
+                Labels::All => style.dimmed().paint(label).to_string(),
+            }
+        }
+        brick_number => brick_letter(brick_number).repeat(LABEL_WIDTH),
+    }
+}
+
+pub fn brick_cells(bricks: &[u64]) -> [u8; 51] {
+    let mut result: [u8; 51] = [0; 51];
+    for (brick_number, brick) in bricks.iter().enumerate() {
+        for (i, result) in result.iter_mut().enumerate() {
+            if 1 << 63 >> i & brick > 0 {
+                *result = brick_number as u8 + 1;
+            }
+        }
+    }
+    result
+}
+
+// Like `brick_cells`, numbering each brick by the physical piece it is, so a piece keeps its
+// color from one solution to the next.
+pub fn solution_cells(board: &SolvedBoard) -> [u8; 51] {
+    let grid = board.to_grid();
+    std::array::from_fn(|i| grid[i / 8].get(i % 8).copied().unwrap_or(0))
+}
+
+pub fn print_board(board: &SolvedBoard) {
+    print_panels(&[grid_panel_for(&solution_cells(board))]);
+}
+
+// Prints the placed bricks with the bricks still left in the box beside the board.
+pub fn print_with_tray(placed_bricks: &[u64], bricks: &[Brick]) {
+    print_with_tray_using(placed_bricks, bricks, brick_dot);
+}
+
+// Like `print_with_tray`, with the placed bricks shaded so they stand out from bricks placed by
+// the player.
+pub fn print_pre_placed(placed_bricks: &[u64], bricks: &[Brick]) {
+    print_with_tray_using(placed_bricks, bricks, pre_placed_dot);
+}
+
+// Prints a board finished from bricks the player put there, with theirs shaded like pre-placed
+// bricks so the ones still to place stand out.
+pub fn print_finished(players: &[u64], finished: &[u64], bricks: &[Brick]) {
+    let mut inventory = Inventory::new(bricks);
+    let mut cells = [(0, false); 51];
+    let placements = players.iter().map(|placement| (placement, true));
+    for (placement, theirs) in placements.chain(finished.iter().map(|placement| (placement, false)))
+    {
+        let brick_number = inventory.place(*placement).map_or(0, |i| i as u8 + 1);
+        for (i, cell) in cells.iter_mut().enumerate() {
+            if 1 << 63 >> i & placement > 0 {
+                *cell = (brick_number, theirs);
+            }
+        }
+    }
+    print_panels(&[grid_panel(|i| match cells[i] {
+        (brick_number, true) => pre_placed_dot(brick_number),
+        (brick_number, false) => brick_dot(brick_number),
+    })]);
+}
+
+// Prints the board with one cell marked, and the shape of the brick covering it if given.
+pub fn print_marked_cell(cell: usize, brick: Option<(usize, &Brick)>) {
+    print_marked_cells(|i| i == cell, brick);
+}
+
+// Prints the shape of the brick on its own, a hint that gives away nothing about where it goes.
+pub fn print_brick(brick_index: usize, brick: &Brick) {
+    print_panels(&[shape_panel(brick.shape(), brick_index as u8 + 1)]);
+}
+
+// Prints a small board for each placement of the brick, side by side as far as they fit.
+pub fn print_placements(placements: &[u64], brick_number: u8) {
+    let boards: Vec<Panel> = placements
+        .iter()
+        .map(|placement| {
+            grid_panel(|i| match 1 << 63 >> i & placement {
+                0 => brick_dot(0),
+                _ => brick_dot(brick_number),
+            })
+        })
+        .collect();
+    print_panels(&boards);
+}
+
+// Like `print_marked_cell`, marking every cell the function picks.
+pub fn print_marked_cells(marked: impl Fn(usize) -> bool, brick: Option<(usize, &Brick)>) {
+    let board = grid_panel(|i| match marked(i) {
+        true => paint(Style::new().bold().reverse(), "?"),
+        false => brick_dot(0),
+    });
+    match brick {
+        Some((brick_index, brick)) => {
+            print_panels(&[board, shape_panel(brick.shape(), brick_index as u8 + 1)])
+        }
+        None => print_panels(&[board]),
+    }
+}
+
+fn print_with_tray_using<'a>(
+    placed_bricks: &[u64],
+    bricks: &[Brick],
+    dot: impl Fn(u8) -> ANSIGenericString<'a, str>,
+) {
+    let mut inventory = Inventory::new(bricks);
+    let mut cells = [0; 51];
+    for placement in placed_bricks {
+        let brick_number = inventory.place(*placement).map_or(0, |i| i as u8 + 1);
+        for (i, cell) in cells.iter_mut().enumerate() {
+            if 1 << 63 >> i & placement > 0 {
+                *cell = brick_number;
+            }
+        }
+    }
+    let board = grid_panel(|i| dot(cells[i]));
+    if inventory.is_empty() {
+        print_panels(&[board]);
+    } else {
+        print_panels(&[board, tray_panel(&inventory)]);
+    }
+}
+
+// Whether the index (row * 8 + column) is a cell of the board, rather than outside its outline.
+pub fn is_board_cell(i: usize) -> bool {
+    let (y, x) = (i / 8, i % 8);
+    i < 51 && ((y < 2 && x < 6) || (y > 1 && x < 7))
+}
+
+pub fn grid_panel<'a>(dot: impl Fn(usize) -> ANSIGenericString<'a, str>) -> Panel {
+    if terminal_width().is_some_and(|width| width < GRID_PANEL_WIDTH) {
+        return compact_grid_panel(dot);
+    }
+    framed_panel(1, |i| dot(i).to_string())
+}
+
+// The board in its frame, with each cell `cell_width` characters wide.
+fn framed_panel(cell_width: usize, cell: impl Fn(usize) -> String) -> Panel {
+    let line = |corner: &str, edge: &str, cells: usize, end: &str| {
+        corner.to_string() + &edge.repeat(cells) + end
+    };
+    let [top, side, step, ledge, bottom] = match ascii() {
+        true => [
+            line("+", "-", 6 * cell_width, "+"),
+            "|".to_string(),
+            line("|", "_", cell_width, ""),
+            line("+", "-", 4 * cell_width - 1, "+"),
+            line("+", "-", 3 * cell_width, "+"),
+        ],
+        false => [
+            line("╔", "═", 6 * cell_width, "╗"),
+            "║".to_string(),
+            line("╚", "═", cell_width - 1, "╗"),
+            line("╔", "═", 4 * cell_width - 1, "╝"),
+            line("╚", "═", 3 * cell_width, "╝"),
+        ],
+    };
+    let mut lines = vec![top];
+    for y in 0..7 {
+        let mut line = side.clone();
+        for x in 0..8 {
+            if is_board_cell(y * 8 + x) {
+                line += &cell(y * 8 + x);
+            }
+        }
+        line += match y {
+            1 => &step,
+            6 => &ledge,
+            _ => &side,
+        };
+        lines.push(line);
+    }
+    lines.push(bottom);
+    Panel {
+        lines,
+        width: 7 * cell_width + 2,
+    }
+}
+
+// The board with weekdays: Sunday to Wednesday end the row below the days, Thursday to Saturday
+// are in an extra row at the bottom right.
+pub fn weekday_grid_panel<'a>(dot: impl Fn(usize) -> ANSIGenericString<'a, str>) -> Panel {
+    let [top, side, step, foot, bottom] = match ascii() {
+        true => ["+------+", "|", "|_", "+---+", "    +---+"],
+        false => ["╔══════╗", "║", "╚╗", "╚═══╗", "    ╚═══╝"],
+    };
+    let mut lines = vec![top.to_string()];
+    for y in 0..8 {
+        let mut line = match y {
+            7 => foot.to_string(),
+            _ => side.to_string(),
+        };
+        for x in 0..8 {
+            if is_weekday_board_cell(y * 8 + x) {
+                line += &dot(y * 8 + x).to_string();
+            }
+        }
+        line += match y {
+            1 => step,
+            _ => side,
+        };
+        lines.push(line);
+    }
+    lines.push(bottom.to_string());
+    Panel {
+        lines,
+        width: GRID_PANEL_WIDTH,
+    }
+}
+
+fn is_weekday_board_cell(i: usize) -> bool {
+    let (y, x) = (i / 8, i % 8);
+    (y < 2 && x < 6) || ((2..7).contains(&y) && x < 7) || (y == 7 && (4..7).contains(&x))
+}
+
+pub fn print_weekday_board(board: &SolvedBoard) {
+    let grid = board.to_grid();
+    print_panels(&[weekday_grid_panel(|i| brick_dot(grid[i / 8][i % 8]))]);
+}
+
+// The cells without the frame, for terminals too narrow for the box drawing to fit.
+fn compact_grid_panel<'a>(dot: impl Fn(usize) -> ANSIGenericString<'a, str>) -> Panel {
+    let lines = (0..7)
+        .map(|y| {
+            let columns = if y < 2 { 6 } else { 7 };
+            (0..columns)
+                .map(|x| y * 8 + x)
+                .filter(|&i| i < 51)
+                .map(|i| dot(i).to_string())
+                .collect()
+        })
+        .collect();
+    Panel { lines, width: 7 }
+}
+
+// The unused bricks in their first orientation, stacked in columns no taller than the board.
+fn tray_panel(inventory: &Inventory) -> Panel {
+    let mut columns: Vec<Panel> = vec![];
+    for (brick_index, brick) in inventory.unused() {
+        let shape = shape_panel(brick.shape(), brick_index as u8 + 1);
+        match columns.last_mut() {
+            Some(column) if column.lines.len() + 1 + shape.lines.len() <= TRAY_HEIGHT => {
+                column.lines.push(String::new());
+                column.width = column.width.max(shape.width);
+                column.lines.extend(shape.lines);
+            }
+            _ => columns.push(shape),
+        }
+    }
+    Panel {
+        width: columns.iter().map(|column| column.width + 1).sum(),
+        lines: side_by_side(&columns, 1),
+    }
+}
+
+pub fn shape_panel(shape: u64, brick_number: u8) -> Panel {
+    let rows: Vec<u8> = shape
+        .to_be_bytes()
+        .into_iter()
+        .filter(|row| *row != 0)
+        .collect();
+    let width = rows
+        .iter()
+        .map(|row| 8 - row.trailing_zeros() as usize)
+        .max()
+        .unwrap_or(0);
+    let lines = rows
+        .iter()
+        .map(|row| {
+            (0..width)
+                .map(|x| match row & (0x80 >> x) {
+                    0 => " ".to_string(),
+                    _ => brick_dot(brick_number).to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    Panel { lines, width }
+}
+
+pub fn side_by_side(panels: &[Panel], gap: usize) -> Vec<String> {
+    let height = panels
+        .iter()
+        .map(|panel| panel.lines.len())
+        .max()
+        .unwrap_or(0);
+    (0..height)
+        .map(|y| {
+            let mut line = String::new();
+            for (i, panel) in panels.iter().enumerate() {
+                let part = panel.lines.get(y).map_or("", String::as_str);
+                line += part;
+                if i + 1 < panels.len() {
+                    let padding = panel.width - visible_width(part) + gap;
+                    line += &" ".repeat(padding);
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+// Prints the panels side by side, wrapping to more rows of panels when the terminal is too narrow
+// for all of them.
+pub fn print_panels(panels: &[Panel]) {
+    let width = terminal_width().unwrap_or(usize::MAX);
+    let mut start = 0;
+    while start < panels.len() {
+        let mut end = start + 1;
+        let mut row_width = panels[start].width;
+        while end < panels.len() && row_width + PANEL_GAP + panels[end].width <= width {
+            row_width += PANEL_GAP + panels[end].width;
+            end += 1;
+        }
+        if start > 0 {
+            outln!();
+        }
+        for line in side_by_side(&panels[start..end], PANEL_GAP) {
+            outln!("{}", line.trim_end());
+        }
+        start = end;
+    }
+}
+
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in line.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if !in_escape => width += 1,
+            _ => {}
+        }
+    }
+    width
+}
+
+const BRICK_LETTERS: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "P", "Q", "R", "S", "T",
+    "U", "V", "W", "X", "Y", "Z", "#",
+];
+
+fn brick_letter(brick_number: u8) -> &'static str {
+    BRICK_LETTERS[(brick_number as usize - 1).min(BRICK_LETTERS.len() - 1)]
+}
+
+// A square in the brick's color, or its letter without colors. With --ascii the letter is drawn
+// on the color instead of the square.
+pub fn brick_dot<'a>(brick_number: u8) -> ANSIGenericString<'a, str> {
+    let color = theme().color(brick_number.max(1));
+    match brick_number {
+        0 => paint(Style::new().bold(), "O"),
+        brick_number if colors_enabled() && ascii() => {
+            Color::Black.on(color).paint(brick_letter(brick_number))
+        }
+        _ if colors_enabled() => Color::Black.on(color).paint("■"),
+        brick_number => Style::new().paint(brick_letter(brick_number)),
+    }
+}
+
+// Shaded in the brick's color, or its letter in lowercase without colors.
+fn pre_placed_dot<'a>(brick_number: u8) -> ANSIGenericString<'a, str> {
+    let color = theme().color(brick_number.max(1));
+    match brick_number {
+        0 => brick_dot(0),
+        brick_number if colors_enabled() && ascii() => color
+            .on(Color::Black)
+            .paint(brick_letter(brick_number).to_lowercase()),
+        _ if colors_enabled() => color.on(Color::Black).paint("▒"),
+        brick_number => Style::new().paint(brick_letter(brick_number).to_lowercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::assert_text;
+    use solver::{Board, solve};
+
+    fn text(panel: &Panel) -> String {
+        panel.lines.join("\n") + "\n"
+    }
+
+    fn first_solution(day: u8, month: u8) -> SolvedBoard {
+        solve(Board::for_date(day, month).unwrap(), &Brick::all_bricks())
+            .next()
+            .unwrap()
+    }
+
+    #[test]
+    fn panels() {
+        let bricks = Brick::all_bricks();
+        for (colors, palette) in [(true, "ansi"), (false, "letters")] {
+            with_settings(colors, false, None, || {
+                for (name, day, month) in [("sep_22", 22, 9), ("jan_1", 1, 1), ("dec_31", 31, 12)] {
+                    let cells = solution_cells(&first_solution(day, month));
+                    let board = grid_panel_for(&cells);
+                    assert_text(&format!("board_{name}_{palette}"), &text(&board));
+                    let compact = compact_grid_panel(|i| brick_dot(cells[i]));
+                    assert_text(&format!("compact_{name}_{palette}"), &text(&compact));
+                }
+                let mut inventory = Inventory::new(&bricks);
+                inventory.place(bricks[0].shape());
+                assert_text(&format!("tray_{palette}"), &text(&tray_panel(&inventory)));
+            });
+        }
+    }
+
+    #[test]
+    fn ascii_panel() {
+        let cells = solution_cells(&first_solution(22, 9));
+        let board = with_settings(false, true, None, || grid_panel_for(&cells));
+        assert_text("board_sep_22_ascii", &text(&board));
+    }
+
+    #[test]
+    fn labelled_panel() {
+        let cells = solution_cells(&first_solution(22, 9));
+        let board = with_settings(false, false, Some(Labels::Date), || grid_panel_for(&cells));
+        assert_text("board_sep_22_labels", &text(&board));
+    }
+
+    #[test]
+    fn weekday_panel() {
+        let board = Board::for_date_weekday(22, 9, 1).unwrap();
+        let solution = solve(board, &Brick::weekday_bricks()).next().unwrap();
+        let grid = solution.to_grid();
+        let weekday = with_settings(false, false, None, || {
+            weekday_grid_panel(|i| brick_dot(grid[i / 8][i % 8]))
+        });
+        assert_text("board_sep_22_monday", &text(&weekday));
+    }
+}
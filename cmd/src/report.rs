@@ -0,0 +1,34 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub month: u8,
+    pub solutions: Vec<SolutionReport>,
+    pub hints: Vec<HintReport>,
+    pub elapsed_ms: u128,
+}
+
+#[derive(Serialize)]
+pub struct SolutionReport {
+    pub placed_bricks: Vec<u64>,
+    pub test_count: u64,
+}
+
+#[derive(Serialize)]
+pub struct HintReport {
+    pub placed_brick: u64,
+    pub solutions: usize,
+}
+
+impl Report {
+    pub fn new(day: u8, month: u8) -> Report {
+        Report {
+            day,
+            month,
+            solutions: vec![],
+            hints: vec![],
+            elapsed_ms: 0,
+        }
+    }
+}
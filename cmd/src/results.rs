@@ -0,0 +1,284 @@
+use crate::i18n::Message;
+use chrono::NaiveDate;
+use solver::{Board, Brick, puzzle_checksum};
+use std::fmt::Write;
+use std::time::Duration;
+
+// One line per field, so result files are easy to read and paste into a chat:
+//
+//     calendar-puzzle-result 1
+//     name = Alice
+//     date = 2025-07-29
+//     time_ms = 312000
+//     hints = 2
+//     spoilers = cell,piece
+//     checksum = 9f0c6e2d1a3b4c5d
+//     signature = 0123456789abcdef
+//
+// The checksum identifies the puzzle (date and brick set). The optional signature is a keyed hash
+// of the other fields using a secret shared by the group. It only stops casual edits, it's no
+// cryptographic proof.
+//
+// Spoilers has how much each hint gave away. Results from before hints came in levels don't have
+// it, every hint there showed where a brick goes.
+const HEADER: &str = "calendar-puzzle-result 1";
+
+// How much a hint gives away. Each hint about a brick shows a bit more of it than the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintLevel {
+    /// A cell the brick covers.
+    Cell,
+    /// Which brick covers the cell.
+    Piece,
+    /// Where the brick goes.
+    Placement,
+}
+
+impl HintLevel {
+    pub const ALL: [HintLevel; 3] = [HintLevel::Cell, HintLevel::Piece, HintLevel::Placement];
+
+    // Counts towards the leaderboard, so giving away more costs more.
+    pub fn weight(self) -> u32 {
+        match self {
+            HintLevel::Cell => 1,
+            HintLevel::Piece => 2,
+            HintLevel::Placement => 3,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            HintLevel::Cell => "cell",
+            HintLevel::Piece => "piece",
+            HintLevel::Placement => "placement",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<HintLevel> {
+        HintLevel::ALL
+            .into_iter()
+            .find(|level| level.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleResult {
+    pub name: String,
+    pub date: NaiveDate,
+    pub time: Duration,
+    pub hints: u32,
+    pub spoilers: Vec<HintLevel>,
+    pub checksum: u64,
+    pub signature: Option<u64>,
+}
+
+impl PuzzleResult {
+    pub fn expected_checksum(date: NaiveDate) -> Option<u64> {
+        use chrono::Datelike;
+        let board = Board::for_date(date.day() as u8, date.month() as u8).ok()?;
+        Some(puzzle_checksum(&board, &Brick::all_bricks()))
+    }
+
+    // The level of every hint given.
+    pub fn hint_levels(&self) -> Vec<HintLevel> {
+        match self.spoilers.is_empty() {
+            true => vec![HintLevel::Placement; self.hints as usize],
+            false => self.spoilers.clone(),
+        }
+    }
+
+    pub fn spoiler_score(&self) -> u32 {
+        self.hint_levels().iter().map(|level| level.weight()).sum()
+    }
+
+    pub fn sign(&mut self, secret: &str) {
+        self.signature = Some(self.keyed_hash(secret));
+    }
+
+    pub fn has_valid_signature(&self, secret: &str) -> bool {
+        self.signature == Some(self.keyed_hash(secret))
+    }
+
+    fn keyed_hash(&self, secret: &str) -> u64 {
+        let mut hash = 0xcbf2_9ce4_8422_2325u64;
+        for byte in secret.bytes().chain(self.fields().bytes()) {
+            hash = (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        hash
+    }
+
+    fn fields(&self) -> String {
+        let mut fields = String::new();
+        writeln!(fields, "name = {}", self.name).unwrap();
+        writeln!(fields, "date = {}", self.date).unwrap();
+        writeln!(fields, "time_ms = {}", self.time.as_millis()).unwrap();
+        writeln!(fields, "hints = {}", self.hints).unwrap();
+        if !self.spoilers.is_empty() {
+            let names: Vec<&str> = self.spoilers.iter().map(|level| level.name()).collect();
+            writeln!(fields, "spoilers = {}", names.join(",")).unwrap();
+        }
+        writeln!(fields, "checksum = {:016x}", self.checksum).unwrap();
+        fields
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{HEADER}\n{}", self.fields());
+        if let Some(signature) = self.signature {
+            writeln!(text, "signature = {signature:016x}").unwrap();
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<PuzzleResult, String> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(HEADER) {
+            return Err(Message::NotAResultFile(HEADER).to_string());
+        }
+        let (mut name, mut date, mut time, mut hints, mut checksum, mut signature) =
+            (None, None, None, None, None, None);
+        let mut spoilers = vec![];
+        for line in lines {
+            let (key, value) = line
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| Message::ExpectedKeyValue(line).to_string())?;
+            let invalid = |error: &dyn std::fmt::Display| {
+                Message::InvalidResultField { key, value, error }.to_string()
+            };
+            match key {
+                "name" => name = Some(value.to_string()),
+                "date" => date = Some(value.parse::<NaiveDate>().map_err(|e| invalid(&e))?),
+                "time_ms" => {
+                    time = Some(Duration::from_millis(
+                        value.parse().map_err(|e| invalid(&e))?,
+                    ))
+                }
+                "hints" => hints = Some(value.parse().map_err(|e| invalid(&e))?),
+                "spoilers" => {
+                    spoilers = value
+                        .split(',')
+                        .map(|name| HintLevel::from_name(name.trim()))
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| invalid(&Message::SpoilerLevels))?
+                }
+                "checksum" => {
+                    checksum = Some(u64::from_str_radix(value, 16).map_err(|e| invalid(&e))?)
+                }
+                "signature" => {
+                    signature = Some(u64::from_str_radix(value, 16).map_err(|e| invalid(&e))?)
+                }
+                _ => return Err(Message::UnknownResultField(key).to_string()),
+            }
+        }
+        let missing = |field| Message::MissingResultField(field).to_string();
+        Ok(PuzzleResult {
+            name: name.ok_or_else(|| missing("name"))?,
+            date: date.ok_or_else(|| missing("date"))?,
+            time: time.ok_or_else(|| missing("time_ms"))?,
+            hints: hints.ok_or_else(|| missing("hints"))?,
+            spoilers,
+            checksum: checksum.ok_or_else(|| missing("checksum"))?,
+            signature,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> PuzzleResult {
+        let date = NaiveDate::from_ymd_opt(2025, 7, 29).unwrap();
+        PuzzleResult {
+            name: "Alice".to_string(),
+            date,
+            time: Duration::from_millis(312_000),
+            hints: 2,
+            spoilers: vec![HintLevel::Cell, HintLevel::Piece],
+            checksum: PuzzleResult::expected_checksum(date).unwrap(),
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn results_read_back() {
+        let mut result = alice();
+        assert_eq!(PuzzleResult::parse(&result.to_text()), Ok(result.clone()));
+        result.sign("secret");
+        let text = result.to_text();
+        assert!(text.starts_with("calendar-puzzle-result 1\nname = Alice\ndate = 2025-07-29\n"));
+        let read = PuzzleResult::parse(&text).unwrap();
+        assert_eq!(read, result);
+        assert!(read.has_valid_signature("secret"));
+        assert!(!read.has_valid_signature("other"));
+    }
+
+    #[test]
+    fn edited_results_lose_their_signature() {
+        let mut result = alice();
+        result.sign("secret");
+        let text = result
+            .to_text()
+            .replace("time_ms = 312000", "time_ms = 12000");
+        let read = PuzzleResult::parse(&text).unwrap();
+        assert_eq!(read.time, Duration::from_secs(12));
+        assert!(!read.has_valid_signature("secret"));
+    }
+
+    #[test]
+    fn results_without_spoilers_are_placements() {
+        let mut result = alice();
+        result.spoilers.clear();
+        let text = result.to_text();
+        assert!(!text.contains("spoilers"));
+        let read = PuzzleResult::parse(&text).unwrap();
+        assert_eq!(read.hint_levels(), [HintLevel::Placement; 2]);
+        assert_eq!(read.spoiler_score(), 6);
+        assert_eq!(
+            PuzzleResult::parse(&alice().to_text())
+                .unwrap()
+                .spoiler_score(),
+            3
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_results() {
+        let text = alice().to_text();
+        let invalid = |key, value, error: &dyn std::fmt::Display| {
+            Message::InvalidResultField { key, value, error }.to_string()
+        };
+        for (text, expected) in [
+            (
+                "name = Alice\n".to_string(),
+                Message::NotAResultFile("calendar-puzzle-result 1").to_string(),
+            ),
+            (
+                text.replace("hints = 2", "hints: 2"),
+                Message::ExpectedKeyValue("hints: 2").to_string(),
+            ),
+            (
+                text.replace("hints = 2", "hints = two"),
+                invalid("hints", "two", &"invalid digit found in string"),
+            ),
+            (
+                text.replace("cell,piece", "cell,board"),
+                invalid("spoilers", "cell,board", &Message::SpoilerLevels),
+            ),
+            (
+                text.replace("date = 2025-07-29", "date = 2025-02-30"),
+                invalid("date", "2025-02-30", &"input is out of range"),
+            ),
+            (
+                text.replace("name =", "nick ="),
+                Message::UnknownResultField("nick").to_string(),
+            ),
+            (
+                text.replace("hints = 2\n", ""),
+                Message::MissingResultField("hints").to_string(),
+            ),
+        ] {
+            assert_eq!(PuzzleResult::parse(&text), Err(expected));
+        }
+    }
+}
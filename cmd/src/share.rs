@@ -0,0 +1,66 @@
+use crate::i18n::{Localized, Message};
+use crate::input;
+use crate::leaderboard::format_time;
+use crate::pager::outln;
+use crate::results::{HintLevel, PuzzleResult};
+use chrono::Datelike;
+use solver::{Brick, difficulty_with_bricks};
+use std::path::Path;
+use std::time::Duration;
+
+// Upper limits of the time buckets, fastest first.
+const TIME_BUCKETS: [Duration; 4] = [
+    Duration::from_secs(2 * 60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(10 * 60),
+    Duration::from_secs(20 * 60),
+];
+
+// Prints a summary of a result from `cmd daily` to paste into a chat, without giving away where
+// the bricks go.
+pub fn run(path: &Path) -> Result<(), String> {
+    let text = input::read_to_string(path).map_err(|error| {
+        Message::CouldNotRead {
+            path: &path.display().to_string(),
+            error: &error,
+        }
+        .to_string()
+    })?;
+    let result = PuzzleResult::parse(&text)?;
+    let (day, month) = (result.date.day() as u8, result.date.month() as u8);
+    let report = difficulty_with_bricks(day, month, &Brick::all_bricks())
+        .map_err(|error| Localized(&error).to_string())?;
+
+    let speed = TIME_BUCKETS.len() + 1
+        - TIME_BUCKETS
+            .iter()
+            .position(|limit| result.time < *limit)
+            .unwrap_or(TIME_BUCKETS.len());
+    let difficulty = report.rating as usize;
+    let levels = result.hint_levels();
+    let hints = match levels.is_empty() {
+        true => Message::NoHintsUsed.to_string(),
+        false => levels
+            .iter()
+            .map(|level| match level {
+                HintLevel::Cell => "🔍",
+                HintLevel::Piece => "🔷",
+                HintLevel::Placement => "💡",
+            })
+            .collect(),
+    };
+    outln!("{}", Message::ShareTitle(result.date));
+    outln!(
+        "⏱️ {}{} {}",
+        "🟩".repeat(speed),
+        "⬜".repeat(5 - speed),
+        format_time(result.time)
+    );
+    outln!(
+        "🧩 {}{}",
+        "⭐".repeat(difficulty),
+        "☆".repeat(5 - difficulty)
+    );
+    outln!("{hints}");
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// Golden file checks for the renderers. The files are in cmd/snapshots. A missing file is written
+// and fails the test, so it gets looked at before committing. Run with UPDATE_SNAPSHOTS=1 to
+// rewrite the files after an intended change.
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(name)
+}
+
+// Writes the snapshot when asked to, or when there is none yet. Returns whether it was missing
+// without being asked for.
+fn update(name: &str, contents: &[u8]) -> bool {
+    let path = path(name);
+    let asked = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let missing = !path.exists();
+    if missing || asked {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, contents).unwrap();
+    }
+    missing && !asked
+}
+
+pub fn assert_text(name: &str, actual: &str) {
+    assert_file(&format!("{name}.txt"), actual);
+}
+
+pub fn assert_svg(name: &str, actual: &str) {
+    assert_file(&format!("{name}.svg"), actual);
+}
+
+fn assert_file(name: &str, actual: &str) {
+    if update(name, actual.as_bytes()) {
+        panic!("New snapshot {name} written, check it and run the tests again");
+    }
+    let expected = fs::read_to_string(path(name)).unwrap();
+    assert!(
+        expected == actual,
+        "Snapshot {name} differs.\n--- expected\n{expected}\n--- actual\n{actual}"
+    );
+}
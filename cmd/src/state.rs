@@ -0,0 +1,56 @@
+use crate::grid::{self, mask, shape};
+use crate::i18n::{Localized, Message};
+use crate::input;
+use crate::pager::outln;
+use crate::render::print_finished;
+use solver::{Board, Brick, Placement, solve};
+use std::path::Path;
+
+// Reads the bricks someone has put on the board so far as a grid, and finishes the puzzle from
+// there. Returns false if it can't be finished.
+pub fn run(path: &Path, day: u8, month: u8, bricks: &[Brick]) -> Result<bool, String> {
+    let shown = path.display().to_string();
+    let text = input::read_to_string(path).map_err(|error| {
+        Message::CouldNotRead {
+            path: &shown,
+            error: &error,
+        }
+        .to_string()
+    })?;
+    let grids = grid::parse(&text)?;
+    let [pieces] = grids.as_slice() else {
+        return Err(Message::BoardCount {
+            path: &shown,
+            boards: grids.len(),
+        }
+        .to_string());
+    };
+    for (&letter, cells) in pieces {
+        if mask(cells).count_ones() as usize != cells.len() {
+            return Err(Message::OutsideBoard(letter).to_string());
+        }
+        if !shape(cells).is_some_and(|shape| bricks.iter().any(|brick| brick.matches(shape))) {
+            return Err(Message::NotABrick(&letter.to_string()).to_string());
+        }
+    }
+    let masks: Vec<u64> = pieces.values().map(mask).collect();
+    let placed =
+        Placement::assign(&masks, bricks).ok_or_else(|| Message::TooManyOfBrick.to_string())?;
+    let board = Board::for_date(day, month)
+        .and_then(|board| board.with_placements(&placed))
+        .map_err(|error| Localized(&error).to_string())?;
+    outln!("{}", Message::Solving { day, month });
+    let Some(solution) = solve(board, bricks).next() else {
+        outln!("{}", Message::DeadEnd);
+        return Ok(false);
+    };
+    outln!("{}", Message::Finished);
+    // The solution has the bricks that were on the board too.
+    let finished: Vec<u64> = solution
+        .placed_bricks
+        .into_iter()
+        .filter(|placement| !masks.contains(placement))
+        .collect();
+    print_finished(&masks, &finished, bricks);
+    Ok(true)
+}
@@ -0,0 +1,70 @@
+use crate::i18n::{Message, month_labels};
+use crate::pager::outln;
+use crate::render::{print_panels, shape_panel};
+use solver::{Board, Brick, count_solutions};
+
+// Swaps one brick of the standard set for another shape of the same size and shows how the
+// number of solutions changes for each date.
+pub fn run(brick_number: usize, shape: &str) -> Result<(), String> {
+    let standard = Brick::all_bricks();
+    let brick_index = brick_number
+        .checked_sub(1)
+        .filter(|index| *index < standard.len())
+        .ok_or_else(|| {
+            Message::InvalidBrick {
+                brick: brick_number,
+                count: standard.len(),
+            }
+            .to_string()
+        })?;
+    let rows: Vec<&str> = shape.split('/').collect();
+    let brick = Brick::from_ascii(&rows).map_err(|error| error.to_string())?;
+    let (area, new_area) = (
+        standard[brick_index].shape().count_ones(),
+        brick.shape().count_ones(),
+    );
+    if new_area != area {
+        return Err(Message::ShapeSizeDiffers {
+            brick: brick_number,
+            area,
+            new_area,
+        }
+        .to_string());
+    }
+    let mut swapped = standard.to_vec();
+    swapped[brick_index] = brick;
+
+    outln!("{}", Message::Swapping(brick_number));
+    print_panels(&[
+        shape_panel(standard[brick_index].shape(), brick_number as u8),
+        shape_panel(swapped[brick_index].shape(), brick_number as u8),
+    ]);
+    let (mut broken, mut richer, mut poorer) = (vec![], 0, 0);
+    outln!("\n{}", Message::SwapHeader);
+    for month in 1..=12 {
+        for day in 1..=31 {
+            let board = Board::for_date(day, month).unwrap();
+            let before = count_solutions(board.clone(), &standard);
+            let after = count_solutions(board, &swapped);
+            if before != after {
+                let name = month_labels()[usize::from(month) - 1];
+                outln!("{name} {day:<2}  {before:>7} {after:>6}");
+            }
+            match after {
+                0 => broken.push((day, month)),
+                _ if after > before => richer += 1,
+                _ if after < before => poorer += 1,
+                _ => {}
+            }
+        }
+    }
+    outln!(
+        "\n{}",
+        Message::SwapSummary {
+            broken: broken.len(),
+            richer,
+            poorer
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use crate::i18n::Message;
+use crate::pager::outln;
+use crate::render::{print_board, print_with_tray};
+use chrono::{Datelike, Days, NaiveDate};
+use solver::{
+    Board, Brick, Hint, SearchProfile, SolutionBrowser, count_solutions, hints_with_profile,
+};
+use std::io::{self, IsTerminal, Read};
+use std::process::{Command, Stdio};
+
+const CTRL_C: u8 = 3;
+
+enum Key {
+    Left,
+    Right,
+    Up,
+    Down,
+    Char(u8),
+}
+
+// The terminal without line buffering and echo, put back the way it was when dropped. Output
+// processing is left alone, so new lines still start at the left.
+struct RawInput {
+    saved: String,
+}
+
+impl RawInput {
+    fn enable() -> io::Result<RawInput> {
+        let saved = stty(&["-g"])?;
+        // Ctrl-C comes in as a key, so quitting with it puts the terminal back too.
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Ok(RawInput {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawInput {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn read_key(input: &mut impl Read) -> Option<Key> {
+    let mut byte = [0];
+    input.read_exact(&mut byte).ok()?;
+    if byte[0] != 0x1b {
+        return Some(Key::Char(byte[0]));
+    }
+    // Arrow keys come as escape, [ and a letter.
+    let mut sequence = [0; 2];
+    input.read_exact(&mut sequence).ok()?;
+    Some(match sequence {
+        [b'[', b'A'] => Key::Up,
+        [b'[', b'B'] => Key::Down,
+        [b'[', b'C'] => Key::Right,
+        [b'[', b'D'] => Key::Left,
+        _ => Key::Char(0x1b),
+    })
+}
+
+// The solutions of one date, browsed one at a time, or its hints when they are toggled on.
+struct View<'a> {
+    date: NaiveDate,
+    bricks: &'a [Brick],
+    browser: Option<SolutionBrowser<'a>>,
+    total: usize,
+    hints: Option<Vec<Hint>>,
+    show_hints: bool,
+    hint: usize,
+}
+
+impl<'a> View<'a> {
+    fn new(date: NaiveDate, bricks: &'a [Brick]) -> View<'a> {
+        let board = Board::for_date(date.day() as u8, date.month() as u8).ok();
+        let mut browser = board
+            .clone()
+            .map(|board| SolutionBrowser::new(board, bricks));
+        if let Some(browser) = &mut browser {
+            browser.forward();
+        }
+        View {
+            date,
+            bricks,
+            total: board.map_or(0, |board| count_solutions(board, bricks)),
+            browser,
+            hints: None,
+            show_hints: false,
+            hint: 0,
+        }
+    }
+
+    fn hints(&mut self) -> &[Hint] {
+        let (date, bricks) = (self.date, self.bricks);
+        self.hints.get_or_insert_with(|| {
+            match Board::for_date(date.day() as u8, date.month() as u8) {
+                Ok(board) => hints_with_profile(board, bricks, SearchProfile::Interactive),
+                Err(_) => vec![],
+            }
+        })
+    }
+
+    fn step(&mut self, forward: bool) {
+        if self.show_hints {
+            let count = self.hints().len();
+            self.hint = match forward {
+                true => (self.hint + 1).min(count.saturating_sub(1)),
+                false => self.hint.saturating_sub(1),
+            };
+        } else if let Some(browser) = &mut self.browser {
+            match forward {
+                true => browser.forward(),
+                false => browser.back(),
+            };
+        }
+    }
+
+    fn draw(&mut self) {
+        outln!("\x1b[H\x1b[2J");
+        if self.show_hints {
+            let (date, hint, bricks) = (self.date, self.hint, self.bricks);
+            let hints = self.hints();
+            match hints.get(hint) {
+                Some(found) => {
+                    let message = Message::Hint {
+                        number: hint + 1,
+                        solutions: found.solutions,
+                    };
+                    outln!("{}: {message}", date.format("%B %-d"));
+                    print_with_tray(&[found.brick], bricks);
+                }
+                None => outln!("{}", Message::NoHints),
+            }
+        } else {
+            let total = self.total;
+            let solution = self.browser.as_mut().and_then(|browser| {
+                let position = browser.position()?;
+                Some((position, browser.jump(position)?.clone()))
+            });
+            match solution {
+                Some((position, solution)) => {
+                    let message = Message::BrowseSolution {
+                        date: self.date,
+                        number: position + 1,
+                        total,
+                    };
+                    outln!("{message}");
+                    print_board(&solution);
+                }
+                None => outln!("{}", Message::NoSolutionFor(self.date)),
+            }
+        }
+        outln!("\n{}", Message::BrowseKeys);
+    }
+}
+
+// Pages through the solutions of a date with the arrow keys, switching dates and hints on the fly.
+pub fn run(date: NaiveDate, bricks: &[Brick]) -> io::Result<()> {
+    if !io::stdin().is_terminal() {
+        return Err(io::Error::other("--tui needs a terminal"));
+    }
+    let raw = RawInput::enable()?;
+    let mut input = io::stdin().lock();
+    let mut view = View::new(date, bricks);
+    loop {
+        view.draw();
+        let Some(key) = read_key(&mut input) else {
+            break;
+        };
+        match key {
+            Key::Right => view.step(true),
+            Key::Left => view.step(false),
+            Key::Down => view = View::new(view.date + Days::new(1), bricks),
+            Key::Up => view = View::new(view.date - Days::new(1), bricks),
+            Key::Char(b'h') => view.show_hints = !view.show_hints,
+            Key::Char(b'q' | CTRL_C) => break,
+            Key::Char(_) => {}
+        }
+    }
+    drop(raw);
+    Ok(())
+}
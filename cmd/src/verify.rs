@@ -0,0 +1,385 @@
+use crate::grid::{self, Pieces, mask, shape};
+use crate::hooks::exit;
+use crate::i18n::{Message, month_day};
+use crate::input;
+use crate::pager::outln;
+use crate::render::is_board_cell;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use solver::{Board, Brick, all_dates};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// The parts of a `--format json` document that say where the bricks go.
+#[derive(Deserialize)]
+struct JsonDocument {
+    date: JsonDate,
+    solutions: Vec<JsonSolution>,
+}
+
+#[derive(Deserialize)]
+struct JsonDate {
+    month: u8,
+    day: u8,
+}
+
+#[derive(Deserialize)]
+struct JsonSolution {
+    placements: Vec<JsonPlacement>,
+}
+
+#[derive(Deserialize)]
+struct JsonPlacement {
+    brick: usize,
+    cells: Vec<[u32; 2]>,
+}
+
+// A solution to check: the cells of each piece, named by its letter in a grid or its brick
+// number in JSON, which also says which brick it is meant to be.
+struct Proposal {
+    date: Option<(u8, u8)>,
+    pieces: Vec<Piece>,
+}
+
+struct Piece {
+    name: String,
+    brick: Option<usize>,
+    cells: Vec<(u32, u32)>,
+}
+
+// Checks each solution in the file, printing what is wrong with it. Exits with 1 if any of them
+// isn't a solution of the date with the bricks.
+pub fn run(path: &Path, date: Option<(u8, u8)>, bricks: &[Brick]) {
+    let text = input::read_to_string(path).unwrap_or_else(|error| {
+        fail(Message::CouldNotRead {
+            path: &path.display().to_string(),
+            error: &error,
+        })
+    });
+    let proposals =
+        parse(&text).unwrap_or_else(|error| fail(format!("{}: {error}", path.display())));
+    if proposals.is_empty() {
+        fail(Message::NoSolutionIn(&path.display().to_string()));
+    }
+    let mut invalid = 0;
+    for (index, proposal) in proposals.iter().enumerate() {
+        let Some((day, month)) = date.or(proposal.date).or_else(|| uncovered_date(proposal)) else {
+            outln!("{}", Message::UnknownDate(index + 1));
+            invalid += 1;
+            continue;
+        };
+        let name = NaiveDate::from_ymd_opt(2024, month.into(), day.into())
+            .map_or(format!("{day}/{month}"), month_day);
+        let problems = check(proposal, day, month, bricks);
+        let verified = Message::Verified {
+            number: index + 1,
+            date: &name,
+            valid: problems.is_empty(),
+        };
+        outln!("{verified}");
+        if problems.is_empty() {
+            continue;
+        }
+        invalid += 1;
+        for problem in problems {
+            outln!("  {problem}");
+        }
+    }
+    if invalid > 0 {
+        exit(1);
+    }
+}
+
+fn parse(text: &str) -> Result<Vec<Proposal>, String> {
+    if !text.trim_start().starts_with('{') {
+        return Ok(grid::parse(text)?.into_iter().map(grid_proposal).collect());
+    }
+    let document: JsonDocument = serde_json::from_str(text).map_err(|error| error.to_string())?;
+    let date = Some((document.date.day, document.date.month));
+    Ok(document
+        .solutions
+        .into_iter()
+        .map(|solution| Proposal {
+            date,
+            pieces: solution
+                .placements
+                .into_iter()
+                .map(|placement| Piece {
+                    name: Message::BrickName(placement.brick).to_string(),
+                    brick: Some(placement.brick),
+                    cells: placement
+                        .cells
+                        .iter()
+                        .map(|&[row, column]| (row, column))
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+fn grid_proposal(pieces: Pieces) -> Proposal {
+    Proposal {
+        date: None,
+        pieces: pieces
+            .into_iter()
+            .map(|(letter, cells)| Piece {
+                name: letter.to_string(),
+                brick: None,
+                cells,
+            })
+            .collect(),
+    }
+}
+
+// The only date whose month and day are both left uncovered, if there is just one.
+fn uncovered_date(proposal: &Proposal) -> Option<(u8, u8)> {
+    let covered = mask(proposal.pieces.iter().flat_map(|piece| &piece.cells));
+    let mut dates = all_dates().filter(|&(month, day)| {
+        let board = Board::for_date(day, month).unwrap();
+        (0..64u8).all(|index| {
+            board.is_free(index) || !is_board_cell(index.into()) || covered & 1 << 63 >> index == 0
+        })
+    });
+    match (dates.next(), dates.next()) {
+        (Some((month, day)), None) => Some((day, month)),
+        _ => None,
+    }
+}
+
+fn check(proposal: &Proposal, day: u8, month: u8, bricks: &[Brick]) -> Vec<String> {
+    let Ok(board) = Board::for_date(day, month) else {
+        return vec![Message::NoDayInMonth { day, month }.to_string()];
+    };
+    let mut problems = vec![];
+    let mut covered: BTreeMap<(u32, u32), Vec<&str>> = BTreeMap::new();
+    for piece in &proposal.pieces {
+        for &cell in &piece.cells {
+            covered.entry(cell).or_default().push(&piece.name);
+        }
+    }
+    for (&cell, names) in &covered {
+        let overlap = names.len() > 1;
+        let index = cell.0.min(8) * 8 + cell.1;
+        if cell.1 >= 8 || !is_board_cell(index as usize) {
+            problems.push(Message::CoversOutside { names, cell }.to_string());
+        } else if !board.is_free(index as u8) {
+            problems.push(Message::CoversDate { names, cell }.to_string());
+        }
+        if overlap {
+            problems.push(Message::Overlap { names, cell }.to_string());
+        }
+    }
+    for index in 0..64u32 {
+        let cell = (index / 8, index % 8);
+        if board.is_free(index as u8) && !covered.contains_key(&cell) {
+            problems.push(Message::Uncovered(cell).to_string());
+        }
+    }
+    let mut used = vec![false; bricks.len()];
+    for piece in &proposal.pieces {
+        let Some(shape) = shape(&piece.cells) else {
+            problems.push(Message::NotABrick(&piece.name).to_string());
+            continue;
+        };
+        let brick = match piece.brick {
+            Some(number) if !(1..=bricks.len()).contains(&number) => {
+                problems.push(Message::NoBrick(number).to_string());
+                continue;
+            }
+            Some(number) if !bricks[number - 1].matches(shape) => {
+                problems.push(Message::WrongShape(&piece.name).to_string());
+                continue;
+            }
+            Some(number) => number - 1,
+            None => {
+                let matching: Vec<usize> = (0..bricks.len())
+                    .filter(|&brick| bricks[brick].matches(shape))
+                    .collect();
+                let Some(&first) = matching.first() else {
+                    problems.push(Message::NotABrick(&piece.name).to_string());
+                    continue;
+                };
+                matching
+                    .into_iter()
+                    .find(|&brick| !used[brick])
+                    .unwrap_or(first)
+            }
+        };
+        if used[brick] {
+            problems.push(
+                Message::UsedTwice {
+                    name: &piece.name,
+                    brick: brick + 1,
+                }
+                .to_string(),
+            );
+        }
+        used[brick] = true;
+    }
+    problems
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", Message::Error(&message));
+    exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use solver::{SolvedBoard, solve};
+
+    fn first_solution(day: u8, month: u8) -> SolvedBoard {
+        solve(Board::for_date(day, month).unwrap(), &Brick::all_bricks())
+            .next()
+            .unwrap()
+    }
+
+    // The solution the way --ascii prints it, a digit per brick cell.
+    fn grid_text(solution: &SolvedBoard) -> String {
+        solution.to_grid()[..7]
+            .iter()
+            .map(|row| {
+                let row: String = row
+                    .iter()
+                    .map(|&brick| match brick {
+                        0 => '.',
+                        brick => char::from(b'0' + brick),
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+
+    fn placements(solution: &SolvedBoard) -> Vec<(usize, Vec<[u32; 2]>)> {
+        solution
+            .bricks
+            .iter()
+            .zip(&solution.placed_bricks)
+            .map(|(&brick, &placement)| {
+                let cells = (0..64)
+                    .filter(|index| placement & 1 << 63 >> index != 0)
+                    .map(|index| [index / 8, index % 8])
+                    .collect();
+                (brick + 1, cells)
+            })
+            .collect()
+    }
+
+    fn json_text(day: u8, month: u8, placements: &[(usize, Vec<[u32; 2]>)]) -> String {
+        let placements: Vec<_> = placements
+            .iter()
+            .map(|(brick, cells)| json!({ "brick": brick, "cells": cells }))
+            .collect();
+        json!({
+            "date": { "day": day, "month": month },
+            "solutions": [{ "placements": placements }],
+        })
+        .to_string()
+    }
+
+    fn problems(text: &str, day: u8, month: u8) -> Vec<String> {
+        let proposals = parse(text).unwrap();
+        assert_eq!(proposals.len(), 1);
+        check(&proposals[0], day, month, &Brick::all_bricks())
+    }
+
+    #[test]
+    fn accepts_solutions() {
+        for (day, month) in [(22, 9), (1, 1), (31, 12)] {
+            let solution = first_solution(day, month);
+            let grid = parse(&grid_text(&solution)).unwrap();
+            assert_eq!(uncovered_date(&grid[0]), Some((day, month)));
+            assert_eq!(
+                problems(&grid_text(&solution), day, month),
+                Vec::<String>::new()
+            );
+            let json = json_text(day, month, &placements(&solution));
+            assert_eq!(parse(&json).unwrap()[0].date, Some((day, month)));
+            assert_eq!(problems(&json, day, month), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn rejects_another_date() {
+        let problems = problems(&grid_text(&first_solution(22, 9)), 23, 9);
+        // The 22nd starts the sixth row, and the 23rd is next to it.
+        let name = first_solution(22, 9).to_grid()[5][1].to_string();
+        let names = [name.as_str()];
+        let expected = [
+            Message::CoversDate {
+                names: &names,
+                cell: (5, 1),
+            }
+            .to_string(),
+            Message::Uncovered((5, 0)).to_string(),
+        ];
+        assert_eq!(problems, expected);
+    }
+
+    #[test]
+    fn rejects_gaps_and_pieces_that_are_no_bricks() {
+        let solution = first_solution(22, 9);
+        let mut text = grid_text(&solution);
+        let gap = text.find('1').unwrap();
+        text.replace_range(gap..gap + 1, ".");
+        let cell = ((gap / 8) as u32, (gap % 8) as u32);
+        let expected = [
+            Message::Uncovered(cell).to_string(),
+            Message::NotABrick("1").to_string(),
+        ];
+        assert_eq!(problems(&text, 22, 9), expected);
+    }
+
+    #[test]
+    fn rejects_wrong_bricks() {
+        let solution = first_solution(22, 9);
+        let mut wrong = placements(&solution);
+        let first = wrong.iter().position(|(brick, _)| *brick == 1).unwrap();
+        let second = wrong.iter().position(|(brick, _)| *brick == 2).unwrap();
+        wrong[first].0 = 2;
+        wrong[second].0 = 9;
+        let expected = [
+            Message::WrongShape(&Message::BrickName(2).to_string()).to_string(),
+            Message::NoBrick(9).to_string(),
+        ];
+        let mut problems = problems(&json_text(22, 9, &wrong), 22, 9);
+        problems.sort();
+        let mut expected = expected.to_vec();
+        expected.sort();
+        assert_eq!(problems, expected);
+    }
+
+    #[test]
+    fn rejects_overlaps_and_bricks_used_twice() {
+        let solution = first_solution(22, 9);
+        let mut placements = placements(&solution);
+        let (brick, cells) = placements[0].clone();
+        // The same brick again, over the first one.
+        placements.push((brick, cells.clone()));
+        let problems = problems(&json_text(22, 9, &placements), 22, 9);
+        let name = Message::BrickName(brick).to_string();
+        let names = [name.as_str(), name.as_str()];
+        let mut expected: Vec<String> = cells
+            .iter()
+            .map(|&[row, column]| {
+                Message::Overlap {
+                    names: &names,
+                    cell: (row, column),
+                }
+                .to_string()
+            })
+            .collect();
+        expected.push(Message::UsedTwice { name: &name, brick }.to_string());
+        assert_eq!(problems, expected);
+    }
+
+    #[test]
+    fn rejects_unreadable_files() {
+        assert!(parse("{\"date\": {}}").is_err());
+        assert!(parse("123\n456\n").is_err());
+    }
+}
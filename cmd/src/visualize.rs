@@ -0,0 +1,127 @@
+use crate::contact_sheet;
+use crate::hooks::exit;
+use crate::i18n::Message;
+use crate::pager::outln;
+use crate::render::{brick_cells, brick_dot, grid_panel, paint, print_panels};
+use ansi_term::Style;
+use solver::{Board, Brick, Palette, solve_with_progress};
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+const SOLUTION_PAUSE: Duration = Duration::from_secs(1);
+
+// Shows every step of the depth first search, at most frame_rate steps per second. With a
+// capture directory the frames are written there as PNG images instead, as fast as possible.
+pub fn run(
+    board: Board,
+    bricks: &[Brick],
+    frame_rate: u32,
+    capture: Option<&Path>,
+    palette: &Palette,
+) {
+    let frame_time = Duration::from_secs(1) / frame_rate.max(1);
+    let date_board = board.clone();
+    let capture = capture.map(|directory| Capture::new(directory, palette));
+    let solutions = solve_with_progress(board, bricks, Duration::ZERO, |progress| match &capture {
+        Some(capture) => capture.write(&date_board, progress.placed_bricks, 1),
+        None => {
+            draw_frame(
+                &date_board,
+                progress.placed_bricks,
+                &Message::SearchStatus {
+                    test_count: progress.test_count,
+                    solutions: progress.solutions,
+                }
+                .to_string(),
+            );
+            thread::sleep(frame_time);
+        }
+    });
+    let mut found = 0;
+    for solution in solutions {
+        found += 1;
+        match &capture {
+            // Repeating the frame keeps the pause when the frames are played at the frame rate.
+            Some(capture) => {
+                let frames = (SOLUTION_PAUSE.as_secs_f64() * frame_rate as f64) as usize;
+                capture.write(&date_board, &solution.placed_bricks, frames.max(1));
+            }
+            None => {
+                draw_frame(
+                    &date_board,
+                    &solution.placed_bricks,
+                    &Message::SolutionFound {
+                        number: found,
+                        test_count: solution.stats.test_count,
+                    }
+                    .to_string(),
+                );
+                thread::sleep(SOLUTION_PAUSE);
+            }
+        }
+    }
+    outln!("{}", Message::SearchFinished(found));
+    if let Some(capture) = capture {
+        outln!(
+            "{}",
+            Message::FramesWritten {
+                count: capture.frames.get(),
+                path: &capture.directory.display().to_string()
+            }
+        );
+    }
+}
+
+// Writes numbered frames, frame-000001.png and on, so tools like ffmpeg can pick them up in order.
+struct Capture<'a> {
+    directory: PathBuf,
+    palette: &'a Palette,
+    frames: Cell<usize>,
+}
+
+impl<'a> Capture<'a> {
+    fn new(directory: &Path, palette: &'a Palette) -> Capture<'a> {
+        if let Err(error) = std::fs::create_dir_all(directory) {
+            fail(directory, &error);
+        }
+        Capture {
+            directory: directory.to_path_buf(),
+            palette,
+            frames: Cell::new(0),
+        }
+    }
+
+    fn write(&self, date_board: &Board, placed_bricks: &[u64], repeat: usize) {
+        let png = contact_sheet::draw_frame(date_board, placed_bricks, self.palette).to_png();
+        for _ in 0..repeat {
+            self.frames.set(self.frames.get() + 1);
+            let path = self
+                .directory
+                .join(format!("frame-{:06}.png", self.frames.get()));
+            if let Err(error) = std::fs::write(&path, &png) {
+                fail(&path, &error);
+            }
+        }
+    }
+}
+
+fn fail(path: &Path, error: &std::io::Error) -> ! {
+    let shown = path.display().to_string();
+    let message = Message::CouldNotWrite {
+        path: &shown,
+        error,
+    };
+    eprintln!("{}", Message::Error(&message));
+    exit(1);
+}
+
+fn draw_frame(date_board: &Board, placed_bricks: &[u64], status: &str) {
+    let cells = brick_cells(placed_bricks);
+    outln!("\x1b[H\x1b[2J{status}");
+    print_panels(&[grid_panel(|i| match cells[i] {
+        0 if date_board.is_free(i as u8) => paint(Style::new(), " "),
+        brick_number => brick_dot(brick_number),
+    })]);
+}
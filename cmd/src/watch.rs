@@ -0,0 +1,69 @@
+use crate::i18n::Message;
+use crate::render::{
+    ascii, brick_dot, grid_panel, paint, print_board, print_panels, solution_cells,
+};
+use ansi_term::Color;
+use chrono::{Datelike, Local, NaiveDate};
+use solver::{Board, Brick, SearchProfile, SolvedBoard, solve_with_profile};
+use std::thread;
+use std::time::Duration;
+
+pub fn run(bricks: &[Brick]) -> ! {
+    let mut shown = show_solution(Local::now().date_naive(), bricks);
+    loop {
+        sleep_until_tomorrow();
+        let today = Local::now().date_naive();
+        let solution = first_solution(today, bricks);
+        if let (Some(yesterday), Some(today_solution)) = (&shown, &solution) {
+            println!("\n{}", Message::DateChanged(today));
+            print_diff(yesterday, today_solution);
+        }
+        shown = show_solution(today, bricks);
+    }
+}
+
+fn first_solution(date: NaiveDate, bricks: &[Brick]) -> Option<SolvedBoard> {
+    let board = Board::for_date(date.day() as u8, date.month() as u8).ok()?;
+    solve_with_profile(board, bricks, SearchProfile::Interactive).next()
+}
+
+fn show_solution(date: NaiveDate, bricks: &[Brick]) -> Option<SolvedBoard> {
+    let solution = first_solution(date, bricks);
+    match &solution {
+        Some(solution) => {
+            println!("\n{}", Message::SolutionFor(date));
+            print_board(solution);
+        }
+        None => eprintln!("{}", Message::Error(&Message::NoSolutionFor(date))),
+    }
+    solution
+}
+
+// Yesterday's solution with today's date cells marked as open, yesterday's date cells marked as
+// needing cover, and the bricks that overlap today's date cells crossed out.
+fn print_diff(yesterday: &SolvedBoard, today: &SolvedBoard) {
+    let old_cells = solution_cells(yesterday);
+    let new_cells = solution_cells(today);
+    let mut broken: Vec<u8> = (0..51)
+        .filter(|&i| new_cells[i] == 0 && old_cells[i] != 0)
+        .map(|i| old_cells[i])
+        .collect();
+    // Each freed cell adds its brick, so a brick covering both date cells is in twice.
+    broken.sort_unstable();
+    broken.dedup();
+    print_panels(&[grid_panel(|i| match (old_cells[i], new_cells[i]) {
+        (_, 0) => brick_dot(0),
+        (0, _) => paint(Color::Yellow.bold(), "+"),
+        (brick, _) if broken.contains(&brick) => {
+            paint(Color::Red.bold(), if ascii() { "x" } else { "✗" })
+        }
+        (brick, _) => brick_dot(brick),
+    })]);
+    println!("{}", Message::BricksToMove(broken.len()));
+}
+
+fn sleep_until_tomorrow() {
+    let now = Local::now().naive_local();
+    let tomorrow = now.date().succ_opt().unwrap().and_hms_opt(0, 0, 1).unwrap();
+    thread::sleep((tomorrow - now).to_std().unwrap_or(Duration::from_secs(1)));
+}
@@ -0,0 +1,33 @@
+use crate::i18n::Message;
+use crate::pager::outln;
+use crate::render::print_weekday_board;
+use chrono::{Datelike, NaiveDate};
+use solver::{Board, Brick, SearchProfile, SolverError, solve_with_profile};
+use std::time::Instant;
+
+// Solves the board with weekdays, with the weekday of the date in its year uncovered too.
+pub fn run(
+    date: NaiveDate,
+    bricks: &[Brick],
+    shown: usize,
+    profile: SearchProfile,
+) -> Result<(), SolverError> {
+    let weekday = date.weekday().number_from_monday() as u8;
+    let board = Board::for_date_weekday(date.day() as u8, date.month() as u8, weekday)?;
+    outln!("{}", Message::SolvingWeekday(date));
+    let start = Instant::now();
+    let mut count = 0;
+    for solved_board in solve_with_profile(board, bricks, profile).take(shown) {
+        count += 1;
+        let message = Message::Solution {
+            number: count,
+            elapsed: start.elapsed(),
+            test_count: solved_board.stats.test_count,
+        };
+        outln!("{message}");
+        print_weekday_board(&solved_board);
+    }
+    let elapsed = start.elapsed();
+    outln!("{}", Message::SolutionCount { count, elapsed });
+    Ok(())
+}
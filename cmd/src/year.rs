@@ -0,0 +1,74 @@
+use crate::i18n::Message;
+use crate::pager::outln;
+use solver::{Brick, DateResult, solve_year};
+use std::time::Instant;
+
+// Solves every month and day and sums up the solution counts, with the dates the search took
+// longest on.
+pub fn run(slowest: usize) {
+    let start = Instant::now();
+    let results = solve_year(&Brick::all_bricks());
+    let total: usize = results.iter().map(|result| result.solutions).sum();
+    let fewest = results
+        .iter()
+        .map(|result| result.solutions)
+        .min()
+        .unwrap_or(0);
+    let most = results
+        .iter()
+        .map(|result| result.solutions)
+        .max()
+        .unwrap_or(0);
+    let dates_with = |count: usize| -> String {
+        results
+            .iter()
+            .filter(|result| result.solutions == count)
+            .map(date)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    outln!(
+        "{}",
+        Message::YearSolved {
+            dates: results.len(),
+            elapsed: start.elapsed(),
+            solutions: total
+        }
+    );
+    outln!(
+        "{}",
+        Message::FewestOfYear {
+            solutions: fewest,
+            dates: &dates_with(fewest)
+        }
+    );
+    outln!(
+        "{}",
+        Message::MostOfYear {
+            solutions: most,
+            dates: &dates_with(most)
+        }
+    );
+    outln!(
+        "{}",
+        Message::MeanOfYear(total as f64 / results.len().max(1) as f64)
+    );
+    let mut by_time: Vec<&DateResult> = results.iter().collect();
+    by_time.sort_by_key(|result| std::cmp::Reverse(result.stats.elapsed));
+    outln!("{}", Message::SlowestDates);
+    for result in by_time.iter().take(slowest) {
+        outln!(
+            "  {}",
+            Message::SlowDate {
+                date: &date(result),
+                test_count: result.stats.test_count,
+                elapsed: result.stats.elapsed,
+                solutions: result.solutions
+            }
+        );
+    }
+}
+
+fn date(result: &DateResult) -> String {
+    format!("{:02}-{:02}", result.month, result.day)
+}
@@ -0,0 +1,81 @@
+// JavaScript bindings for the solver, built with `wasm-pack build solver-wasm`. Placements are
+// given as the brick number (1-8) and the (row, column) of each cell it covers, with rows and
+// columns counted from 0 at the top left of the board:
+//
+//     { "brick": 3, "cells": [[0, 0], [0, 1], [0, 2], [1, 0], [2, 0]] }
+use serde_json::{Value, json};
+use solver::{Board, Brick};
+use wasm_bindgen::prelude::*;
+
+/// Every solution for the date, each an array of placements.
+#[wasm_bindgen]
+pub fn solve(day: u8, month: u8) -> Result<JsValue, JsError> {
+    let bricks = Brick::all_bricks();
+    let board = Board::for_date(day, month)?;
+    #[cfg(feature = "table")]
+    let solutions = solver::table_solutions(&board, &bricks)
+        .into_iter()
+        .flatten();
+    #[cfg(not(feature = "table"))]
+    let solutions = solver::solve(board, &bricks);
+    let solutions: Vec<Value> = solutions
+        .map(|solution| {
+            solution
+                .bricks
+                .iter()
+                .zip(&solution.placed_bricks)
+                .map(|(&brick, &mask)| placement_json(brick, mask))
+                .collect()
+        })
+        .collect();
+    to_js(&Value::Array(solutions))
+}
+
+/// The first `n` hints for the date, best first. Each is a placement with the number of
+/// solutions it is part of.
+#[wasm_bindgen]
+pub fn hints(day: u8, month: u8, n: usize) -> Result<JsValue, JsError> {
+    let bricks = Brick::all_bricks();
+    let board = Board::for_date(day, month)?;
+    let hints: Vec<Value> = solver::hints(board, &bricks)
+        .into_iter()
+        .filter_map(|hint| {
+            let brick = bricks.iter().position(|brick| brick.matches(hint.brick))?;
+            let mut placement = placement_json(brick, hint.brick);
+            placement["solutions"] = json!(hint.solutions);
+            Some(placement)
+        })
+        .take(n)
+        .collect();
+    to_js(&Value::Array(hints))
+}
+
+/// Every spot brick number `brick` (1-8) fits on the free cells of the date, without solving.
+#[wasm_bindgen]
+pub fn placements(day: u8, month: u8, brick: usize) -> Result<JsValue, JsError> {
+    let bricks = Brick::all_bricks();
+    let board = Board::for_date(day, month)?;
+    let Some(index) = brick.checked_sub(1).filter(|&index| index < bricks.len()) else {
+        return Err(JsError::new(&format!(
+            "Invalid brick {brick}. Valid bricks: 1-{}",
+            bricks.len()
+        )));
+    };
+    let placements: Vec<Value> = board
+        .placements_of(index, &bricks[index])
+        .map(|placement| placement_json(placement.brick, placement.mask))
+        .collect();
+    to_js(&Value::Array(placements))
+}
+
+fn placement_json(brick: usize, mask: u64) -> Value {
+    let cells: Vec<[u32; 2]> = (0..64)
+        .filter(|index| mask & 1 << 63 >> index != 0)
+        .map(|index| [index / 8, index % 8])
+        .collect();
+    json!({ "brick": brick + 1, "cells": cells })
+}
+
+fn to_js(value: &Value) -> Result<JsValue, JsError> {
+    js_sys::JSON::parse(&value.to_string()).map_err(|_| JsError::new("Invalid JSON"))
+}
@@ -0,0 +1,26 @@
+use solver::{Board, Brick, SolvedBoard, dlx, solve};
+use std::time::{Duration, Instant};
+
+const DATES: [(u8, u8); 4] = [(1, 1), (29, 7), (22, 9), (31, 12)];
+
+fn time_all(name: &str, search: impl Fn(Board) -> Vec<SolvedBoard>) {
+    let mut total = Duration::ZERO;
+    let mut solutions = 0;
+    let mut tests = 0u64;
+    for (day, month) in DATES {
+        let start = Instant::now();
+        let found = search(Board::for_date(day, month).unwrap());
+        total += start.elapsed();
+        solutions += found.len();
+        tests += found.last().map_or(0, |solution| solution.stats.test_count);
+    }
+    println!("{name}: all {solutions} solutions for {DATES:?} in {total:?} ({tests} tests)");
+}
+
+fn main() {
+    let bricks = Brick::all_bricks();
+    time_all("Bitboard DFS", |board| solve(board, &bricks).collect());
+    time_all("Dancing links", |board| {
+        dlx::solve(board, &bricks).collect()
+    });
+}
@@ -0,0 +1,34 @@
+use solver::{Board, Brick, SearchProfile, solve_with_profile};
+use std::time::{Duration, Instant};
+
+const DAYS_IN_MONTH: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+fn main() {
+    let bricks = Brick::all_bricks();
+    for profile in [
+        SearchProfile::Exhaustive,
+        SearchProfile::Interactive,
+        SearchProfile::FailFirst,
+    ] {
+        let mut total = Duration::ZERO;
+        let mut slowest = (Duration::ZERO, 0, 0);
+        let mut total_tests = 0u64;
+        for (month, days) in (1..=12).zip(DAYS_IN_MONTH) {
+            for day in 1..=days {
+                let board = Board::for_date(day, month).unwrap();
+                let start = Instant::now();
+                let first = solve_with_profile(board, &bricks, profile).next();
+                let elapsed = start.elapsed();
+                total_tests += first.map_or(0, |solution| solution.stats.test_count);
+                total += elapsed;
+                if elapsed > slowest.0 {
+                    slowest = (elapsed, day, month);
+                }
+            }
+        }
+        println!(
+            "{profile:?}: first solution for all dates in {total:?} ({total_tests} tests), slowest {}/{} in {:?}",
+            slowest.1, slowest.2, slowest.0
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// With the `table` feature, solves every date of the year and writes the solutions to
+// $OUT_DIR/solutions.bin for `table.rs` to embed. The search here is a plain one of its own, since
+// a build script can't use the crate it builds, so the bricks are repeated from
+// `Brick::all_bricks` and must stay in the same order.
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const BRICKS: [&[&str]; 8] = [
+    &[".XX", ".X.", "XX."],
+    &["XXX", "XXX"],
+    &["XXX", "X..", "X.."],
+    &["XXX", "X.X"],
+    &["...X", "XXXX"],
+    &["XXXX", ".X.."],
+    &["XXX", "XX."],
+    &["XXX.", "..XX"],
+];
+const EMPTY_BOARD: u64 =
+    0b00000011_00000011_00000001_00000001_00000001_00000001_00011111_11111111u64;
+const DAYS_IN_MONTH: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_TABLE").is_none() {
+        return;
+    }
+    let variants: Vec<Vec<u64>> = BRICKS.iter().map(|rows| variants(rows)).collect();
+    let mut offsets = vec![0u32];
+    let mut solutions = vec![];
+    for month in 1..=12u8 {
+        for day in 1..=DAYS_IN_MONTH[month as usize - 1] {
+            let board = date_board(day, month);
+            let mut placed = [0; 8];
+            search(board, 0, &variants, &mut placed, &mut |placed| {
+                solutions.extend(encode(board, placed));
+            });
+            offsets.push((solutions.len() / 16) as u32);
+        }
+    }
+    let mut table: Vec<u8> = offsets
+        .iter()
+        .flat_map(|offset| offset.to_le_bytes())
+        .collect();
+    table.extend(solutions);
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.bin"), table).unwrap();
+}
+
+fn cell(index: u32) -> u64 {
+    1 << 63 >> index
+}
+
+fn date_board(day: u8, month: u8) -> u64 {
+    let month_cell = if month <= 6 { month - 1 } else { month + 1 };
+    let day_cell = day + 15 + (day - 1) / 7;
+    EMPTY_BOARD | cell(month_cell.into()) | cell(day_cell.into())
+}
+
+// Every rotation and reflection of the shape, moved to the top left corner.
+fn variants(rows: &[&str]) -> Vec<u64> {
+    let mut cells: Vec<(i32, i32)> = vec![];
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            if c == 'X' {
+                cells.push((y as i32, x as i32));
+            }
+        }
+    }
+    let mut patterns = vec![];
+    for flip in [false, true] {
+        for turns in 0..4 {
+            let moved: Vec<(i32, i32)> = cells
+                .iter()
+                .map(|&(y, x)| {
+                    let (mut y, mut x) = if flip { (y, -x) } else { (y, x) };
+                    for _ in 0..turns {
+                        (y, x) = (x, -y);
+                    }
+                    (y, x)
+                })
+                .collect();
+            let top = moved.iter().map(|&(y, _)| y).min().unwrap();
+            let left = moved.iter().map(|&(_, x)| x).min().unwrap();
+            let pattern = moved.iter().fold(0, |pattern, &(y, x)| {
+                pattern | cell(((y - top) * 8 + x - left) as u32)
+            });
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+    }
+    patterns
+}
+
+// Fills the first free cell with every unused brick that fits there, calling `found` with the
+// placement of each brick once the board is full.
+fn search(
+    board: u64,
+    used: u8,
+    variants: &[Vec<u64>],
+    placed: &mut [u64; 8],
+    found: &mut impl FnMut(&[u64; 8]),
+) {
+    if used == 0xff {
+        found(placed);
+        return;
+    }
+    let free = (!board).leading_zeros();
+    for (brick, brick_variants) in variants.iter().enumerate() {
+        if used & 1 << brick != 0 {
+            continue;
+        }
+        for &pattern in brick_variants {
+            // The pattern's first cell goes on the free cell. Cells pushed past the end of a row
+            // land on the blocked last column, so those placements don't fit.
+            let first = pattern.leading_zeros();
+            if free % 8 < first {
+                continue;
+            }
+            let placement = pattern >> (free - first);
+            if board & placement == 0 {
+                placed[brick] = placement;
+                search(
+                    board | placement,
+                    used | 1 << brick,
+                    variants,
+                    placed,
+                    found,
+                );
+            }
+        }
+    }
+}
+
+// The brick index of every free cell of the date board, three bits each from the lowest bit up.
+fn encode(board: u64, placed: &[u64; 8]) -> [u8; 16] {
+    let mut bits = 0u128;
+    let mut shift = 0;
+    for index in (0..64).filter(|&index| board & cell(index) == 0) {
+        let brick = placed
+            .iter()
+            .position(|&mask| mask & cell(index) != 0)
+            .unwrap();
+        bits |= (brick as u128) << shift;
+        shift += 3;
+    }
+    bits.to_le_bytes()
+}
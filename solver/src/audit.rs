@@ -0,0 +1,148 @@
+use crate::Brick;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::shape::cell_orientations;
+
+/// The standard bricks as (row, column) cell lists, written down from the physical pieces.
+pub const STANDARD_BRICK_CELLS: [&[(u32, u32)]; 8] = [
+    &[(0, 1), (0, 2), (1, 1), (2, 0), (2, 1)],
+    &[(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)],
+    &[(0, 0), (0, 1), (0, 2), (1, 0), (2, 0)],
+    &[(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)],
+    &[(0, 3), (1, 0), (1, 1), (1, 2), (1, 3)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3), (1, 1)],
+    &[(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)],
+    &[(0, 0), (0, 1), (0, 2), (1, 2), (1, 3)],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditIssue {
+    /// An orientation of the cells the brick has no variant for.
+    MissingOrientation(u64),
+    /// A variant that isn't an orientation of the cells, in the top left corner.
+    UnexpectedVariant(u64),
+    /// A variant that is an orientation of the cells, but not moved to the top left corner.
+    Shifted(u64),
+    DuplicateVariant(u64),
+}
+
+/// Checks the variants of a brick against every rotation and reflection of its cells, worked out
+/// on cell coordinates rather than bit patterns.
+pub fn audit_brick(brick: &Brick, cells: &[(u32, u32)]) -> Vec<AuditIssue> {
+    let expected: Vec<u64> = cell_orientations(cells)
+        .iter()
+        .map(|cells| {
+            cells.iter().fold(0, |pattern, (row, column)| {
+                pattern | 1 << 63 >> (row * 8 + column)
+            })
+        })
+        .collect();
+    let variants: Vec<u64> = brick
+        .brick_variants
+        .iter()
+        .map(|variant| variant.bit_pattern)
+        .collect();
+    let mut issues = vec![];
+    for (i, &variant) in variants.iter().enumerate() {
+        if variants[..i].contains(&variant) {
+            issues.push(AuditIssue::DuplicateVariant(variant));
+        } else if expected.contains(&variant) {
+            continue;
+        } else if expected
+            .iter()
+            .any(|expected| is_shifted(*expected, variant))
+        {
+            issues.push(AuditIssue::Shifted(variant));
+        } else {
+            issues.push(AuditIssue::UnexpectedVariant(variant));
+        }
+    }
+    for expected in expected {
+        let found = variants
+            .iter()
+            .any(|&variant| variant == expected || is_shifted(expected, variant));
+        if !found {
+            issues.push(AuditIssue::MissingOrientation(expected));
+        }
+    }
+    issues
+}
+
+// Whether the placement is the pattern moved right and down, without wrapping around a row end.
+fn is_shifted(pattern: u64, placement: u64) -> bool {
+    let columns = pattern
+        .to_be_bytes()
+        .iter()
+        .fold(0, |columns, row| columns | row);
+    let width = 8 - columns.trailing_zeros();
+    (1..64).any(|shift| {
+        shift % 8 + width <= 7
+            && pattern >> shift == placement
+            && placement.count_ones() == pattern.count_ones()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_bricks_pass() {
+        // The variants of the standard bricks are written out by hand, not generated from the cells.
+        for (brick, cells) in Brick::all_bricks().iter().zip(STANDARD_BRICK_CELLS) {
+            assert_eq!(audit_brick(brick, cells), []);
+        }
+    }
+
+    #[test]
+    fn standard_bricks_have_their_known_orientations() {
+        // Counted on the physical pieces: the rectangle has 2, the symmetric ones 4, the rest 8.
+        let counts = STANDARD_BRICK_CELLS.map(|cells| cell_orientations(cells).len());
+        assert_eq!(counts, [4, 2, 4, 4, 8, 8, 8, 8]);
+        let variants: Vec<usize> = Brick::all_bricks()
+            .iter()
+            .map(|brick| brick.brick_variants.len())
+            .collect();
+        assert_eq!(variants, counts);
+        // The four corners the V can point into, drawn row by row.
+        let mut v: Vec<u64> = cell_orientations(STANDARD_BRICK_CELLS[2])
+            .iter()
+            .map(|cells| {
+                cells.iter().fold(0, |pattern, (row, column)| {
+                    pattern | 1 << 63 >> (row * 8 + column)
+                })
+            })
+            .collect();
+        v.sort_unstable();
+        assert_eq!(
+            v,
+            [
+                0x2020_E000_0000_0000,
+                0x8080_E000_0000_0000,
+                0xE020_2000_0000_0000,
+                0xE080_8000_0000_0000,
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_issues() {
+        let cells = STANDARD_BRICK_CELLS[2];
+        let mut brick = Brick::all_bricks()[2].clone();
+        let shifted = brick.brick_variants[1].bit_pattern >> 1;
+        brick.brick_variants[1].bit_pattern = shifted;
+        brick.brick_variants[3].bit_pattern = brick.brick_variants[0].bit_pattern;
+        let issues = audit_brick(&brick, cells);
+        assert!(issues.contains(&AuditIssue::Shifted(shifted)));
+        assert!(issues.contains(&AuditIssue::DuplicateVariant(
+            brick.brick_variants[0].bit_pattern
+        )));
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|issue| matches!(issue, AuditIssue::MissingOrientation(_)))
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, SearchStats, SolveIterator};
+use core::time::Duration;
+
+const DAYS_IN_MONTH: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// The number of solutions of a date and what the search took to find them all.
+#[derive(Debug, Clone, Copy)]
+pub struct DateResult {
+    pub day: u8,
+    pub month: u8,
+    pub solutions: usize,
+    pub stats: SearchStats,
+}
+
+/// The 366 (month, day) pairs of a leap year, in calendar order.
+pub fn all_dates() -> impl Iterator<Item = (u8, u8)> {
+    (1..=12u8)
+        .flat_map(|month| (1..=DAYS_IN_MONTH[month as usize - 1]).map(move |day| (month, day)))
+}
+
+/// Solves each (month, day) with the same bricks. Dates no year has, like February 30, are left
+/// out.
+pub fn solve_dates(dates: &[(u8, u8)], bricks: &[Brick]) -> Vec<DateResult> {
+    dates
+        .iter()
+        .filter_map(|&(month, day)| solve_date(month, day, bricks, usize::MAX))
+        .collect()
+}
+
+/// The dates with fewer than `below` solutions, with what the search took, in the order given.
+/// A `below` of 2 finds the dates with a unique solution. Dates with no solution are included,
+/// and each search stops once a date has too many.
+pub fn dates_with_few_solutions(
+    dates: &[(u8, u8)],
+    below: usize,
+    bricks: &[Brick],
+) -> Vec<DateResult> {
+    dates
+        .iter()
+        .filter_map(|&(month, day)| solve_date(month, day, bricks, below))
+        .filter(|result| result.solutions < below)
+        .collect()
+}
+
+// Counts the solutions of the date, stopping at the limit.
+fn solve_date(month: u8, day: u8, bricks: &[Brick], limit: usize) -> Option<DateResult> {
+    if day > *DAYS_IN_MONTH.get(usize::from(month).checked_sub(1)?)? {
+        return None;
+    }
+    let board = Board::for_date(day, month).ok()?;
+    let mut search = SolveIterator::new(board, bricks);
+    let solutions = search.by_ref().take(limit).count();
+    let elapsed = search
+        .started
+        .map_or(Duration::ZERO, |started| started.elapsed());
+    Some(DateResult {
+        day,
+        month,
+        solutions,
+        stats: SearchStats {
+            elapsed,
+            ..search.stats
+        },
+    })
+}
+
+/// [`solve_dates`] for every date of the year, February 29 included.
+pub fn solve_year(bricks: &[Brick]) -> Vec<DateResult> {
+    let dates: Vec<(u8, u8)> = all_dates().collect();
+    solve_dates(&dates, bricks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_solutions;
+
+    #[test]
+    fn batch_matches_single_dates() {
+        assert_eq!(all_dates().count(), 366);
+        assert_eq!(all_dates().nth(59), Some((2, 29)));
+        let bricks = Brick::all_bricks();
+        let results = solve_dates(&[(9, 22), (2, 30), (12, 31)], &bricks);
+        assert_eq!(results.len(), 2);
+        let board = Board::for_date(31, 12).unwrap();
+        assert_eq!(results[1].solutions, count_solutions(board, &bricks));
+        assert!(results[1].stats.test_count > 0);
+    }
+
+    #[test]
+    fn few_solutions_stop_early() {
+        let bricks = Brick::all_bricks();
+        let dates: Vec<(u8, u8)> = all_dates().filter(|&(month, _)| month == 10).collect();
+        let all = solve_dates(&dates, &bricks);
+        let fewest = all.iter().map(|result| result.solutions).min().unwrap();
+        assert!(dates_with_few_solutions(&dates, fewest, &bricks).is_empty());
+        let few = dates_with_few_solutions(&dates, fewest + 1, &bricks);
+        assert!(!few.is_empty());
+        for result in &few {
+            let full = all
+                .iter()
+                .find(|full| (full.month, full.day) == (result.month, result.day))
+                .unwrap();
+            assert_eq!(result.solutions, full.solutions);
+            assert_eq!(result.stats.test_count, full.stats.test_count);
+        }
+    }
+}
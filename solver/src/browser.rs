@@ -0,0 +1,144 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, SearchStats, SolveIterator, SolvedBoard};
+
+const CHUNK_SIZE: usize = 64;
+
+// The search state just before a solution, enough to continue the enumeration from there.
+struct Checkpoint {
+    stack: Vec<(Board, u32)>,
+    stats: SearchStats,
+}
+
+/// Moves back and forth through the solutions in the order `solve` finds them, keeping only one
+/// chunk of solutions and a checkpoint of the search per chunk in memory. Going back to an
+/// earlier chunk reruns the search from its checkpoint.
+pub struct SolutionBrowser<'a> {
+    board: Board,
+    bricks: &'a [Brick],
+    chunk_size: usize,
+    // checkpoints[n] is the state before solution n * chunk_size.
+    checkpoints: Vec<Checkpoint>,
+    // The search continuing after the last solution found so far.
+    frontier: SolveIterator<'a>,
+    found: usize,
+    exhausted: bool,
+    chunk_start: usize,
+    chunk: Vec<SolvedBoard>,
+    position: Option<usize>,
+}
+
+impl<'a> SolutionBrowser<'a> {
+    pub fn new(board: Board, bricks: &'a [Brick]) -> SolutionBrowser<'a> {
+        SolutionBrowser::with_chunk_size(board, bricks, CHUNK_SIZE)
+    }
+
+    fn with_chunk_size(board: Board, bricks: &'a [Brick], chunk_size: usize) -> Self {
+        SolutionBrowser {
+            frontier: SolveIterator::new(board.clone(), bricks),
+            board,
+            bricks,
+            chunk_size,
+            checkpoints: vec![],
+            found: 0,
+            exhausted: false,
+            chunk_start: 0,
+            chunk: vec![],
+            position: None,
+        }
+    }
+
+    /// The index of the solution last moved to.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// The number of solutions, once the search has been run to the end.
+    pub fn total(&self) -> Option<usize> {
+        self.exhausted.then_some(self.found)
+    }
+
+    pub fn forward(&mut self) -> Option<&SolvedBoard> {
+        self.jump(self.position.map_or(0, |position| position + 1))
+    }
+
+    pub fn back(&mut self) -> Option<&SolvedBoard> {
+        self.jump(self.position?.checked_sub(1)?)
+    }
+
+    /// Moves to the solution with the index. Stays put if there are fewer solutions.
+    pub fn jump(&mut self, index: usize) -> Option<&SolvedBoard> {
+        let chunk_start = index - index % self.chunk_size;
+        if !(self.chunk_start..self.chunk_start + self.chunk.len()).contains(&index) {
+            self.load_chunk(chunk_start);
+        }
+        let solution = self.chunk.get(index - self.chunk_start)?;
+        self.position = Some(index);
+        Some(solution)
+    }
+
+    fn load_chunk(&mut self, chunk_start: usize) {
+        while self.found < chunk_start && self.advance().is_some() {}
+        let chunk_size = self.chunk_size;
+        let chunk: Vec<SolvedBoard> = if self.found == chunk_start {
+            core::iter::from_fn(|| self.advance())
+                .take(chunk_size)
+                .collect()
+        } else {
+            let Some(checkpoint) = self.checkpoints.get(chunk_start / self.chunk_size) else {
+                return;
+            };
+            let mut solver = SolveIterator::new(self.board.clone(), self.bricks);
+            solver.stack = checkpoint.stack.clone();
+            solver.stats = checkpoint.stats;
+            solver.solutions = chunk_start;
+            solver.take(chunk_size).collect()
+        };
+        if !chunk.is_empty() {
+            self.chunk_start = chunk_start;
+            self.chunk = chunk;
+        }
+    }
+
+    // Finds the next solution on the frontier, saving a checkpoint at the start of each chunk.
+    fn advance(&mut self) -> Option<SolvedBoard> {
+        if self.found == self.checkpoints.len() * self.chunk_size {
+            self.checkpoints.push(Checkpoint {
+                stack: self.frontier.stack.clone(),
+                stats: self.frontier.stats,
+            });
+        }
+        let solution = self.frontier.next();
+        match solution {
+            Some(_) => self.found += 1,
+            None => self.exhausted = true,
+        }
+        solution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn browses_in_search_order() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let all: Vec<Vec<u64>> = solve(board.clone(), &bricks)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        let mut browser = SolutionBrowser::with_chunk_size(board, &bricks, 5);
+        assert_eq!(browser.jump(17).unwrap().placed_bricks, all[17]);
+        assert_eq!(browser.back().unwrap().placed_bricks, all[16]);
+        assert_eq!(browser.jump(3).unwrap().placed_bricks, all[3]);
+        assert_eq!(browser.jump(28).unwrap().placed_bricks, all[28]);
+        assert!(browser.forward().is_none());
+        assert_eq!(browser.position(), Some(28));
+        assert_eq!(browser.total(), Some(29));
+        assert!(browser.jump(100).is_none());
+        assert_eq!(browser.jump(0).unwrap().placed_bricks, all[0]);
+        assert!(browser.back().is_none());
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, COLUMN_7, SolverError};
+
+/// Builds a board of any shape within 8 rows of 7 columns. The eighth column of the bitboard is
+/// always blocked, since the placements rely on bricks not wrapping around from one row to the next.
+#[derive(Debug, Clone, Default)]
+pub struct BoardBuilder {
+    blocked: u64,
+    open: u64,
+    invalid: Vec<(u8, u8)>,
+}
+
+impl BoardBuilder {
+    /// Starts with every cell free.
+    pub fn new() -> BoardBuilder {
+        BoardBuilder::default()
+    }
+
+    /// Makes the cell part of the frame, so no brick can cover it.
+    pub fn block(mut self, row: u8, column: u8) -> BoardBuilder {
+        match BoardBuilder::cell(row, column) {
+            Some(cell) => self.blocked |= cell,
+            None => self.invalid.push((row, column)),
+        }
+        self
+    }
+
+    /// Marks a cell the bricks have to leave open, like the cells of the date.
+    pub fn keep_open(mut self, row: u8, column: u8) -> BoardBuilder {
+        match BoardBuilder::cell(row, column) {
+            Some(cell) => self.open |= cell,
+            None => self.invalid.push((row, column)),
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<Board, SolverError> {
+        if let Some(&(row, column)) = self.invalid.first() {
+            return Err(SolverError::InvalidCell {
+                row: row.into(),
+                column: column.into(),
+            });
+        }
+        let bitboard = self.blocked | self.open | COLUMN_7;
+        if bitboard == u64::MAX {
+            return Err(SolverError::NoFreeCells);
+        }
+        Ok(Board {
+            bitboard,
+            placed_bricks: vec![],
+            brick_indices: vec![],
+        })
+    }
+
+    pub(crate) fn cell(row: u8, column: u8) -> Option<u64> {
+        (row < 8 && column < 7).then(|| 1 << 63 >> (row * 8 + column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_the_standard_board() {
+        let mut builder = BoardBuilder::new()
+            .block(0, 6)
+            .block(1, 6)
+            .keep_open(0, 0)
+            .keep_open(2, 0);
+        for column in 3..7 {
+            builder = builder.block(6, column);
+        }
+        for column in 0..7 {
+            builder = builder.block(7, column);
+        }
+        assert_eq!(builder.build().unwrap(), Board::for_date(1, 1).unwrap());
+        assert!(BoardBuilder::new().block(0, 7).build().is_err());
+        assert!(BoardBuilder::new().block(8, 0).build().is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    Board, Brick, ValidPlacementIterator, all_bricks_mask, composable_areas, has_dead_region, solve,
+};
+use std::collections::HashSet;
+
+/// Evidence that a board has exactly one solution, checkable without trusting the solver's
+/// pruning. The checker in [`UniquenessCertificate::verify`] walks every branch itself, only
+/// skipping the dead ends listed here after checking that each really can't be finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniquenessCertificate {
+    /// The placed bricks of the only solution.
+    pub solution: Vec<u64>,
+    /// The placements leading to each branch the search dropped, sorted. Each one leaves a region
+    /// of free cells no set of the remaining bricks has the size of.
+    pub dead_ends: Vec<Vec<u64>>,
+}
+
+/// Whether the board has exactly one solution, stopping the search at the second one.
+pub fn has_unique_solution(board: Board, bricks: &[Brick]) -> bool {
+    let mut solutions = solve(board, bricks);
+    solutions.next().is_some() && solutions.next().is_none()
+}
+
+/// A certificate for the board having exactly one solution, or None if it has none or several, or
+/// if the bricks don't cover every free cell.
+pub fn uniqueness_certificate(board: Board, bricks: &[Brick]) -> Option<UniquenessCertificate> {
+    if !board.is_exact_cover(bricks) {
+        return None;
+    }
+    let mut solutions = vec![];
+    let mut dead_ends = vec![];
+    let mut stack = vec![(board, all_bricks_mask(bricks))];
+    while let Some((board, remaining)) = stack.pop() {
+        if remaining == 0 {
+            solutions.push(board.placed_bricks);
+            if solutions.len() > 1 {
+                return None;
+            }
+            continue;
+        }
+        let anchor = (!board.bitboard).leading_zeros();
+        for (brick_index, brick) in bricks.iter().enumerate() {
+            if remaining & (1 << brick_index) == 0 {
+                continue;
+            }
+            let rest = remaining & !(1 << brick_index);
+            for placement in ValidPlacementIterator::anchored(board.bitboard, brick, anchor) {
+                let child = board.place(brick_index, placement);
+                if has_dead_region(child.bitboard, composable_areas(bricks, rest)) {
+                    let mut path = child.placed_bricks;
+                    path.sort_unstable();
+                    dead_ends.push(path);
+                } else {
+                    stack.push((child, rest));
+                }
+            }
+        }
+    }
+    let mut solution = solutions.pop()?;
+    solution.sort_unstable();
+    Some(UniquenessCertificate {
+        solution,
+        dead_ends,
+    })
+}
+
+impl UniquenessCertificate {
+    /// Checks the certificate by searching every branch covering the first free cell, with no
+    /// pruning other than the listed dead ends. Only the brick variants are shared with the
+    /// solver, and `cmd self-test` checks those against the brick shapes.
+    pub fn verify(&self, board: &Board, bricks: &[Brick]) -> bool {
+        let dead_ends: HashSet<&[u64]> = self.dead_ends.iter().map(Vec::as_slice).collect();
+        let mut solutions = 0;
+        let mut stack = vec![(board.bitboard, all_bricks_mask(bricks), vec![])];
+        while let Some((bitboard, remaining, path)) = stack.pop() {
+            if remaining == 0 {
+                if bitboard != u64::MAX || path != self.solution {
+                    return false;
+                }
+                solutions += 1;
+                continue;
+            }
+            if dead_ends.contains(path.as_slice()) {
+                if !is_dead(bitboard, remaining, bricks) {
+                    return false;
+                }
+                continue;
+            }
+            let anchor = (!bitboard).leading_zeros();
+            for (brick_index, brick) in bricks.iter().enumerate() {
+                if remaining & (1 << brick_index) == 0 {
+                    continue;
+                }
+                for variant in brick.brick_variants.iter() {
+                    let pattern = variant.bit_pattern;
+                    let Some(shift) = anchor.checked_sub(pattern.leading_zeros()) else {
+                        continue;
+                    };
+                    let placement = pattern >> shift;
+                    if placement.count_ones() != pattern.count_ones() || bitboard & placement != 0 {
+                        continue;
+                    }
+                    let mut path = path.clone();
+                    path.push(placement);
+                    path.sort_unstable();
+                    stack.push((bitboard | placement, remaining & !(1 << brick_index), path));
+                }
+            }
+        }
+        solutions == 1
+    }
+}
+
+// Whether some region of free cells, grown cell by cell, has a size no subset of the remaining
+// bricks adds up to.
+fn is_dead(bitboard: u64, remaining: u32, bricks: &[Brick]) -> bool {
+    let mut sums = HashSet::from([0]);
+    for (brick_index, brick) in bricks.iter().enumerate() {
+        if remaining & (1 << brick_index) != 0 {
+            let with_brick: Vec<u32> = sums.iter().map(|sum| sum + brick.area()).collect();
+            sums.extend(with_brick);
+        }
+    }
+    let mut unvisited: Vec<u32> = (0..64)
+        .filter(|index| bitboard & 1 << 63 >> index == 0)
+        .collect();
+    while let Some(seed) = unvisited.pop() {
+        let mut region = vec![seed];
+        let mut index = 0;
+        while let Some(&cell) = region.get(index) {
+            let (row, column) = (cell / 8, cell % 8);
+            unvisited.retain(|&other| {
+                let neighbour = row.abs_diff(other / 8) + column.abs_diff(other % 8) == 1;
+                if neighbour {
+                    region.push(other);
+                }
+                !neighbour
+            });
+            index += 1;
+        }
+        if !sums.contains(&(region.len() as u32)) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoardBuilder;
+
+    // A P pentomino, only filled one way by a straight tromino and a domino.
+    fn unique_board() -> (Board, Vec<Brick>) {
+        let mut builder = BoardBuilder::new();
+        for row in 0..8 {
+            for column in 0..7 {
+                if row > 1 || column > 2 || (row, column) == (1, 2) {
+                    builder = builder.block(row, column);
+                }
+            }
+        }
+        let bricks = vec![
+            Brick::from_ascii(&["XXX"]).unwrap(),
+            Brick::from_ascii(&["XX"]).unwrap(),
+        ];
+        (builder.build().unwrap(), bricks)
+    }
+
+    #[test]
+    fn certificate_for_unique_board() {
+        let (board, bricks) = unique_board();
+        assert!(has_unique_solution(board.clone(), &bricks));
+        let certificate = uniqueness_certificate(board.clone(), &bricks).unwrap();
+        assert!(certificate.verify(&board, &bricks));
+
+        let mut forged = certificate.clone();
+        forged.dead_ends.push(vec![]);
+        assert!(!forged.verify(&board, &bricks));
+
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        assert!(!has_unique_solution(board.clone(), &bricks));
+        assert!(uniqueness_certificate(board.clone(), &bricks).is_none());
+        assert!(!certificate.verify(&board, &bricks));
+    }
+}
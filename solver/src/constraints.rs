@@ -0,0 +1,111 @@
+use crate::options::PlacementFilter;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, BoardBuilder, Brick, Placement, SolveIterator, SolvedBoard, SolverError};
+
+/// Rules for where bricks may go, like "brick 3 in the top left corner". The bricks are numbered
+/// by their index in the brick list given to [`solve_with_constraints`].
+#[derive(Default)]
+pub struct Constraints {
+    filters: Vec<Box<dyn PlacementFilter>>,
+    invalid: Vec<(u8, u8)>,
+}
+
+impl Constraints {
+    pub fn new() -> Constraints {
+        Constraints::default()
+    }
+
+    /// The brick has to cover the cell.
+    pub fn require_cell(mut self, brick: usize, row: u8, column: u8) -> Constraints {
+        match BoardBuilder::cell(row, column) {
+            Some(cell) => self.filters.push(Box::new(move |_, index, placement: u64| {
+                index != brick || placement & cell != 0
+            })),
+            None => self.invalid.push((row, column)),
+        }
+        self
+    }
+
+    /// The brick may not cover any cell of the region.
+    pub fn forbid_region(mut self, brick: usize, region: u64) -> Constraints {
+        self.filters.push(Box::new(move |_, index, placement: u64| {
+            index != brick || placement & region == 0
+        }));
+        self
+    }
+
+    /// The brick has to go exactly where the placement puts it.
+    pub fn pin(mut self, placement: Placement) -> Constraints {
+        self.filters.push(Box::new(move |_, index, mask| {
+            index != placement.brick || mask == placement.mask
+        }));
+        self
+    }
+}
+
+/// The solutions where every brick follows the constraints.
+pub fn solve_with_constraints<'a>(
+    initial_board: Board,
+    bricks: &'a [Brick],
+    constraints: &'a Constraints,
+) -> Result<impl Iterator<Item = SolvedBoard> + 'a, SolverError> {
+    if let Some(&(row, column)) = constraints.invalid.first() {
+        return Err(SolverError::InvalidCell {
+            row: row.into(),
+            column: column.into(),
+        });
+    }
+    let mut solver = SolveIterator::new(initial_board, bricks);
+    solver.branching.filters = &constraints.filters;
+    Ok(solver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn constrained_solutions() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let all: Vec<SolvedBoard> = solve(board.clone(), &bricks).collect();
+        let corner = 1 << 63;
+        let brick = all[0]
+            .placements(&bricks)
+            .unwrap()
+            .iter()
+            .find(|placement| placement.mask & corner != 0)
+            .unwrap()
+            .brick;
+        let constraints = Constraints::new().require_cell(brick, 0, 0);
+        let cornered: Vec<SolvedBoard> =
+            solve_with_constraints(board.clone(), &bricks, &constraints)
+                .unwrap()
+                .collect();
+        assert!(!cornered.is_empty());
+        for solution in &cornered {
+            let placements = solution.placements(&bricks).unwrap();
+            let placement = placements.iter().find(|placement| placement.brick == brick);
+            assert_ne!(placement.unwrap().mask & corner, 0);
+        }
+
+        let constraints = Constraints::new().forbid_region(brick, corner);
+        let rest = solve_with_constraints(board.clone(), &bricks, &constraints)
+            .unwrap()
+            .count();
+        assert_eq!(cornered.len() + rest, all.len());
+
+        let pinned = all[3].placements(&bricks).unwrap()[0];
+        let constraints = Constraints::new().pin(pinned);
+        assert!(
+            solve_with_constraints(board.clone(), &bricks, &constraints)
+                .unwrap()
+                .all(|solution| solution.placed_bricks.contains(&pinned.mask))
+        );
+
+        let constraints = Constraints::new().require_cell(0, 0, 7);
+        assert!(solve_with_constraints(board, &bricks, &constraints).is_err());
+    }
+}
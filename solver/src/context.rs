@@ -0,0 +1,214 @@
+use crate::options::search;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    Board, Brick, Hint, Map, PlacementTable, Search, SearchProfile, SolveOptions, SolvedBoard,
+    SolverError, rank_hints,
+};
+
+// Keeps per-date work around so successive solve/hints/verify calls for the same date don't redo it.
+pub struct SolverContext {
+    bricks: Box<[Brick]>,
+    dates: Map<(u8, u8), DateContext>,
+}
+
+struct DateContext {
+    board: Board,
+    table: PlacementTable,
+    solutions: Option<Vec<SolvedBoard>>,
+}
+
+impl DateContext {
+    fn new(board: Board, bricks: &[Brick]) -> DateContext {
+        DateContext {
+            table: PlacementTable::new(&board, bricks),
+            board,
+            solutions: None,
+        }
+    }
+}
+
+impl SolverContext {
+    pub fn new(bricks: Box<[Brick]>) -> SolverContext {
+        SolverContext {
+            bricks,
+            dates: Map::new(),
+        }
+    }
+
+    pub fn bricks(&self) -> &[Brick] {
+        &self.bricks
+    }
+
+    fn date(&mut self, day: u8, month: u8) -> Result<&mut DateContext, SolverError> {
+        if !self.dates.contains_key(&(day, month)) {
+            let board = Board::for_date(day, month)?;
+            let date = DateContext::new(board, &self.bricks);
+            self.dates.insert((day, month), date);
+        }
+        Ok(self.dates.get_mut(&(day, month)).unwrap())
+    }
+
+    /// Valid placements of every brick on the date's board, indexed like the bricks.
+    pub fn placements(&mut self, day: u8, month: u8) -> Result<&[Vec<u64>], SolverError> {
+        Ok(self.date(day, month)?.table.all())
+    }
+
+    /// Number of valid placements of every brick on the date's board.
+    pub fn opening_counts(&mut self, day: u8, month: u8) -> Result<Vec<usize>, SolverError> {
+        Ok(self.placements(day, month)?.iter().map(Vec::len).collect())
+    }
+
+    /// Like [`crate::solve_with_options`] for the date, looking the placements up in the ones
+    /// kept for it. The solutions found this way aren't kept.
+    pub fn solve_with_options<'a>(
+        &'a mut self,
+        day: u8,
+        month: u8,
+        options: &'a SolveOptions,
+    ) -> Result<Search<'a>, SolverError> {
+        self.date(day, month)?;
+        let context: &'a SolverContext = self;
+        let date = &context.dates[&(day, month)];
+        Ok(search(
+            date.board.clone(),
+            &context.bricks,
+            options,
+            Some(&date.table),
+        ))
+    }
+
+    /// All solutions for the date. The search only runs the first time a date is asked for.
+    pub fn solve(&mut self, day: u8, month: u8) -> Result<&[SolvedBoard], SolverError> {
+        self.date(day, month)?;
+        let date = self.dates.get_mut(&(day, month)).unwrap();
+        let bricks = &self.bricks;
+        let board = date.board.clone();
+        let table = &date.table;
+        Ok(date.solutions.get_or_insert_with(|| {
+            let options = SolveOptions::new().profile(SearchProfile::Interactive);
+            search(board, bricks, &options, Some(table)).collect()
+        }))
+    }
+
+    pub fn hints(&mut self, day: u8, month: u8) -> Result<Vec<Hint>, SolverError> {
+        let solutions = self.solve(day, month)?;
+        Ok(rank_hints(
+            solutions
+                .iter()
+                .map(|solution| solution.placed_bricks.clone()),
+        ))
+    }
+
+    /// Checks that the placed bricks are a complete solution for the date, using every brick once.
+    pub fn verify(
+        &mut self,
+        day: u8,
+        month: u8,
+        placed_bricks: &[u64],
+    ) -> Result<bool, SolverError> {
+        let date = self.date(day, month)?;
+        if placed_bricks.len() != date.table.all().len() {
+            return Ok(false);
+        }
+        let mut covered = date.board.bitboard;
+        for &placed in placed_bricks {
+            if covered & placed != 0 {
+                return Ok(false);
+            }
+            covered |= placed;
+        }
+        Ok(covered == u64::MAX && assign_bricks(placed_bricks, date.table.all(), 0))
+    }
+}
+
+// Whether each placed mask can be matched to a different brick that has it as a valid placement.
+fn assign_bricks(placed_bricks: &[u64], placements: &[Vec<u64>], used: u64) -> bool {
+    let Some((placed, rest)) = placed_bricks.split_first() else {
+        return true;
+    };
+    placements
+        .iter()
+        .enumerate()
+        .any(|(brick_index, brick_placements)| {
+            used & (1 << brick_index) == 0
+                && brick_placements.contains(placed)
+                && assign_bricks(rest, placements, used | (1 << brick_index))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hints, solve_with_options};
+
+    #[test]
+    fn solutions_are_cached_and_verified() {
+        let mut context = SolverContext::new(Brick::all_bricks());
+        let solutions = context.solve(22, 9).unwrap().to_vec();
+        assert_eq!(solutions.len(), 29);
+        assert_eq!(context.solve(22, 9).unwrap().len(), 29);
+        for solution in &solutions {
+            assert!(context.verify(22, 9, &solution.placed_bricks).unwrap());
+        }
+        let mut overlapping = solutions[0].placed_bricks.clone();
+        overlapping[1] = overlapping[0];
+        assert!(!context.verify(22, 9, &overlapping).unwrap());
+        assert!(!context.verify(23, 9, &solutions[0].placed_bricks).unwrap());
+    }
+
+    #[test]
+    fn hints_match_uncached_hints() {
+        let mut context = SolverContext::new(Brick::all_bricks());
+        let cached = context.hints(29, 7).unwrap();
+        let uncached = hints(Board::for_date(29, 7).unwrap(), &Brick::all_bricks());
+        assert_eq!(cached.len(), uncached.len());
+        assert_eq!(cached[0].solutions, uncached[0].solutions);
+    }
+
+    #[test]
+    fn looked_up_placements_search_the_same_boards() {
+        let mut context = SolverContext::new(Brick::all_bricks());
+        let bricks = Brick::all_bricks();
+        for profile in [
+            SearchProfile::Exhaustive,
+            SearchProfile::Interactive,
+            SearchProfile::FailFirst,
+        ] {
+            for (day, month) in [(1, 1), (22, 9), (31, 12)] {
+                let options = SolveOptions::new().profile(profile);
+                let board = Board::for_date(day, month).unwrap();
+                let expected: Vec<(Vec<u64>, u64)> = solve_with_options(board, &bricks, &options)
+                    .map(|solution| (solution.placed_bricks, solution.stats.test_count))
+                    .collect();
+                let found: Vec<(Vec<u64>, u64)> = context
+                    .solve_with_options(day, month, &options)
+                    .unwrap()
+                    .map(|solution| (solution.placed_bricks, solution.stats.test_count))
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+        // Two bricks leave cells free, so the search goes through the bricks in order instead.
+        let mut context = SolverContext::new(bricks[..2].into());
+        let options = SolveOptions::new();
+        let board = Board::for_date(22, 9).unwrap();
+        let expected: Vec<Vec<u64>> = solve_with_options(board, &bricks[..2], &options)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        let found: Vec<Vec<u64>> = context
+            .solve_with_options(22, 9, &options)
+            .unwrap()
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        assert!(found.len() > 100);
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn invalid_date_is_an_error() {
+        let mut context = SolverContext::new(Brick::all_bricks());
+        assert!(context.solve(32, 1).is_err());
+        assert!(context.opening_counts(1, 13).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, SolveIterator, SolverError, hints_from_solutions};
+
+/// How hard a date is to solve by hand, with what the rating is based on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyReport {
+    pub day: u8,
+    pub month: u8,
+    /// 1 for the easiest dates to 5 for the hardest.
+    pub rating: u8,
+    pub solutions: usize,
+    /// Placements that are part of every solution. Few dates have any, and those hinge on
+    /// finding them.
+    pub forced_placements: usize,
+    /// Forced placements per brick, from 0 to 1.
+    pub forced_density: f32,
+    /// Boards the search tested before finding a solution, or in all when there is none.
+    pub boards_tested: u64,
+}
+
+/// The difficulty of a date with the standard bricks.
+pub fn difficulty(day: u8, month: u8) -> Result<DifficultyReport, SolverError> {
+    difficulty_with_bricks(day, month, &Brick::all_bricks())
+}
+
+/// The difficulty of a date with the bricks. A point is added to the rating of 1 for each of: fewer
+/// than 50 solutions, fewer than 20, more than 60 boards tested before the first solution, more
+/// than 300, and any forced placement, up to 5. A date without solutions is rated 5. Most standard
+/// dates are rated 1 to 3, and fewer than 1 in 10 are 4 or 5.
+pub fn difficulty_with_bricks(
+    day: u8,
+    month: u8,
+    bricks: &[Brick],
+) -> Result<DifficultyReport, SolverError> {
+    let board = Board::for_date(day, month)?;
+    let mut search = SolveIterator::new(board, bricks);
+    let mut first = None;
+    let mut solutions = Vec::new();
+    for solution in search.by_ref() {
+        first.get_or_insert(solution.stats.test_count);
+        solutions.push(solution.placed_bricks);
+    }
+    let boards_tested = first.unwrap_or(search.stats.test_count);
+    let count = solutions.len();
+    let forced_placements = hints_from_solutions(solutions)
+        .iter()
+        .filter(|hint| hint.solutions == count)
+        .count();
+    let rating = if count == 0 {
+        5
+    } else {
+        1 + u8::from(count < 50)
+            + u8::from(count < 20)
+            + u8::from(boards_tested > 60)
+            + u8::from(boards_tested > 300)
+            + u8::from(forced_placements > 0)
+    };
+    Ok(DifficultyReport {
+        day,
+        month,
+        rating: rating.min(5),
+        solutions: count,
+        forced_placements,
+        forced_density: forced_placements as f32 / bricks.len().max(1) as f32,
+        boards_tested,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_solutions, forced_placements};
+
+    #[test]
+    fn difficulty_counts_solutions_and_forced_placements() {
+        let report = difficulty(6, 4).unwrap();
+        let board = Board::for_date(6, 4).unwrap();
+        let bricks = Brick::all_bricks();
+        assert_eq!(report.solutions, count_solutions(board.clone(), &bricks));
+        assert_eq!(
+            report.forced_placements,
+            forced_placements(board, &bricks).len()
+        );
+        assert_eq!(report.forced_density, 1.0 / 8.0);
+        assert!(report.rating >= 3);
+    }
+
+    #[test]
+    fn difficulty_needs_a_date() {
+        assert!(difficulty(32, 1).is_err());
+    }
+
+    #[test]
+    fn unsolvable_dates_are_hardest() {
+        // A single brick covering the free cells of January 1 fits no other date.
+        let brick = Brick::from_shape(!Board::for_date(1, 1).unwrap().bitboard);
+        let report = difficulty_with_bricks(1, 2, &[brick]).unwrap();
+        assert_eq!((report.solutions, report.rating), (0, 5));
+    }
+}
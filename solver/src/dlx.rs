@@ -0,0 +1,265 @@
+// Exact cover backend using Knuth's Algorithm X with dancing links. Columns are the free cells of
+// the board and the bricks, rows are the valid placements of each brick. When the bricks don't
+// cover every free cell, the cell columns are secondary, so they may stay uncovered.
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::stats::Stopwatch;
+use crate::{Board, Brick, SearchStats, SolvedBoard};
+
+pub fn solve(initial_board: Board, bricks: &[Brick]) -> impl Iterator<Item = SolvedBoard> {
+    DancingLinks::new(initial_board, bricks)
+}
+
+struct DancingLinks {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>,
+    row: Vec<usize>,
+    size: Vec<usize>,
+    // The brick index and placement of each row.
+    placements: Vec<(usize, u64)>,
+    placed_bricks: Vec<u64>,
+    brick_indices: Vec<usize>,
+    chosen: Vec<(usize, usize)>,
+    state: State,
+    stats: SearchStats,
+    started: Option<Stopwatch>,
+}
+
+enum State {
+    Descend,
+    Backtrack,
+}
+
+const ROOT: usize = 0;
+
+impl DancingLinks {
+    fn new(board: Board, bricks: &[Brick]) -> DancingLinks {
+        let cells: Vec<u32> = (0..64).filter(|&i| board.is_free(i as u8)).collect();
+        let columns = cells.len() + bricks.len();
+        let primary_cells = board.is_exact_cover(bricks);
+        let mut links = DancingLinks {
+            left: (0..=columns).map(|i| i.wrapping_sub(1)).collect(),
+            right: (0..=columns).map(|i| i + 1).collect(),
+            up: (0..=columns).collect(),
+            down: (0..=columns).collect(),
+            column: (0..=columns).collect(),
+            row: vec![usize::MAX; columns + 1],
+            size: vec![0; columns + 1],
+            placements: vec![],
+            placed_bricks: board.placed_bricks.clone(),
+            brick_indices: board.brick_indices.clone(),
+            chosen: vec![],
+            state: State::Descend,
+            stats: SearchStats::default(),
+            started: None,
+        };
+        // The header ring: root, then the primary columns. Bricks already on the board have no
+        // rows, so their columns are left out.
+        let primary: Vec<usize> = (1..=columns)
+            .filter(|&c| primary_cells || c > cells.len())
+            .filter(|&c| c <= cells.len() || !board.brick_indices.contains(&(c - cells.len() - 1)))
+            .collect();
+        let mut previous = ROOT;
+        for &c in &primary {
+            links.right[previous] = c;
+            links.left[c] = previous;
+            previous = c;
+        }
+        links.right[previous] = ROOT;
+        links.left[ROOT] = previous;
+        for c in (1..=cells.len()).filter(|c| !primary.contains(c)) {
+            links.left[c] = c;
+            links.right[c] = c;
+        }
+
+        for (brick_index, brick) in bricks.iter().enumerate() {
+            if board.brick_indices.contains(&brick_index) {
+                continue;
+            }
+            for placement in board.valid_placements(brick) {
+                let row_columns = cells
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &cell)| placement & (1u64 << 63 >> cell) != 0)
+                    .map(|(i, _)| i + 1)
+                    .chain(core::iter::once(cells.len() + 1 + brick_index));
+                links.add_row(row_columns.collect(), brick_index, placement);
+            }
+        }
+        links
+    }
+
+    fn add_row(&mut self, columns: Vec<usize>, brick_index: usize, placement: u64) {
+        let row = self.placements.len();
+        self.placements.push((brick_index, placement));
+        let first = self.left.len();
+        for (i, &c) in columns.iter().enumerate() {
+            let node = first + i;
+            let last = first + columns.len() - 1;
+            self.left.push(if i == 0 { last } else { node - 1 });
+            self.right.push(if node == last { first } else { node + 1 });
+            self.up.push(self.up[c]);
+            self.down.push(c);
+            self.down[self.up[c]] = node;
+            self.up[c] = node;
+            self.column.push(c);
+            self.row.push(row);
+            self.size[c] += 1;
+        }
+    }
+
+    fn cover(&mut self, c: usize) {
+        self.right[self.left[c]] = self.right[c];
+        self.left[self.right[c]] = self.left[c];
+        let mut i = self.down[c];
+        while i != c {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.up[c];
+        while i != c {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        self.right[self.left[c]] = c;
+        self.left[self.right[c]] = c;
+    }
+
+    fn select(&mut self, node: usize) {
+        let (brick_index, placement) = self.placements[self.row[node]];
+        self.placed_bricks.push(placement);
+        self.brick_indices.push(brick_index);
+        let mut j = self.right[node];
+        while j != node {
+            self.cover(self.column[j]);
+            j = self.right[j];
+        }
+    }
+
+    fn unselect(&mut self, node: usize) {
+        self.placed_bricks.pop();
+        self.brick_indices.pop();
+        let mut j = self.left[node];
+        while j != node {
+            self.uncover(self.column[j]);
+            j = self.left[j];
+        }
+    }
+
+    // The primary column with the fewest rows left.
+    fn smallest_column(&self) -> Option<usize> {
+        let mut best = None;
+        let mut c = self.right[ROOT];
+        while c != ROOT {
+            if best.is_none_or(|best| self.size[c] < self.size[best]) {
+                best = Some(c);
+            }
+            c = self.right[c];
+        }
+        best
+    }
+}
+
+impl Iterator for DancingLinks {
+    type Item = SolvedBoard;
+
+    fn next(&mut self) -> Option<SolvedBoard> {
+        loop {
+            match self.state {
+                State::Descend => {
+                    let started = *self.started.get_or_insert_with(Stopwatch::start);
+                    self.stats.test_count += 1;
+                    self.stats.max_depth = self.stats.max_depth.max(self.placed_bricks.len());
+                    let Some(c) = self.smallest_column() else {
+                        self.state = State::Backtrack;
+                        let elapsed = started.elapsed();
+                        self.stats.solution_time = elapsed.saturating_sub(self.stats.elapsed);
+                        self.stats.elapsed = elapsed;
+                        return Some(SolvedBoard {
+                            placed_bricks: self.placed_bricks.clone(),
+                            bricks: self.brick_indices.clone(),
+                            stats: self.stats,
+                        });
+                    };
+                    self.stats.placements_generated += self.size[c] as u64;
+                    if self.size[c] == 0 {
+                        self.stats.backtracks += 1;
+                        self.state = State::Backtrack;
+                        continue;
+                    }
+                    self.cover(c);
+                    let node = self.down[c];
+                    self.chosen.push((c, node));
+                    self.select(node);
+                }
+                State::Backtrack => {
+                    let (c, node) = self.chosen.pop()?;
+                    self.unselect(node);
+                    let next = self.down[node];
+                    if next == c {
+                        self.uncover(c);
+                        continue;
+                    }
+                    self.chosen.push((c, next));
+                    self.select(next);
+                    self.state = State::Descend;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(solutions: impl Iterator<Item = SolvedBoard>) -> Vec<Vec<u64>> {
+        let mut solutions: Vec<Vec<u64>> = solutions
+            .map(|solution| {
+                let mut placed = solution.placed_bricks;
+                placed.sort_unstable();
+                placed
+            })
+            .collect();
+        solutions.sort_unstable();
+        solutions
+    }
+
+    #[test]
+    fn same_solutions_as_bitboard_search() {
+        for (day, month) in [(22, 9), (29, 7), (31, 12)] {
+            let board = Board::for_date(day, month).unwrap();
+            let bricks = Brick::all_bricks();
+            let dlx = sorted(solve(board.clone(), &bricks));
+            assert_eq!(dlx, sorted(crate::solve(board, &bricks)));
+        }
+    }
+
+    #[test]
+    fn secondary_cells_may_stay_uncovered() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let subset = &bricks[..2];
+        let dlx = sorted(solve(board.clone(), subset));
+        assert!(!dlx.is_empty());
+        assert_eq!(dlx, sorted(crate::solve(board, subset)));
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolverError {
+    InvalidDay(u8),
+    InvalidMonth(u8),
+    InvalidWeekday(u8),
+    /// A cell outside the board.
+    InvalidCell {
+        row: u32,
+        column: u32,
+    },
+    NoFreeCells,
+    BoardTooLarge {
+        width: u32,
+        height: u32,
+        bits: u32,
+    },
+    /// The bricks cover a different number of cells than the board leaves free.
+    AreaMismatch {
+        bricks: u32,
+        free: u32,
+    },
+    /// The brick with the index is placed more than once.
+    BrickPlacedTwice(usize),
+    /// The brick with the index covers a cell that is blocked or already covered.
+    CellTaken(usize),
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::InvalidDay(day) => write!(f, "Invalid day {day}. Valid days: 1-31"),
+            SolverError::InvalidMonth(month) => {
+                write!(f, "Invalid month {month}. Valid months: 1-12")
+            }
+            SolverError::InvalidWeekday(weekday) => {
+                write!(f, "Invalid weekday {weekday}. Valid weekdays: 1-7")
+            }
+            SolverError::InvalidCell { row, column } => {
+                write!(
+                    f,
+                    "The cell at row {row}, column {column} is outside the board"
+                )
+            }
+            SolverError::NoFreeCells => write!(f, "The board has no free cells"),
+            SolverError::BoardTooLarge {
+                width,
+                height,
+                bits,
+            } => write!(f, "A {width}x{height} board doesn't fit in {bits} bits"),
+            SolverError::AreaMismatch { bricks, free } => write!(
+                f,
+                "The bricks cover {bricks} cells, but the board has {free} free"
+            ),
+            SolverError::BrickPlacedTwice(brick) => {
+                write!(f, "Brick {} is placed more than once", brick + 1)
+            }
+            SolverError::CellTaken(brick) => {
+                write!(f, "Brick {} covers a cell that is already taken", brick + 1)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SolverError {}
+
+// Errors used to be strings, so `?` still works in functions returning them.
+impl From<SolverError> for String {
+    fn from(error: SolverError) -> String {
+        format!("{error}")
+    }
+}
@@ -0,0 +1,97 @@
+use crate::rng::Rng;
+use crate::{Board, Brick, SolveIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolutionEstimate {
+    pub estimate: f64,
+    pub std_error: f64,
+    /// Lower end of the 95% confidence interval.
+    pub low: f64,
+    /// Upper end of the 95% confidence interval.
+    pub high: f64,
+    pub samples: usize,
+}
+
+// Knuth's estimator: follow one random path from the root and multiply the branching factors along
+// the way. The product is an unbiased estimate of the number of solutions when the path ends in one.
+pub fn estimate_solution_count(
+    board: Board,
+    bricks: &[Brick],
+    samples: usize,
+    seed: u64,
+) -> SolutionEstimate {
+    let solver = SolveIterator::new(board.clone(), bricks);
+    let mut rng = Rng::new(seed);
+    let mut sum = 0.0;
+    let mut sum_of_squares = 0.0;
+    for _ in 0..samples {
+        let mut bitboard = board.bitboard;
+        let mut remaining = board.unplaced(bricks);
+        let mut weight = 1.0;
+        while remaining != 0 {
+            // The pruned children have no solutions, so leaving them out keeps the estimate
+            // unbiased while cutting its variance a lot.
+            let children: Vec<(usize, u64)> =
+                solver.branching.children(bitboard, remaining).collect();
+            if children.is_empty() {
+                weight = 0.0;
+                break;
+            }
+            weight *= children.len() as f64;
+            let (brick_index, placement) = children[rng.below(children.len())];
+            bitboard |= placement;
+            remaining &= !(1 << brick_index);
+        }
+        sum += weight;
+        sum_of_squares += weight * weight;
+    }
+    let n = samples.max(1) as f64;
+    let estimate = sum / n;
+    let variance = (sum_of_squares / n - estimate * estimate).max(0.0);
+    let std_error = (variance / n).sqrt();
+    SolutionEstimate {
+        estimate,
+        std_error,
+        low: (estimate - 1.96 * std_error).max(0.0),
+        high: estimate + 1.96 * std_error,
+        samples,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_brackets_exact_count() {
+        let board = Board::for_date(22, 9).unwrap(); // 29 solutions
+        let estimate = estimate_solution_count(board, &Brick::all_bricks(), 20_000, 1);
+        assert!(
+            estimate.low <= 29.0 && 29.0 <= estimate.high,
+            "29 not within {estimate:?}"
+        );
+    }
+
+    #[test]
+    fn estimate_leaves_out_placed_bricks() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let first = crate::solve(board.clone(), &bricks).next().unwrap();
+        let placed = first.placements(&bricks).unwrap();
+        let partial = board.with_placements(&placed[..3]).unwrap();
+        let exact = crate::count_solutions(partial.clone(), &bricks) as f64;
+        let estimate = estimate_solution_count(partial, &bricks, 5_000, 1);
+        assert!(estimate.estimate > 0.0);
+        assert!(
+            estimate.low <= exact && exact <= estimate.high,
+            "{exact} not within {estimate:?}"
+        );
+    }
+
+    #[test]
+    fn no_samples_gives_zero() {
+        let board = Board::for_date(1, 1).unwrap();
+        let estimate = estimate_solution_count(board, &Brick::all_bricks(), 0, 1);
+        assert_eq!(estimate.estimate, 0.0);
+    }
+}
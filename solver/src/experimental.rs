@@ -0,0 +1,24 @@
+//! Newer parts of the API, which may still change in any release, without deprecated shims.
+//! Pin an exact version when depending on them.
+
+#[cfg(feature = "table")]
+pub use crate::table_solutions;
+pub use crate::{
+    AuditIssue, BitWord, Board128, BoardBuilder, BrickSetGenerator, CancellationToken, Constraints,
+    DateResult, DifficultyReport, DiversityOrder, FoundOrder, GeneratorOptions, HintStrategy,
+    Image, Inventory, LexicographicOrder, Palette, Placement, PlacementFilter, Quadrant,
+    Redundancy, STANDARD_BRICK_CELLS, Search, SolutionBrowser, SolutionOrderer, SolutionScorer,
+    SolveOptions, SolverContext, SymmetryOrder, WideBoard, WideBrick, all_dates, audit_brick,
+    brick_redundancy, dates_with_few_solutions, dead_placements, difficulty,
+    difficulty_with_bricks, dlx, easy_start, forced_placements, generate_brick_sets, hints_by,
+    hints_for_partial, hints_from_solutions, hints_iter, hints_with_options, hints_with_profile,
+    placements_covering, polyominoes, puzzle_checksum, ranked_solutions, sample_solutions,
+    solve_dates, solve_n, solve_random, solve_wide, solve_with_constraints, solve_with_options,
+    solve_year,
+};
+#[cfg(feature = "std")]
+pub use crate::{
+    ParallelSearch, Progress, SearchProgress, SolutionEstimate, StablePlacement, ThreadStats,
+    UniquenessCertificate, estimate_solution_count, has_unique_solution, placement_stability,
+    solve_par, solve_par_threads, solve_with_progress, uniqueness_certificate,
+};
@@ -0,0 +1,186 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::rng::Rng;
+use crate::shape::{canonical, cells};
+use crate::{Board, Brick, COLUMN_0, COLUMN_7, Set, all_dates, solve};
+
+// The bottom row, outside the 7 by 7 board.
+const ROW_7: u64 = 0xFF;
+
+/// What `generate_brick_sets` looks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorOptions {
+    /// The number of cells of each brick. They have to add up to 41, the cells a date leaves
+    /// free, for the bricks to cover the board.
+    pub sizes: Vec<usize>,
+    /// Seed for picking the shapes, so a run can be repeated.
+    pub seed: u64,
+    /// How many sets to try before giving up.
+    pub attempts: usize,
+}
+
+impl Default for GeneratorOptions {
+    /// The sizes of the standard bricks: one of 6 cells and seven of 5.
+    fn default() -> GeneratorOptions {
+        GeneratorOptions {
+            sizes: vec![6, 5, 5, 5, 5, 5, 5, 5],
+            seed: 0,
+            attempts: 1000,
+        }
+    }
+}
+
+/// Every shape of `size` connected cells that fits on the board, once each however it is
+/// rotated or turned over.
+pub fn polyominoes(size: usize) -> Vec<Brick> {
+    shapes(size).into_iter().map(Brick::from_shape).collect()
+}
+
+// The canonical patterns of the polyominoes of the size, sorted so the order doesn't depend on
+// the set type.
+fn shapes(size: usize) -> Vec<u64> {
+    if size == 0 {
+        return vec![];
+    }
+    let mut shapes = vec![1u64 << 63];
+    for _ in 1..size {
+        let mut grown = Set::new();
+        for shape in shapes {
+            // Moved one cell down and right, so there is room to grow up and to the left.
+            let shape = shape >> 9;
+            let neighbours =
+                shape << 8 | shape >> 8 | (shape & !COLUMN_0) << 1 | (shape & !COLUMN_7) >> 1;
+            for cell in cells(neighbours & !shape) {
+                let pattern = canonical(shape | cell);
+                if pattern & COLUMN_7 == 0 && pattern & ROW_7 == 0 {
+                    grown.insert(pattern);
+                }
+            }
+        }
+        shapes = grown.into_iter().collect();
+        shapes.sort_unstable();
+    }
+    shapes
+}
+
+/// Sets of different bricks with the sizes in the options that solve every date of the year,
+/// found by trying random sets. Sets already found aren't given again, and the iterator ends
+/// once the attempts are used up.
+pub fn generate_brick_sets(options: &GeneratorOptions) -> BrickSetGenerator {
+    let mut pools: Vec<(usize, usize, Vec<u64>)> = Vec::new();
+    for &size in &options.sizes {
+        match pools
+            .iter_mut()
+            .find(|(pool_size, _, _)| *pool_size == size)
+        {
+            Some((_, count, _)) => *count += 1,
+            None => pools.push((size, 1, shapes(size))),
+        }
+    }
+    let possible = pools
+        .iter()
+        .all(|(_, count, shapes)| *count <= shapes.len());
+    BrickSetGenerator {
+        rng: Rng::new(options.seed),
+        pools,
+        attempts_left: if possible { options.attempts } else { 0 },
+        tried: Set::new(),
+    }
+}
+
+/// The iterator `generate_brick_sets` returns.
+#[derive(Debug, Clone)]
+pub struct BrickSetGenerator {
+    rng: Rng,
+    // The size, how many bricks of it, and the shapes to pick from.
+    pools: Vec<(usize, usize, Vec<u64>)>,
+    attempts_left: usize,
+    tried: Set<Vec<u64>>,
+}
+
+impl BrickSetGenerator {
+    // Distinct random shapes of each size, largest first like the standard bricks.
+    fn pick(&mut self) -> Vec<u64> {
+        let mut set = Vec::new();
+        for (_, count, shapes) in &mut self.pools {
+            self.rng.shuffle(shapes);
+            set.extend_from_slice(&shapes[..*count]);
+        }
+        set.sort_unstable_by_key(|&shape| (core::cmp::Reverse(shape.count_ones()), shape));
+        set
+    }
+}
+
+impl Iterator for BrickSetGenerator {
+    type Item = Box<[Brick]>;
+
+    fn next(&mut self) -> Option<Box<[Brick]>> {
+        while self.attempts_left > 0 {
+            self.attempts_left -= 1;
+            let set = self.pick();
+            if !self.tried.insert(set.clone()) {
+                continue;
+            }
+            let bricks: Box<[Brick]> = set.into_iter().map(Brick::from_shape).collect();
+            if solves_every_date(&bricks) {
+                return Some(bricks);
+            }
+        }
+        None
+    }
+}
+
+fn solves_every_date(bricks: &[Brick]) -> bool {
+    all_dates().all(|(month, day)| {
+        let board = Board::for_date(day, month).unwrap();
+        solve(board, bricks).next().is_some()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polyomino_counts() {
+        let counts: Vec<usize> = (0..=7).map(|size| polyominoes(size).len()).collect();
+        assert_eq!(counts, [0, 1, 1, 2, 5, 12, 35, 108]);
+    }
+
+    #[test]
+    fn standard_bricks_are_polyominoes() {
+        let pentominoes = shapes(5);
+        let hexominoes = shapes(6);
+        for brick in Brick::all_bricks().iter() {
+            let shape = canonical(brick.shape());
+            assert!(pentominoes.contains(&shape) || hexominoes.contains(&shape));
+        }
+    }
+
+    #[test]
+    fn generated_sets_solve_every_date() {
+        let options = GeneratorOptions {
+            seed: 3,
+            attempts: 10,
+            ..GeneratorOptions::default()
+        };
+        let set = generate_brick_sets(&options).next().unwrap();
+        assert_eq!(set.len(), 8);
+        assert_eq!(
+            set.iter()
+                .map(|brick| brick.shape().count_ones())
+                .sum::<u32>(),
+            41
+        );
+        assert!(solves_every_date(&set));
+    }
+
+    #[test]
+    fn too_many_bricks_of_a_size_find_nothing() {
+        let options = GeneratorOptions {
+            sizes: vec![3, 3, 3],
+            ..GeneratorOptions::default()
+        };
+        assert!(generate_brick_sets(&options).next().is_none());
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, Hint, hints};
+use core::cmp::Ordering;
+
+/// How hints are ranked, best first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HintStrategy {
+    /// By the number of solutions the placement is part of, like [`hints`].
+    #[default]
+    MostSolutions,
+    /// By the solutions per way to go on: the placement's solutions divided by the placements
+    /// that could cover the first cell it leaves free, dead ends included. Favours hints after
+    /// which few of the next moves are wrong, so the rest of the puzzle is easier.
+    LeastBranching,
+}
+
+impl HintStrategy {
+    /// Orders hints for the board, as `hints` gives them, by the strategy. Placements that score
+    /// the same keep their order.
+    pub fn rank(self, board: &Board, bricks: &[Brick], hints: &mut [Hint]) {
+        if self == HintStrategy::MostSolutions {
+            hints.sort_by_key(|hint| core::cmp::Reverse(hint.solutions));
+            return;
+        }
+        let mut scored: Vec<(Hint, usize)> = hints
+            .iter()
+            .map(|hint| (*hint, next_choices(board, bricks, hint.brick).max(1)))
+            .collect();
+        scored.sort_by(|(a, a_choices), (b, b_choices)| compare(b, *b_choices, a, *a_choices));
+        for (hint, (scored, _)) in hints.iter_mut().zip(scored) {
+            *hint = scored;
+        }
+    }
+}
+
+// Compares solutions per choice without dividing.
+fn compare(a: &Hint, a_choices: usize, b: &Hint, b_choices: usize) -> Ordering {
+    (a.solutions * b_choices).cmp(&(b.solutions * a_choices))
+}
+
+// The placements of the bricks still left that cover the first free cell once the placement is
+// made, the choices the next move has.
+fn next_choices(board: &Board, bricks: &[Brick], placement: u64) -> usize {
+    let unplaced = board.unplaced(bricks);
+    let Some(brick_index) = (0..bricks.len())
+        .find(|&index| unplaced & 1 << index != 0 && bricks[index].matches(placement))
+    else {
+        return 0;
+    };
+    let board = board.place(brick_index, placement);
+    let Some(cell) = (0..64u8).find(|&index| board.is_free(index)) else {
+        return 0;
+    };
+    let cell = 1 << 63 >> cell;
+    let unplaced = board.unplaced(bricks);
+    bricks
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| unplaced & 1 << index != 0)
+        .map(|(_, brick)| {
+            board
+                .valid_placements(brick)
+                .filter(|placement| placement & cell != 0)
+                .count()
+        })
+        .sum()
+}
+
+/// Like [`hints`], ranked by the strategy.
+pub fn hints_by(board: Board, bricks: &[Brick], strategy: HintStrategy) -> Vec<Hint> {
+    let mut ranked = hints(board.clone(), bricks);
+    strategy.rank(&board, bricks, &mut ranked);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_branching_ranks_by_solutions_per_choice() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let mut most = hints_by(board.clone(), &bricks, HintStrategy::MostSolutions);
+        assert!(
+            most.windows(2)
+                .all(|pair| pair[0].solutions >= pair[1].solutions)
+        );
+        let mut least = hints_by(board.clone(), &bricks, HintStrategy::LeastBranching);
+        let score = |hint: &Hint| {
+            hint.solutions as f64 / next_choices(&board, &bricks, hint.brick).max(1) as f64
+        };
+        assert!(
+            least
+                .windows(2)
+                .all(|pair| score(&pair[0]) >= score(&pair[1]))
+        );
+        assert!(score(&least[0]) >= score(&most[0]));
+        most.sort_by_key(|hint| hint.brick);
+        least.sort_by_key(|hint| hint.brick);
+        assert_eq!(least, most);
+    }
+}
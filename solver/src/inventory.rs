@@ -0,0 +1,61 @@
+use crate::Brick;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+// Keeps track of which bricks of a set are still in the box.
+#[derive(Debug, Clone)]
+pub struct Inventory<'a> {
+    bricks: &'a [Brick],
+    available: Vec<bool>,
+}
+
+impl<'a> Inventory<'a> {
+    pub fn new(bricks: &'a [Brick]) -> Inventory<'a> {
+        Inventory {
+            bricks,
+            available: vec![true; bricks.len()],
+        }
+    }
+
+    /// Takes the first unused brick that can make the placement out of the box, returning its index.
+    pub fn place(&mut self, placement: u64) -> Option<usize> {
+        let brick_index = (0..self.bricks.len())
+            .find(|&i| self.available[i] && self.bricks[i].matches(placement))?;
+        self.available[brick_index] = false;
+        Some(brick_index)
+    }
+
+    pub fn unused(&self) -> impl Iterator<Item = (usize, &'a Brick)> + '_ {
+        self.bricks
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.available[*i])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.available.contains(&true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, solve};
+
+    #[test]
+    fn solution_empties_inventory() {
+        let bricks = Brick::all_bricks();
+        let solution = solve(Board::for_date(22, 9).unwrap(), &bricks)
+            .next()
+            .unwrap();
+        let mut inventory = Inventory::new(&bricks);
+        assert_eq!(inventory.unused().count(), 8);
+        let first = inventory.place(solution.placed_bricks[0]);
+        assert_eq!(first, Some(0));
+        assert_eq!(inventory.place(solution.placed_bricks[0]), None);
+        for placement in &solution.placed_bricks[1..] {
+            assert!(inventory.place(*placement).is_some());
+        }
+        assert!(inventory.is_empty());
+    }
+}
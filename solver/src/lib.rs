@@ -1,9 +1,119 @@
-use std::collections::HashMap;
+//! Solves the calendar puzzle: covering every cell of the board but today's month and day with
+//! the bricks. The API comes in two tiers, [`stable`] and [`experimental`].
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::{BTreeMap as Map, BTreeSet as Set};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::{HashMap as Map, HashSet as Set};
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+mod audit;
+mod batch;
+mod browser;
+mod builder;
+#[cfg(feature = "std")]
+mod certificate;
+mod constraints;
+mod context;
+mod difficulty;
+pub mod dlx;
+mod error;
+#[cfg(feature = "std")]
+mod estimate;
+pub mod experimental;
+mod generator;
+mod hint_strategy;
+mod inventory;
+mod options;
+mod order;
+#[cfg(feature = "std")]
+mod parallel;
+mod placement;
+mod png;
+mod profile;
+#[cfg(feature = "std")]
+mod progress;
+mod redundancy;
+mod rng;
+mod sample;
+mod shape;
+#[cfg(feature = "std")]
+mod stability;
+pub mod stable;
+mod stats;
+#[cfg(feature = "table")]
+mod table;
+mod wide;
+
+// What the std prelude brings in, for the modules building without std.
+#[cfg(not(feature = "std"))]
+mod prelude {
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::format;
+    pub(crate) use alloc::string::String;
+    pub(crate) use alloc::vec;
+    pub(crate) use alloc::vec::Vec;
+}
+
+#[cfg(not(feature = "std"))]
+use prelude::*;
+use rng::Rng;
+use stats::Stopwatch;
+
+pub use audit::{AuditIssue, STANDARD_BRICK_CELLS, audit_brick};
+pub use batch::{DateResult, all_dates, dates_with_few_solutions, solve_dates, solve_year};
+pub use browser::SolutionBrowser;
+pub use builder::BoardBuilder;
+#[cfg(feature = "std")]
+pub use certificate::{UniquenessCertificate, has_unique_solution, uniqueness_certificate};
+pub use constraints::{Constraints, solve_with_constraints};
+pub use context::SolverContext;
+pub use difficulty::{DifficultyReport, difficulty, difficulty_with_bricks};
+pub use error::SolverError;
+#[cfg(feature = "std")]
+pub use estimate::{SolutionEstimate, estimate_solution_count};
+pub use generator::{BrickSetGenerator, GeneratorOptions, generate_brick_sets, polyominoes};
+pub use hint_strategy::{HintStrategy, hints_by};
+pub use inventory::Inventory;
+pub use options::{
+    CancellationToken, PlacementFilter, Search, SolutionScorer, SolveOptions, ranked_solutions,
+    solve_with_options,
+};
+pub use order::{DiversityOrder, FoundOrder, LexicographicOrder, SolutionOrderer, SymmetryOrder};
+#[cfg(feature = "std")]
+pub use parallel::{ParallelSearch, ThreadStats, solve_par, solve_par_threads};
+use placement::PlacementTable;
+pub use placement::{Placement, dead_placements, placements_covering};
+pub use png::{Image, Palette};
+pub use profile::SearchProfile;
+#[cfg(feature = "std")]
+use progress::ProgressReporter;
+#[cfg(feature = "std")]
+pub use progress::{Progress, SearchProgress};
+pub use redundancy::{Redundancy, brick_redundancy};
+pub use sample::{sample_solutions, solve_random};
+pub use shape::ShapeError;
+#[cfg(feature = "std")]
+pub use stability::{StablePlacement, placement_stability};
+pub use stats::SearchStats;
+#[cfg(feature = "table")]
+pub use table::table_solutions;
+pub use wide::{BitWord, Board128, WideBoard, WideBrick, solve_wide};
+
+const COLUMN_0: u64 = 0x8080_8080_8080_8080;
+const COLUMN_7: u64 = 0x0101_0101_0101_0101;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Board {
     bitboard: u64,
     pub placed_bricks: Vec<u64>,
+    // The index of the brick making each placed brick.
+    brick_indices: Vec<usize>,
 }
 
 impl Board {
@@ -11,14 +121,25 @@ impl Board {
         Board {
             bitboard: 0b00000011_00000011_00000001_00000001_00000001_00000001_00011111_11111111u64,
             placed_bricks: Vec::with_capacity(8),
+            brick_indices: Vec::with_capacity(8),
         }
     }
-    pub fn for_date(day: u8, month: u8) -> Result<Board, String> {
+
+    // The board with weekdays, which is one row taller. The row below the days ends with Sunday to
+    // Wednesday, and Thursday to Saturday are in the right of the last row.
+    fn new_weekday() -> Board {
+        Board {
+            bitboard: 0b00000011_00000011_00000001_00000001_00000001_00000001_00000001_11110001u64,
+            placed_bricks: Vec::with_capacity(10),
+            brick_indices: Vec::with_capacity(10),
+        }
+    }
+    pub fn for_date(day: u8, month: u8) -> Result<Board, SolverError> {
         let mut empty_board = Board::new();
         match month {
             1..=6 => empty_board.set_index(month - 1),
             7..=12 => empty_board.set_index(month + 1),
-            _ => return Err(format!("Invalid month {month}. Valid months: 1-12")),
+            _ => return Err(SolverError::InvalidMonth(month)),
         }
         match day {
             1..=7 => empty_board.set_index(day + 15),
@@ -26,25 +147,85 @@ impl Board {
             15..=21 => empty_board.set_index(day + 17),
             22..=28 => empty_board.set_index(day + 18),
             29..=31 => empty_board.set_index(day + 19),
-            _ => return Err(format!("Invalid day {day}. Valid days: 1-31")),
+            _ => return Err(SolverError::InvalidDay(day)),
         }
         Ok(empty_board)
     }
 
+    /// The board with weekday cells, for use with [`Brick::weekday_bricks`]. Weekdays are numbered
+    /// 1 for Monday to 7 for Sunday.
+    pub fn for_date_weekday(day: u8, month: u8, weekday: u8) -> Result<Board, SolverError> {
+        let date_cells = Board::for_date(day, month)?.bitboard & !Board::new().bitboard;
+        let mut board = Board::new_weekday();
+        board.bitboard |= date_cells;
+        match weekday {
+            1..=3 => board.set_index(weekday + 51),
+            4..=6 => board.set_index(weekday + 56),
+            7 => board.set_index(51),
+            _ => return Err(SolverError::InvalidWeekday(weekday)),
+        }
+        Ok(board)
+    }
+
     fn set_index(&mut self, index: u8) {
         self.bitboard |= 1u64 << 63 >> index;
     }
 
-    #[allow(dead_code)] // Only used in tests
-    fn is_free(&self, index: u8) -> bool {
+    /// Whether the cell at the index (row * 8 + column) is neither blocked nor covered by a brick.
+    pub fn is_free(&self, index: u8) -> bool {
         !self.is_occupied(index)
     }
-    #[allow(dead_code)] // Only used in tests
     fn is_occupied(&self, index: u8) -> bool {
         (1_u64 << 63 >> index & self.bitboard) > 0
     }
-    fn valid_placements<'a>(&'a self, brick: &'a Brick) -> ValidPlacementIterator<'a> {
-        ValidPlacementIterator::new(self, brick)
+    fn free_cells(&self) -> u32 {
+        (!self.bitboard).count_ones()
+    }
+    fn is_exact_cover(&self, bricks: &[Brick]) -> bool {
+        let unplaced = self.unplaced(bricks);
+        let area: u32 = (bricks.iter().enumerate())
+            .filter(|(brick_index, _)| unplaced & (1 << brick_index) != 0)
+            .map(|(_, brick)| brick.area())
+            .sum();
+        area == self.free_cells()
+    }
+    // The mask of the bricks not placed on the board yet.
+    fn unplaced(&self, bricks: &[Brick]) -> u32 {
+        (self.brick_indices.iter()).fold(all_bricks_mask(bricks), |mask, brick_index| {
+            mask & !(1 << brick_index)
+        })
+    }
+    fn place(&self, brick_index: usize, placement: u64) -> Board {
+        let mut placed_bricks = self.placed_bricks.clone();
+        placed_bricks.push(placement);
+        let mut brick_indices = self.brick_indices.clone();
+        brick_indices.push(brick_index);
+        Board {
+            bitboard: self.bitboard | placement,
+            placed_bricks,
+            brick_indices,
+        }
+    }
+    /// The board with the bricks already placed, like the progress on a physical puzzle. Solving it
+    /// with the full set of bricks only places the others; the solutions include the placed bricks.
+    pub fn with_placements(&self, placements: &[Placement]) -> Result<Board, SolverError> {
+        let mut board = self.clone();
+        for (i, placement) in placements.iter().enumerate() {
+            if placements[..i]
+                .iter()
+                .any(|earlier| earlier.brick == placement.brick)
+            {
+                return Err(SolverError::BrickPlacedTwice(placement.brick));
+            }
+            if board.bitboard & placement.mask != 0 {
+                return Err(SolverError::CellTaken(placement.brick));
+            }
+            board = board.place(placement.brick, placement.mask);
+        }
+        Ok(board)
+    }
+    fn valid_placements<'a>(&self, brick: &'a Brick) -> ValidPlacementIterator<'a> {
+        ValidPlacementIterator::new(self.bitboard, brick)
     }
 }
 
@@ -52,118 +233,670 @@ pub fn solve(initial_board: Board, bricks: &[Brick]) -> impl Iterator<Item = Sol
     SolveIterator::new(initial_board, bricks)
 }
 
-pub fn hints(board: Board, bricks: &[Brick]) -> Vec<Hint> {
-    let mut brick_in_solution: HashMap<u64, usize> = HashMap::new();
-    for solution in solve(board, bricks) {
-        for brick in solution.placed_bricks {
-            *brick_in_solution.entry(brick).or_default() += 1;
+// Boxed like it has been since it was added, so code naming the type keeps compiling.
+pub fn solve_with_profile<'a>(
+    initial_board: Board,
+    bricks: &'a [Brick],
+    profile: SearchProfile,
+) -> Box<dyn Iterator<Item = SolvedBoard> + 'a> {
+    Box::new(SolveIterator::with_profile(initial_board, bricks, profile))
+}
+
+// Like `solve`, calling the callback with the board being tested at most once per interval.
+#[cfg(feature = "std")]
+pub fn solve_with_progress<'a>(
+    initial_board: Board,
+    bricks: &'a [Brick],
+    interval: Duration,
+    callback: impl FnMut(&SearchProgress) + 'a,
+) -> impl Iterator<Item = SolvedBoard> + 'a {
+    let mut iterator = SolveIterator::new(initial_board, bricks);
+    iterator.progress = Some(ProgressReporter::new(interval, callback));
+    iterator
+}
+
+/// The first `n` solutions in the order [`solve`] finds them, stopping the search there.
+pub fn solve_n(initial_board: Board, bricks: &[Brick], n: usize) -> Vec<SolvedBoard> {
+    SolveIterator::new(initial_board, bricks).take(n).collect()
+}
+
+// Counts the solutions without keeping track of the placed bricks.
+pub fn count_solutions(initial_board: Board, bricks: &[Brick]) -> usize {
+    #[cfg(feature = "table")]
+    if let Some(count) = table::count(&initial_board, bricks) {
+        return count;
+    }
+    let branching = Branching::new(&initial_board, bricks, (0..bricks.len()).collect());
+    let mut stack = Vec::with_capacity(256);
+    stack.push((initial_board.bitboard, initial_board.unplaced(bricks)));
+    let mut solutions = 0;
+    while let Some((bitboard, remaining)) = stack.pop() {
+        if remaining == 0 {
+            solutions += 1;
+            continue;
+        }
+        for (brick_index, placement) in branching.children(bitboard, remaining) {
+            stack.push((bitboard | placement, remaining & !(1 << brick_index)));
         }
     }
-    let mut hints: Vec<Hint> = brick_in_solution
-        .iter()
-        .map(|(brick, solutions)| Hint {
-            brick: *brick,
-            solutions: *solutions,
-        })
+    solutions
+}
+
+// FNV-1a over the board and every brick variant, so the same puzzle gets the same checksum anywhere.
+pub fn puzzle_checksum(board: &Board, bricks: &[Brick]) -> u64 {
+    let words = core::iter::once(board.bitboard).chain(bricks.iter().flat_map(|brick| {
+        brick
+            .brick_variants
+            .iter()
+            .map(|variant| variant.bit_pattern)
+    }));
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for byte in words.flat_map(u64::to_be_bytes) {
+        hash = (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+pub fn hints(board: Board, bricks: &[Brick]) -> Vec<Hint> {
+    #[cfg(feature = "table")]
+    if let Some(solutions) = table_solutions(&board, bricks) {
+        return rank_hints(solutions.map(|solution| solution.placed_bricks));
+    }
+    hints_with_profile(board, bricks, SearchProfile::default())
+}
+
+pub fn hints_with_profile(board: Board, bricks: &[Brick], profile: SearchProfile) -> Vec<Hint> {
+    rank_hints(solve_with_profile(board, bricks, profile).map(|solution| solution.placed_bricks))
+}
+
+/// Like `hints`, searching with the options, so the search can be followed or stopped.
+pub fn hints_with_options(board: Board, bricks: &[Brick], options: &SolveOptions) -> Vec<Hint> {
+    rank_hints(solve_with_options(board, bricks, options).map(|solution| solution.placed_bricks))
+}
+
+/// Placements that are part of every solution, ranked like `hints`. These are the hints that are
+/// sure to be right, rather than likely. Empty when the board has no solutions.
+pub fn forced_placements(board: Board, bricks: &[Brick]) -> Vec<Hint> {
+    let solutions = count_solutions(board.clone(), bricks);
+    let mut forced = hints(board, bricks);
+    forced.retain(|hint| hint.solutions == solutions);
+    forced
+}
+
+// Ranks where to put the next brick, given the bricks already placed, by how many ways there are
+// to finish the puzzle from there. The bricks are the full set, the placed ones are left out.
+pub fn hints_for_partial(
+    board: Board,
+    placed: &[Placement],
+    bricks: &[Brick],
+) -> Result<Vec<Hint>, SolverError> {
+    let board = board.with_placements(placed)?;
+    let mut hints = rank_hints(solve(board, bricks).map(|solution| solution.placed_bricks));
+    hints.retain(|hint| placed.iter().all(|placement| placement.mask != hint.brick));
+    Ok(hints)
+}
+
+// Placements to pre-place for an easier puzzle. Each is the hint with the most solutions among
+// the solutions that have the placements before it, and its count is the solutions left after it.
+pub fn easy_start(board: Board, bricks: &[Brick], pieces: usize) -> Vec<Hint> {
+    let mut solutions: Vec<Vec<u64>> = solve(board, bricks)
+        .map(|solution| solution.placed_bricks)
         .collect();
-    hints.sort_unstable_by(|hint1, hint2| hint2.solutions.cmp(&hint1.solutions));
-    hints
+    let mut placed: Vec<Hint> = vec![];
+    while placed.len() < pieces {
+        let Some(hint) = rank_hints(solutions.iter().cloned())
+            .into_iter()
+            .find(|hint| placed.iter().all(|placed| placed.brick != hint.brick))
+        else {
+            break;
+        };
+        solutions.retain(|solution| solution.contains(&hint.brick));
+        placed.push(hint);
+    }
+    placed
+}
+
+// Hint rankings refined as the search goes, one after each solution found. The last ranking is
+// the same as `hints` gives.
+pub fn hints_iter<'a>(board: Board, bricks: &'a [Brick]) -> impl Iterator<Item = Vec<Hint>> + 'a {
+    let mut tally = HintTally::default();
+    solve(board, bricks).map(move |solution| {
+        tally.add(solution.placed_bricks);
+        tally.ranking()
+    })
+}
+
+/// Ranks the placements of solutions already found by how many of them each is part of, the same
+/// ranking `hints` gives when the solutions are all of the board's.
+pub fn hints_from_solutions(solutions: impl IntoIterator<Item = Vec<u64>>) -> Vec<Hint> {
+    rank_hints(solutions.into_iter())
 }
 
+fn rank_hints(solutions: impl Iterator<Item = Vec<u64>>) -> Vec<Hint> {
+    let mut tally = HintTally::default();
+    for solution in solutions {
+        tally.add(solution);
+    }
+    tally.ranking()
+}
+
+// How many of the solutions so far each placement is part of.
+#[derive(Default)]
+struct HintTally {
+    brick_in_solution: Map<u64, usize>,
+}
+
+impl HintTally {
+    fn add(&mut self, solution: Vec<u64>) {
+        for brick in solution {
+            *self.brick_in_solution.entry(brick).or_default() += 1;
+        }
+    }
+
+    fn ranking(&self) -> Vec<Hint> {
+        let mut hints: Vec<Hint> = self
+            .brick_in_solution
+            .iter()
+            .map(|(brick, solutions)| Hint {
+                brick: *brick,
+                solutions: *solutions,
+            })
+            .collect();
+        hints.sort_unstable_by_key(|hint| core::cmp::Reverse(hint.solutions));
+        hints
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Hint {
     pub brick: u64,
     pub solutions: usize,
 }
 
+/// A quarter of the board, split through the middle row and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Quadrant {
+    /// Whether the cell at the index (row * 8 + column) is in the quadrant. The middle row and
+    /// column belong to both sides.
+    pub fn contains(self, index: u8) -> bool {
+        let (row, column) = (index / 8, index % 8);
+        let top = matches!(self, Quadrant::TopLeft | Quadrant::TopRight);
+        let left = matches!(self, Quadrant::TopLeft | Quadrant::BottomLeft);
+        (if top { row <= 3 } else { row >= 3 }) && (if left { column <= 3 } else { column >= 3 })
+    }
+}
+
+impl Hint {
+    /// Index of the brick that makes the hint's placement, the first one with its shape.
+    pub fn brick_index(&self, bricks: &[Brick]) -> Option<usize> {
+        bricks.iter().position(|brick| brick.matches(self.brick))
+    }
+
+    /// The quadrant the middle of the placement is in, for a hint that only says roughly where
+    /// the brick goes. A placement centred on the middle row or column counts as top or left.
+    pub fn quadrant(&self) -> Quadrant {
+        let cells = (0..64u32).filter(|index| self.brick & 1 << 63 >> index != 0);
+        let (rows, columns, count) = cells.fold((0, 0, 0), |(rows, columns, count), index| {
+            (rows + index / 8, columns + index % 8, count + 1)
+        });
+        match (rows <= 3 * count, columns <= 3 * count) {
+            (true, true) => Quadrant::TopLeft,
+            (true, false) => Quadrant::TopRight,
+            (false, true) => Quadrant::BottomLeft,
+            (false, false) => Quadrant::BottomRight,
+        }
+    }
+}
+
+// Placements of one brick on a board. When anchored on a cell, only placements whose first cell is
+// the anchor are tried, which are all the placements covering it when every cell before it is taken.
 struct ValidPlacementIterator<'a> {
     index: usize,
-    brick_index: usize,
-    board: &'a Board,
+    variant_index: usize,
+    bitboard: u64,
     brick: &'a Brick,
+    anchor: Option<u32>,
+    // Placements worked out before, to pick the ones that still fit from instead.
+    listed: Option<&'a [u64]>,
 }
 
 impl ValidPlacementIterator<'_> {
-    fn new<'a>(board: &'a Board, brick: &'a Brick) -> ValidPlacementIterator<'a> {
+    fn new(bitboard: u64, brick: &Brick) -> ValidPlacementIterator<'_> {
         ValidPlacementIterator {
             index: 0,
-            brick_index: 0,
-            board,
+            variant_index: 0,
+            bitboard,
             brick,
+            anchor: None,
+            listed: None,
+        }
+    }
+    fn anchored(bitboard: u64, brick: &Brick, anchor: u32) -> ValidPlacementIterator<'_> {
+        ValidPlacementIterator {
+            anchor: Some(anchor),
+            ..ValidPlacementIterator::new(bitboard, brick)
+        }
+    }
+    fn listed<'a>(
+        bitboard: u64,
+        brick: &'a Brick,
+        placements: &'a [u64],
+    ) -> ValidPlacementIterator<'a> {
+        ValidPlacementIterator {
+            listed: Some(placements),
+            ..ValidPlacementIterator::new(bitboard, brick)
         }
     }
 }
 
 impl Iterator for ValidPlacementIterator<'_> {
-    type Item = Board;
+    type Item = u64;
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
-        while self.brick_index < self.brick.brick_variants.len() {
-            let brick_variant = self.brick.brick_variants.get(self.brick_index)?;
-            while self.index <= 42_usize {
+        if let Some(placements) = self.listed {
+            while let Some(&placement) = placements.get(self.index) {
+                self.index += 1;
+                if self.bitboard & placement == 0 {
+                    return Some(placement);
+                }
+            }
+            return None;
+        }
+        while let Some(brick_variant) = self.brick.brick_variants.get(self.variant_index) {
+            if let Some(anchor) = self.anchor {
+                self.variant_index += 1;
+                let first_cell = brick_variant.bit_pattern.leading_zeros();
+                if first_cell > anchor {
+                    continue;
+                }
+                let placement = brick_variant.bit_pattern >> (anchor - first_cell);
+                // Cells shifted past the last row are lost, so those placements are not real.
+                if placement.count_ones() == brick_variant.bit_pattern.count_ones()
+                    && self.bitboard & placement == 0
+                {
+                    return Some(placement);
+                }
+                continue;
+            }
+            // Shifting further would push cells past the last row.
+            while self.index <= brick_variant.bit_pattern.trailing_zeros() as usize {
                 let indexed_brick_pattern = brick_variant.bit_pattern >> self.index;
                 self.index += 1;
-                if (self.board.bitboard & indexed_brick_pattern) == 0 {
-                    let mut placed_bricks = self.board.placed_bricks.clone();
-                    placed_bricks.push(indexed_brick_pattern);
-                    return Some(Board {
-                        bitboard: self.board.bitboard | indexed_brick_pattern,
-                        placed_bricks,
-                    });
+                if (self.bitboard & indexed_brick_pattern) == 0 {
+                    return Some(indexed_brick_pattern);
                 }
             }
             self.index = 0;
-            self.brick_index += 1;
+            self.variant_index += 1;
         }
         None
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct SolvedBoard {
     pub placed_bricks: Vec<u64>,
-    pub test_count: u32,
+    /// The index of the brick making each placed brick, in the same order.
+    pub bricks: Vec<usize>,
+    pub stats: SearchStats,
+}
+
+impl SolvedBoard {
+    #[deprecated(note = "use `stats.test_count`, the field this used to be")]
+    pub fn test_count(&self) -> u64 {
+        self.stats.test_count
+    }
+
+    /// The board as rows of cells, top to bottom, without the blocked column at the end of each
+    /// row. A cell is the number of the brick covering it (1-8, its index in the bricks plus
+    /// one), or 0 when uncovered. The last row is only used by the board with weekdays.
+    pub fn to_grid(&self) -> [[u8; 7]; 8] {
+        let mut grid = [[0; 7]; 8];
+        for (&brick, &placement) in self.bricks.iter().zip(&self.placed_bricks) {
+            for (row, cells) in grid.iter_mut().enumerate() {
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    if placement & 1 << 63 >> (row * 8 + column) != 0 {
+                        *cell = brick as u8 + 1;
+                    }
+                }
+            }
+        }
+        grid
+    }
 }
 
+/// The grid as text, one line per row with the brick numbers and `.` for uncovered cells. Rows
+/// below the last brick are left out.
+impl fmt::Display for SolvedBoard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = self.to_grid();
+        let rows = grid
+            .iter()
+            .rposition(|row| row.iter().any(|&cell| cell != 0))
+            .map_or(0, |last| last + 1);
+        for row in &grid[..rows] {
+            for &cell in row {
+                match cell {
+                    0 => write!(f, ".")?,
+                    brick => write!(f, "{brick}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Depth first search over boards and the bricks still to place. When every free cell has to be
+// covered, each step only tries the placements covering the first free cell, and branches leaving
+// regions none of the remaining bricks can fill are dropped. Otherwise the bricks are placed in order.
 struct SolveIterator<'a> {
-    stack: Vec<(Board, &'a [Brick])>,
-    test_count: u32,
+    stack: Vec<(Board, u32)>,
+    branching: Branching<'a>,
+    rng: Option<Rng>,
+    #[cfg(feature = "std")]
+    progress: Option<ProgressReporter<'a>>,
+    // Checked at every node, ending the search once stopped.
+    options: Option<&'a SolveOptions>,
+    // Whether the options ended the search before it was done.
+    truncated: bool,
+    solutions: usize,
+    stats: SearchStats,
+    started: Option<Stopwatch>,
+    // The nodes whose children are still being explored, as the stack length before their children,
+    // their (bitboard, remaining bricks) state and the solutions found before them.
+    open: Vec<(usize, (u64, u32), usize)>,
+    // States known to lead to no solution, so reaching them again some other way is skipped.
+    dead: Set<(u64, u32)>,
 }
 
 impl<'a> SolveIterator<'a> {
     fn new(board: Board, bricks: &'a [Brick]) -> Self {
+        SolveIterator::with_order(board, bricks, (0..bricks.len()).collect())
+    }
+
+    fn with_order(board: Board, bricks: &'a [Brick], order: Vec<usize>) -> Self {
+        let branching = Branching::new(&board, bricks, order);
         let mut initial_stack = Vec::with_capacity(256);
-        initial_stack.push((board, bricks));
+        let unplaced = board.unplaced(bricks);
+        initial_stack.push((board, unplaced));
         SolveIterator {
             stack: initial_stack,
-            test_count: 0,
+            branching,
+            rng: None,
+            #[cfg(feature = "std")]
+            progress: None,
+            options: None,
+            truncated: false,
+            solutions: 0,
+            stats: SearchStats::default(),
+            started: None,
+            open: vec![],
+            dead: Set::new(),
         }
     }
+
+    fn with_profile(board: Board, bricks: &'a [Brick], profile: SearchProfile) -> Self {
+        let mut iterator = SolveIterator::with_order(board, bricks, profile.brick_order(bricks));
+        iterator.branching.fail_first = profile == SearchProfile::FailFirst;
+        iterator
+    }
+
+    // Records the nodes whose children have all been explored without finding a solution as dead.
+    fn close_explored(&mut self) {
+        while let Some(&(children_start, state, solutions)) = self.open.last() {
+            if self.stack.len() > children_start {
+                break;
+            }
+            self.open.pop();
+            if self.solutions == solutions {
+                self.dead.insert(state);
+                self.stats.dead_states += 1;
+            }
+        }
+    }
+
+    // Updates the progress handle of the options, if any.
+    #[cfg(feature = "std")]
+    fn publish_progress(&self, depth: usize) {
+        if let Some(progress) = self.options.and_then(|options| options.progress.as_ref()) {
+            progress.update(depth, self.stats.test_count, self.solutions);
+        }
+    }
+
+    // Shuffles the candidates at every node instead of following the brick order.
+    fn randomized(board: Board, bricks: &'a [Brick], seed: u64) -> Self {
+        let mut iterator = SolveIterator::new(board, bricks);
+        iterator.rng = Some(Rng::new(seed));
+        iterator
+    }
+}
+
+// How the search branches from a node: which bricks to try, in which order, and where.
+struct Branching<'a> {
+    bricks: &'a [Brick],
+    order: Vec<usize>,
+    anchored: bool,
+    // Whether to try the bricks with the fewest placements on the board first, instead of in order.
+    fail_first: bool,
+    filters: &'a [Box<dyn PlacementFilter>],
+    // Placements worked out for the board the search starts from, if any.
+    table: Option<&'a PlacementTable>,
+}
+
+impl<'a> Branching<'a> {
+    fn new(board: &Board, bricks: &'a [Brick], order: Vec<usize>) -> Self {
+        assert!(bricks.len() <= 32, "At most 32 bricks are supported");
+        Branching {
+            bricks,
+            order,
+            anchored: board.is_exact_cover(bricks),
+            fail_first: false,
+            filters: &[],
+            table: None,
+        }
+    }
+
+    // The (brick index, placement) pairs to try next, in the order they should be tried.
+    fn children(&self, bitboard: u64, remaining: u32) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.candidates(bitboard, remaining)
+            .filter(move |&(brick_index, placement)| {
+                self.keeps(bitboard, remaining, brick_index, placement)
+            })
+    }
+
+    // The placements that fit, before pruning.
+    fn candidates(&self, bitboard: u64, remaining: u32) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let bricks = self.bricks;
+        let anchor = self.anchored.then(|| (!bitboard).leading_zeros());
+        let table = self.table;
+        let placements = move |brick_index: usize| {
+            let brick = &bricks[brick_index];
+            match (table, anchor) {
+                (Some(table), Some(anchor)) => ValidPlacementIterator::listed(
+                    bitboard,
+                    brick,
+                    table.starting_at(brick_index, anchor),
+                ),
+                (Some(table), None) => {
+                    ValidPlacementIterator::listed(bitboard, brick, &table.all()[brick_index])
+                }
+                (None, Some(anchor)) => ValidPlacementIterator::anchored(bitboard, brick, anchor),
+                (None, None) => ValidPlacementIterator::new(bitboard, brick),
+            }
+        };
+        // At most 32 bricks, so the order fits on the stack.
+        let mut order = [0; 32];
+        let mut count = 0;
+        for &brick_index in &self.order {
+            if remaining & (1 << brick_index) != 0 {
+                order[count] = brick_index;
+                count += 1;
+            }
+        }
+        if self.fail_first {
+            order[..count].sort_by_cached_key(|&brick_index| placements(brick_index).count());
+        }
+        (0..count)
+            .map(move |position| order[position])
+            .take(match anchor {
+                None => 1,
+                Some(64) => 0,
+                Some(_) => usize::MAX,
+            })
+            .flat_map(move |brick_index| {
+                placements(brick_index).map(move |placement| (brick_index, placement))
+            })
+    }
+
+    // Whether the placement passes the filters and, when every cell has to be covered, leaves no
+    // region the remaining bricks can't fill.
+    fn keeps(&self, bitboard: u64, remaining: u32, brick_index: usize, placement: u64) -> bool {
+        let rest = remaining & !(1 << brick_index);
+        self.filters
+            .iter()
+            .all(|filter| filter.allows(bitboard, brick_index, placement))
+            && (!self.anchored
+                || !has_dead_region(bitboard | placement, composable_areas(self.bricks, rest)))
+    }
 }
 
 impl<'a> Iterator for SolveIterator<'a> {
     type Item = SolvedBoard;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some((current_board, bricks)) = self.stack.pop() {
-            self.test_count += 1;
-            if bricks.is_empty() {
+        loop {
+            self.close_explored();
+            let (current_board, remaining) = self.stack.pop()?;
+            if self.options.is_some_and(|options| {
+                options.is_stopped()
+                    || options
+                        .max_tests
+                        .is_some_and(|max_tests| self.stats.test_count >= max_tests)
+            }) {
+                self.stack.clear();
+                self.open.clear();
+                self.truncated = true;
+                return None;
+            }
+            if self.dead.contains(&(current_board.bitboard, remaining)) {
+                self.stats.dead_state_hits += 1;
+                continue;
+            }
+            let started = *self.started.get_or_insert_with(Stopwatch::start);
+            let depth = current_board.placed_bricks.len();
+            self.stats.test_count += 1;
+            self.stats.max_depth = self.stats.max_depth.max(depth);
+            #[cfg(feature = "std")]
+            if let Some(progress) = &mut self.progress {
+                progress.report(
+                    &current_board.placed_bricks,
+                    self.stats.test_count,
+                    self.solutions,
+                );
+            }
+            #[cfg(feature = "std")]
+            self.publish_progress(depth);
+            if remaining == 0 {
+                self.solutions += 1;
+                #[cfg(feature = "std")]
+                self.publish_progress(depth);
+                let elapsed = started.elapsed();
+                self.stats.solution_time = elapsed.saturating_sub(self.stats.elapsed);
+                self.stats.elapsed = elapsed;
                 return Some(SolvedBoard {
                     placed_bricks: current_board.placed_bricks,
-                    test_count: self.test_count,
+                    bricks: current_board.brick_indices,
+                    stats: self.stats,
                 });
             }
 
-            if let Some((brick, remaining)) = bricks.split_first() {
-                let valid_placements = current_board.valid_placements(brick);
-                for valid_placement in valid_placements {
-                    self.stack.push((valid_placement, remaining));
+            let children_start = self.stack.len();
+            for (brick_index, placement) in
+                (self.branching).candidates(current_board.bitboard, remaining)
+            {
+                self.stats.placements_generated += 1;
+                if !(self.branching).keeps(
+                    current_board.bitboard,
+                    remaining,
+                    brick_index,
+                    placement,
+                ) {
+                    self.stats.prunes += 1;
+                    continue;
                 }
+                self.stack.push((
+                    current_board.place(brick_index, placement),
+                    remaining & !(1 << brick_index),
+                ));
             }
+            if self.stack.len() == children_start {
+                self.stats.backtracks += 1;
+            }
+            self.open.push((
+                children_start,
+                (current_board.bitboard, remaining),
+                self.solutions,
+            ));
+            // Reversed so the first child is popped first.
+            self.stack[children_start..].reverse();
+            if let Some(rng) = &mut self.rng {
+                rng.shuffle(&mut self.stack[children_start..]);
+            }
+        }
+    }
+}
+
+fn all_bricks_mask(bricks: &[Brick]) -> u32 {
+    ((1u64 << bricks.len()) - 1) as u32
+}
+
+// Region sizes the bricks in the mask can fill together.
+fn composable_areas(bricks: &[Brick], mask: u32) -> u128 {
+    bricks
+        .iter()
+        .enumerate()
+        .filter(|(brick_index, _)| mask & (1 << brick_index) != 0)
+        .fold(1, |sums, (_, brick)| sums | sums << brick.area())
+}
+
+// Whether some connected area of free cells has a size none of the remaining bricks add up to.
+fn has_dead_region(bitboard: u64, composable_areas: u128) -> bool {
+    let mut free = !bitboard;
+    while free != 0 {
+        let region = connected_region(free & free.wrapping_neg(), free);
+        if composable_areas & (1 << region.count_ones()) == 0 {
+            return true;
         }
+        free &= !region;
+    }
+    false
+}
 
-        None
+// The cells of `cells` connected to `seed`.
+fn connected_region(seed: u64, cells: u64) -> u64 {
+    let mut region = seed;
+    loop {
+        let grown = (region
+            | region << 8
+            | region >> 8
+            | (region << 1) & !COLUMN_7
+            | (region >> 1) & !COLUMN_0)
+            & cells;
+        if grown == region {
+            return region;
+        }
+        region = grown;
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 struct BrickVariant {
     bit_pattern: u64,
 }
@@ -174,7 +907,7 @@ impl BrickVariant {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Brick {
     brick_variants: Box<[BrickVariant]>,
 }
@@ -183,6 +916,41 @@ impl Brick {
     fn new(brick_variants: Box<[BrickVariant]>) -> Brick {
         Brick { brick_variants }
     }
+    /// A brick in every rotation and reflection of the shape. The shape is a pattern of cells
+    /// anywhere on the board. Panics if the shape is empty.
+    pub fn from_shape(shape: u64) -> Brick {
+        assert!(shape != 0, "A brick needs at least one cell");
+        Brick::new(
+            shape::orientations(shape)
+                .into_iter()
+                .map(BrickVariant::new)
+                .collect(),
+        )
+    }
+    /// A brick in every rotation and reflection of the shape drawn by the rows, with X or # for
+    /// cells and . or space for gaps, like `Brick::from_ascii(&["XX.", ".XX"])`.
+    pub fn from_ascii(rows: &[&str]) -> Result<Brick, ShapeError> {
+        shape::parse_ascii(rows).map(Brick::from_shape)
+    }
+    fn area(&self) -> u32 {
+        self.brick_variants[0].bit_pattern.count_ones()
+    }
+    /// The brick in its first orientation, placed in the top left corner.
+    pub fn shape(&self) -> u64 {
+        self.brick_variants[0].bit_pattern
+    }
+    /// Whether the placement is one of the variants of this brick moved somewhere on the board.
+    pub fn matches(&self, placement: u64) -> bool {
+        self.brick_variants.iter().any(|variant| {
+            let first_cell = variant.bit_pattern.leading_zeros();
+            let placement_first_cell = placement.leading_zeros();
+            placement_first_cell >= first_cell
+                && variant.bit_pattern >> (placement_first_cell - first_cell) == placement
+        })
+    }
+    /// The eight bricks of the standard puzzle. Their variants are written out in the order the
+    /// search has always tried them, which the order of the solutions and the search counts depend
+    /// on. Other bricks get theirs generated by `from_shape`.
     pub fn all_bricks() -> Box<[Brick]> {
         Box::new([
             Brick::new(Box::new([
@@ -249,12 +1017,67 @@ impl Brick {
             ])),
         ])
     }
+    /// The bricks of the board with weekdays: three tetrominoes and seven pentominoes.
+    pub fn weekday_bricks() -> Box<[Brick]> {
+        [
+            ["XXXX"].as_slice(),
+            &["XXX", "X.."],
+            &["XX.", ".XX"],
+            &["XXXX", "X..."],
+            &["XX.", ".XX", "..X"],
+            &["XXX", "X.X"],
+            &["XXX", "XX."],
+            &["XXX.", "..XX"],
+            &["X..", "XXX", "..X"],
+            &["XXX", ".X.", ".X."],
+        ]
+        .iter()
+        .map(|rows| Brick::from_ascii(rows).unwrap())
+        .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{println, string::ToString};
+
+    #[test]
+    fn first_n_solutions() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let first: Vec<Vec<u64>> = solve(board.clone(), &bricks)
+            .take(2)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        let solutions = solve_n(board.clone(), &bricks, 2);
+        assert_eq!(solutions.len(), 2);
+        assert_eq!(solutions[1].placed_bricks, first[1]);
+        assert_eq!(solve_n(board, &bricks, 100).len(), 29);
+    }
+
+    #[test]
+    fn solution_grid() {
+        let bricks = Brick::all_bricks();
+        let solution = solve(Board::for_date(1, 1).unwrap(), &bricks)
+            .next()
+            .unwrap();
+        let grid = solution.to_grid();
+        assert_eq!(grid[0][0], 0);
+        assert_eq!(grid[2][0], 0);
+        assert_eq!(grid[0][6], 0);
+        assert_eq!(grid[7], [0; 7]);
+        let covered = grid.iter().flatten().filter(|&&cell| cell != 0).count();
+        assert_eq!(covered, 41);
+        let text = solution.to_string();
+        assert_eq!(text.lines().count(), 7);
+        assert!(text.starts_with('.'));
+        assert_eq!(text.lines().nth(6).unwrap().len(), 7);
+    }
 
     #[test]
     fn initial_empty_board() {
@@ -302,15 +1125,222 @@ mod tests {
         assert_eq!(placement_counter, 961);
     }
 
+    #[test]
+    fn dead_region_detection() {
+        let mut board = Board::new();
+        // Enclose cell 0 by filling the cells to the right of and below it.
+        board.set_index(1);
+        board.set_index(8);
+        assert!(has_dead_region(board.bitboard, 1 | 1 << 5 | 1 << 6));
+        assert!(!has_dead_region(Board::new().bitboard, 1 << 43));
+        assert!(has_dead_region(Board::new().bitboard, 1 << 41));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn progress_reports_every_node_without_interval() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let mut reports = 0;
+        let mut max_depth = 0;
+        let solutions = solve_with_progress(board, &bricks, Duration::ZERO, |progress| {
+            reports += 1;
+            max_depth = max_depth.max(progress.depth());
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(solutions.len(), 29);
+        assert!(reports >= solutions.last().unwrap().stats.test_count);
+        assert_eq!(max_depth, 8);
+    }
+
+    #[test]
+    fn count_matches_solve() {
+        for (day, month) in [(1, 1), (29, 7), (22, 9)] {
+            let board = Board::for_date(day, month).unwrap();
+            let bricks = Brick::all_bricks();
+            assert_eq!(
+                count_solutions(board.clone(), &bricks),
+                solve(board, &bricks).count()
+            );
+        }
+    }
+
+    #[test]
+    fn checksum_depends_on_date_and_bricks() {
+        let bricks = Brick::all_bricks();
+        let jan_1 = puzzle_checksum(&Board::for_date(1, 1).unwrap(), &bricks);
+        assert_eq!(
+            jan_1,
+            puzzle_checksum(&Board::for_date(1, 1).unwrap(), &bricks)
+        );
+        assert_ne!(
+            jan_1,
+            puzzle_checksum(&Board::for_date(2, 1).unwrap(), &bricks)
+        );
+        assert_ne!(
+            jan_1,
+            puzzle_checksum(&Board::for_date(1, 1).unwrap(), &bricks[1..])
+        );
+    }
+
+    #[test]
+    fn hints_iter_ends_with_hints() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let rankings: Vec<Vec<Hint>> = hints_iter(board.clone(), &bricks).collect();
+        assert_eq!(rankings.len(), 29);
+        assert_eq!(rankings[0].len(), bricks.len());
+        let last = rankings.last().unwrap();
+        let all = hints(board, &bricks);
+        assert_eq!(last.len(), all.len());
+        assert_eq!(last[0].solutions, all[0].solutions);
+    }
+
+    #[test]
+    fn easy_start_keeps_most_solutions() {
+        let board = Board::for_date(29, 7).unwrap();
+        let bricks = Brick::all_bricks();
+        let placed = easy_start(board.clone(), &bricks, 3);
+        assert_eq!(placed.len(), 3);
+        assert_eq!(placed[0].solutions, hints(board, &bricks)[0].solutions);
+        assert!(
+            placed
+                .windows(2)
+                .all(|pair| pair[0].solutions >= pair[1].solutions)
+        );
+        let covered = placed.iter().fold(0, |covered, hint| covered | hint.brick);
+        let areas: u32 = placed.iter().map(|hint| hint.brick.count_ones()).sum();
+        assert_eq!(covered.count_ones(), areas);
+    }
+
+    #[test]
+    fn hint_brick_and_quadrant() {
+        let bricks = Brick::all_bricks();
+        for hint in hints(Board::for_date(22, 9).unwrap(), &bricks) {
+            let brick_index = hint.brick_index(&bricks).unwrap();
+            assert!(bricks[brick_index].matches(hint.brick));
+            let quadrant = hint.quadrant();
+            assert!(
+                (0..64).any(|index| hint.brick & 1 << 63 >> index != 0 && quadrant.contains(index))
+            );
+        }
+        let top_left = Hint {
+            brick: 0b11100000_11000000 << 48,
+            solutions: 1,
+        };
+        assert_eq!(top_left.quadrant(), Quadrant::TopLeft);
+        let bottom_right = Hint {
+            brick: top_left.brick >> (4 * 8 + 4),
+            solutions: 1,
+        };
+        assert_eq!(bottom_right.quadrant(), Quadrant::BottomRight);
+    }
+
+    #[test]
+    fn forced_placements_are_in_every_solution() {
+        let bricks = Brick::all_bricks();
+        // April 6 is the only date of the year with a forced placement.
+        for (month, day) in [(1, 1), (9, 22), (4, 6)] {
+            let board = Board::for_date(day, month).unwrap();
+            let solutions: Vec<SolvedBoard> = solve(board.clone(), &bricks).collect();
+            let forced = forced_placements(board, &bricks);
+            for hint in &forced {
+                assert_eq!(hint.solutions, solutions.len());
+            }
+            let in_every = solutions[0]
+                .placed_bricks
+                .iter()
+                .filter(|brick| {
+                    solutions
+                        .iter()
+                        .all(|solution| solution.placed_bricks.contains(brick))
+                })
+                .count();
+            assert_eq!(forced.len(), in_every);
+        }
+    }
+
+    #[test]
+    fn hints_for_partial_follow_placed_bricks() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let solution = solve(board.clone(), &bricks).next().unwrap();
+        let placements = solution.placements(&bricks).unwrap();
+        let hints = hints_for_partial(board.clone(), &placements[..3], &bricks).unwrap();
+        assert!(hints.iter().all(|hint| hint.solutions >= 1));
+        assert!(hints.iter().any(|hint| hint.brick == placements[3].mask));
+        assert!(
+            hints_for_partial(board.clone(), &placements[..7], &bricks)
+                .unwrap()
+                .len()
+                == 1
+        );
+        let twice = [placements[0], placements[0]];
+        assert_eq!(
+            hints_for_partial(board, &twice, &bricks).err(),
+            Some(SolverError::BrickPlacedTwice(placements[0].brick))
+        );
+    }
+
+    #[test]
+    fn solve_with_placed_bricks() {
+        let board = Board::for_date(1, 1).unwrap();
+        let bricks = Brick::all_bricks();
+        let solution = solve(board.clone(), &bricks).last().unwrap();
+        let placements = solution.placements(&bricks).unwrap();
+        let partial = board.with_placements(&placements[..5]).unwrap();
+        let finished: Vec<SolvedBoard> = solve(partial.clone(), &bricks).collect();
+        assert_eq!(dlx::solve(partial, &bricks).count(), finished.len());
+        assert!(
+            finished
+                .iter()
+                .all(|finished| finished.placed_bricks.len() == 8)
+        );
+        let mut expected = solution.placed_bricks.clone();
+        expected.sort();
+        assert!(finished.iter().any(|finished| {
+            let mut placed = finished.placed_bricks.clone();
+            placed.sort();
+            placed == expected
+        }));
+        assert_eq!(
+            board.with_placements(&[placements[0], placements[0]]),
+            Err(SolverError::BrickPlacedTwice(placements[0].brick))
+        );
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert_eq!(Board::for_date(32, 1), Err(SolverError::InvalidDay(32)));
+        assert_eq!(Board::for_date(1, 13), Err(SolverError::InvalidMonth(13)));
+        assert_eq!(
+            Board::for_date_weekday(1, 1, 0),
+            Err(SolverError::InvalidWeekday(0))
+        );
+    }
+
+    #[test]
+    fn weekday_board() {
+        let board = Board::for_date_weekday(1, 1, 1).unwrap();
+        assert_eq!(board.free_cells(), 47);
+        assert!(board.is_free(51) && board.is_free(60) && !board.is_free(52));
+        assert!(Board::for_date_weekday(1, 1, 7).unwrap().is_free(52));
+        assert!(Board::for_date_weekday(1, 1, 8).is_err());
+        let bricks = Brick::weekday_bricks();
+        assert!(board.is_exact_cover(&bricks));
+        let solution = solve(board, &bricks).next().unwrap();
+        assert_eq!(solution.placed_bricks.len(), 10);
+    }
+
     #[test]
     fn solve_jan_1() {
         let board = Board::for_date(1, 1).unwrap(); // January 1st.
         let solutions = solve(board, &Brick::all_bricks()).collect::<Vec<_>>();
         assert_eq!(solutions.len(), 64);
         assert!(
-            solutions.last().unwrap().test_count <= 3_139_899,
+            solutions.last().unwrap().stats.test_count <= 9_735,
             "Regression, used {} tests",
-            solutions.last().unwrap().test_count
+            solutions.last().unwrap().stats.test_count
         );
     }
 
@@ -320,22 +1350,77 @@ mod tests {
         let solutions = solve(board, &Brick::all_bricks()).collect::<Vec<_>>();
         assert_eq!(solutions.len(), 77);
         assert!(
-            solutions.last().unwrap().test_count <= 3_157_070,
+            solutions.last().unwrap().stats.test_count <= 15_063,
             "Regression, used {} tests",
-            solutions.last().unwrap().test_count
+            solutions.last().unwrap().stats.test_count
         );
     }
 
     #[test]
     fn solve_sep_22() {
-        let board = Board::for_date(22, 9).unwrap(); // December 31st.
+        let board = Board::for_date(22, 9).unwrap(); // September 22nd.
         let solutions = solve(board, &Brick::all_bricks()).collect::<Vec<_>>();
         assert_eq!(solutions.len(), 29);
+        // Covering the first free cell takes more boards here than trying every placement of the
+        // next brick did (5_670), though fewer on most dates. No brick order gets it back.
         assert!(
-            solutions.last().unwrap().test_count <= 1_306_469,
+            solutions.last().unwrap().stats.test_count <= 6_353,
             "Regression, used {} tests",
-            solutions.last().unwrap().test_count
+            solutions.last().unwrap().stats.test_count
+        );
+    }
+
+    #[test]
+    fn profiles_find_same_solutions() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let sorted = |solutions: Vec<SolvedBoard>| {
+            let mut solutions: Vec<Vec<u64>> = solutions
+                .into_iter()
+                .map(|solution| {
+                    let mut placed = solution.placed_bricks;
+                    placed.sort_unstable();
+                    placed
+                })
+                .collect();
+            solutions.sort_unstable();
+            solutions
+        };
+        let exhaustive = solve(board.clone(), &bricks).collect::<Vec<_>>();
+        let interactive = solve_with_profile(board.clone(), &bricks, SearchProfile::Interactive)
+            .collect::<Vec<_>>();
+        let fail_first = solve_with_profile(board.clone(), &bricks, SearchProfile::FailFirst)
+            .collect::<Vec<_>>();
+        assert_eq!(sorted(interactive), sorted(exhaustive.clone()));
+        assert_eq!(sorted(fail_first), sorted(exhaustive.clone()));
+        // Interactive is tuned over all dates, not for each one.
+        let first_tests = |profile| {
+            all_dates()
+                .map(|(month, day)| {
+                    let board = Board::for_date(day, month).unwrap();
+                    let first = solve_with_profile(board, &bricks, profile).next().unwrap();
+                    first.stats.test_count
+                })
+                .sum::<u64>()
+        };
+        assert!(first_tests(SearchProfile::Interactive) < first_tests(SearchProfile::Exhaustive));
+
+        // Without every cell to cover, the bricks are placed one at a time, so trying the one
+        // with the fewest placements first cuts the search short.
+        let placed: Vec<Placement> = exhaustive[0]
+            .placements(&bricks)
+            .unwrap()
+            .into_iter()
+            .filter(|placement| placement.brick < 2)
+            .collect();
+        let board = board.with_placements(&placed).unwrap();
+        let in_order = solve(board.clone(), &bricks[..7]).collect::<Vec<_>>();
+        let fail_first =
+            solve_with_profile(board, &bricks[..7], SearchProfile::FailFirst).collect::<Vec<_>>();
+        assert!(
+            fail_first.last().unwrap().stats.test_count < in_order.last().unwrap().stats.test_count
         );
+        assert_eq!(sorted(fail_first), sorted(in_order));
     }
 
     #[test]
@@ -0,0 +1,292 @@
+#[cfg(feature = "std")]
+use crate::Progress;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, PlacementTable, SearchProfile, SearchStats, SolveIterator, SolvedBoard};
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Decides whether the search may place a brick somewhere.
+///
+/// Called for every candidate placement that fits on the board, before the search recurses into
+/// it, so it's on the hot path: keep it cheap and free of allocations. It has to give the same
+/// answer for the same arguments, since the search may ask more than once.
+pub trait PlacementFilter {
+    /// `bitboard` has the cells covered so far, `placement` the cells the brick would cover.
+    fn allows(&self, bitboard: u64, brick: usize, placement: u64) -> bool;
+}
+
+impl<F: Fn(u64, usize, u64) -> bool> PlacementFilter for F {
+    fn allows(&self, bitboard: u64, brick: usize, placement: u64) -> bool {
+        self(bitboard, brick, placement)
+    }
+}
+
+/// Rates a solution, higher is better. Called once per solution found.
+pub trait SolutionScorer {
+    fn score(&self, solution: &SolvedBoard) -> i64;
+}
+
+impl<F: Fn(&SolvedBoard) -> i64> SolutionScorer for F {
+    fn score(&self, solution: &SolvedBoard) -> i64 {
+        self(solution)
+    }
+}
+
+/// Stops a search running with [`SolveOptions::cancel_token`], for example from another thread.
+/// Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Default)]
+pub struct SolveOptions {
+    pub profile: SearchProfile,
+    #[cfg(feature = "std")]
+    pub deadline: Option<Instant>,
+    /// The most boards the search tests, for a bound that is the same on every run.
+    pub max_tests: Option<u64>,
+    pub(crate) filters: Vec<Box<dyn PlacementFilter>>,
+    scorers: Vec<Box<dyn SolutionScorer>>,
+    cancel: Option<CancellationToken>,
+    #[cfg(feature = "std")]
+    pub(crate) progress: Option<Progress>,
+}
+
+impl SolveOptions {
+    pub fn new() -> SolveOptions {
+        SolveOptions::default()
+    }
+
+    pub fn profile(mut self, profile: SearchProfile) -> SolveOptions {
+        self.profile = profile;
+        self
+    }
+
+    /// The search ends at the deadline, after the solutions found so far.
+    #[cfg(feature = "std")]
+    pub fn deadline(mut self, deadline: Instant) -> SolveOptions {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// The search ends after testing this many boards, after the solutions found so far.
+    pub fn max_tests(mut self, max_tests: u64) -> SolveOptions {
+        self.max_tests = Some(max_tests);
+        self
+    }
+
+    /// Like `deadline`, counting from now.
+    #[cfg(feature = "std")]
+    pub fn time_budget(self, budget: Duration) -> SolveOptions {
+        self.deadline(Instant::now() + budget)
+    }
+
+    /// The search ends once the token is cancelled, after the solutions found so far.
+    pub fn cancel_token(mut self, token: CancellationToken) -> SolveOptions {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Keeps the handle up to date with the search while it runs.
+    #[cfg(feature = "std")]
+    pub fn progress(mut self, progress: Progress) -> SolveOptions {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Whether the deadline has passed or the search was cancelled, so a search with these options
+    /// may have ended before finding every solution.
+    pub fn is_stopped(&self) -> bool {
+        #[cfg(feature = "std")]
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return true;
+        }
+        self.cancel
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Only placements every filter allows are tried.
+    pub fn filter(mut self, filter: impl PlacementFilter + 'static) -> SolveOptions {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// The score of a solution is the sum of the scores of every scorer.
+    pub fn scorer(mut self, scorer: impl SolutionScorer + 'static) -> SolveOptions {
+        self.scorers.push(Box::new(scorer));
+        self
+    }
+
+    pub fn score(&self, solution: &SolvedBoard) -> i64 {
+        self.scorers
+            .iter()
+            .map(|scorer| scorer.score(solution))
+            .sum()
+    }
+}
+
+/// The solutions of [`solve_with_options`], telling whether the options ended the search early.
+pub struct Search<'a>(SolveIterator<'a>);
+
+impl Search<'_> {
+    /// Whether the search ended before trying everything, because of the deadline, the
+    /// cancellation token or the test budget.
+    pub fn is_truncated(&self) -> bool {
+        self.0.truncated
+    }
+
+    /// The counters of the search so far.
+    pub fn stats(&self) -> SearchStats {
+        SearchStats {
+            elapsed: self
+                .0
+                .started
+                .map_or(Duration::ZERO, |started| started.elapsed()),
+            ..self.0.stats
+        }
+    }
+}
+
+impl Iterator for Search<'_> {
+    type Item = SolvedBoard;
+
+    fn next(&mut self) -> Option<SolvedBoard> {
+        self.0.next()
+    }
+}
+
+pub fn solve_with_options<'a>(
+    initial_board: Board,
+    bricks: &'a [Brick],
+    options: &'a SolveOptions,
+) -> Search<'a> {
+    search(initial_board, bricks, options, None)
+}
+
+// Like `solve_with_options`, looking the placements up in the table when there is one. The table
+// has to be for the board, or for one it was made from by placing bricks.
+pub(crate) fn search<'a>(
+    initial_board: Board,
+    bricks: &'a [Brick],
+    options: &'a SolveOptions,
+    table: Option<&'a PlacementTable>,
+) -> Search<'a> {
+    let mut solver = SolveIterator::with_profile(initial_board, bricks, options.profile);
+    solver.branching.filters = &options.filters;
+    solver.branching.table = table;
+    solver.options = Some(options);
+    Search(solver)
+}
+
+/// Every solution with its score, best first. Solutions with the same score keep the search order.
+pub fn ranked_solutions(
+    initial_board: Board,
+    bricks: &[Brick],
+    options: &SolveOptions,
+) -> Vec<(i64, SolvedBoard)> {
+    let mut ranked: Vec<(i64, SolvedBoard)> = solve_with_options(initial_board, bricks, options)
+        .map(|solution| (options.score(&solution), solution))
+        .collect();
+    ranked.sort_by_key(|(score, _)| core::cmp::Reverse(*score));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_and_scorers() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        // Keep brick 2 out of the top row.
+        let options = SolveOptions::new()
+            .filter(|_, brick, placement| brick != 1 || placement & 0xff << 56 == 0)
+            .scorer(|solution: &SolvedBoard| solution.placed_bricks[0].count_ones() as i64);
+        let ranked = ranked_solutions(board.clone(), &bricks, &options);
+        assert!(!ranked.is_empty() && ranked.len() < 29);
+        assert!(ranked.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+        for (_, solution) in &ranked {
+            let placements = solution.placements(&bricks).unwrap();
+            let brick_2 = placements
+                .iter()
+                .find(|placement| placement.brick == 1)
+                .unwrap();
+            assert_eq!(brick_2.mask & 0xff << 56, 0);
+        }
+    }
+
+    #[test]
+    fn stopped_search_ends_early() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let token = CancellationToken::new();
+        let options = SolveOptions::new().cancel_token(token.clone());
+        let mut solutions = solve_with_options(board.clone(), &bricks, &options);
+        assert!(solutions.next().is_some());
+        token.cancel();
+        assert!(solutions.next().is_none());
+        assert!(options.is_stopped());
+
+        let all = solve_with_options(board.clone(), &bricks, &SolveOptions::new()).count();
+        let options = SolveOptions::new().max_tests(1000);
+        let mut search = solve_with_options(board.clone(), &bricks, &options);
+        let found = search.by_ref().count();
+        assert!(search.is_truncated() && search.stats().test_count == 1000);
+        assert!(found < all);
+        let mut again = solve_with_options(board.clone(), &bricks, &options);
+        assert_eq!(again.by_ref().count(), found);
+
+        let options = SolveOptions::new();
+        let mut search = solve_with_options(board.clone(), &bricks, &options);
+        let last = search.by_ref().last().unwrap();
+        let stats = search.stats();
+        assert_eq!(
+            stats.placements_generated - stats.prunes,
+            stats.test_count - 1 + stats.dead_state_hits
+        );
+        assert!(stats.dead_states > 0 && stats.dead_state_hits > 0);
+        assert!(stats.backtracks > 0 && stats.max_depth == 8);
+        assert!(last.stats.elapsed >= last.stats.solution_time);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn deadline_and_progress() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let options = SolveOptions::new().deadline(Instant::now());
+        assert_eq!(
+            solve_with_options(board.clone(), &bricks, &options).count(),
+            0
+        );
+
+        let all = solve_with_options(board.clone(), &bricks, &SolveOptions::new()).count();
+        let progress = Progress::new();
+        let options = SolveOptions::new().progress(progress.clone());
+        assert_eq!(solve_with_options(board, &bricks, &options).count(), all);
+        assert_eq!(progress.solutions(), all);
+        assert!(progress.test_count() > 1000 && progress.elapsed() > Duration::ZERO);
+    }
+}
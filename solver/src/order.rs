@@ -0,0 +1,158 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::shape::is_reflected;
+use crate::{Brick, SolvedBoard};
+use core::cmp::Reverse;
+
+/// Decides the order solutions are shown in. Implement it to order them some other way.
+pub trait SolutionOrderer {
+    fn order(&self, solutions: Vec<SolvedBoard>, bricks: &[Brick]) -> Vec<SolvedBoard>;
+}
+
+/// The order the search finds them in.
+pub struct FoundOrder;
+
+/// Sorted by where brick 1 lies, then brick 2 and so on, with bricks nearer the top left first.
+pub struct LexicographicOrder;
+
+/// The solutions with the fewest bricks turned over first, so the easiest to lay out come first.
+pub struct SymmetryOrder;
+
+/// Each next solution the one differing the most from those before it, so the first few show as
+/// many different ways to solve the date as possible.
+pub struct DiversityOrder;
+
+impl SolutionOrderer for FoundOrder {
+    fn order(&self, solutions: Vec<SolvedBoard>, _bricks: &[Brick]) -> Vec<SolvedBoard> {
+        solutions
+    }
+}
+
+impl SolutionOrderer for LexicographicOrder {
+    fn order(&self, mut solutions: Vec<SolvedBoard>, _bricks: &[Brick]) -> Vec<SolvedBoard> {
+        // A higher placement has its first differing cell nearer the top left.
+        solutions.sort_by_cached_key(|solution| Reverse(by_brick(solution)));
+        solutions
+    }
+}
+
+impl SolutionOrderer for SymmetryOrder {
+    fn order(&self, mut solutions: Vec<SolvedBoard>, bricks: &[Brick]) -> Vec<SolvedBoard> {
+        solutions.sort_by_cached_key(|solution| {
+            solution
+                .bricks
+                .iter()
+                .zip(&solution.placed_bricks)
+                .filter(|&(&brick, &placement)| is_reflected(bricks[brick].shape(), placement))
+                .count()
+        });
+        solutions
+    }
+}
+
+impl SolutionOrderer for DiversityOrder {
+    fn order(&self, solutions: Vec<SolvedBoard>, _bricks: &[Brick]) -> Vec<SolvedBoard> {
+        let keys: Vec<Vec<u64>> = solutions.iter().map(by_brick).collect();
+        let mut left: Vec<usize> = (0..solutions.len()).collect();
+        // The fewest bricks placed alike in any solution picked so far.
+        let mut nearest = vec![usize::MAX; solutions.len()];
+        let mut picked = Vec::with_capacity(solutions.len());
+        while !left.is_empty() {
+            // Ties go to the solution found first.
+            let position = (0..left.len())
+                .max_by_key(|&position| (nearest[left[position]], Reverse(left[position])))
+                .unwrap();
+            let next = left.remove(position);
+            for &other in &left {
+                let distance = keys[next]
+                    .iter()
+                    .zip(&keys[other])
+                    .filter(|(a, b)| a != b)
+                    .count();
+                nearest[other] = nearest[other].min(distance);
+            }
+            picked.push(next);
+        }
+        let mut solutions: Vec<Option<SolvedBoard>> = solutions.into_iter().map(Some).collect();
+        picked
+            .into_iter()
+            .map(|index| solutions[index].take().unwrap())
+            .collect()
+    }
+}
+
+// The placements ordered by brick, the same for a solution however the search found it.
+fn by_brick(solution: &SolvedBoard) -> Vec<u64> {
+    let mut placements: Vec<(usize, u64)> = solution
+        .bricks
+        .iter()
+        .copied()
+        .zip(solution.placed_bricks.iter().copied())
+        .collect();
+    placements.sort_unstable();
+    placements
+        .into_iter()
+        .map(|(_, placement)| placement)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, solve};
+
+    #[test]
+    fn orderers_keep_every_solution() {
+        let bricks = Brick::all_bricks();
+        let found: Vec<SolvedBoard> = solve(Board::for_date(22, 9).unwrap(), &bricks).collect();
+        let orderers: [&dyn SolutionOrderer; 4] = [
+            &FoundOrder,
+            &LexicographicOrder,
+            &SymmetryOrder,
+            &DiversityOrder,
+        ];
+        for orderer in orderers {
+            let mut ordered: Vec<Vec<u64>> = orderer
+                .order(found.clone(), &bricks)
+                .iter()
+                .map(by_brick)
+                .collect();
+            ordered.sort();
+            let mut expected: Vec<Vec<u64>> = found.iter().map(by_brick).collect();
+            expected.sort();
+            assert_eq!(ordered, expected);
+        }
+
+        let lexicographic: Vec<Vec<u64>> = LexicographicOrder
+            .order(found.clone(), &bricks)
+            .iter()
+            .map(by_brick)
+            .collect();
+        assert!(lexicographic.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        let diverse = DiversityOrder.order(found.clone(), &bricks);
+        assert_eq!(by_brick(&diverse[0]), by_brick(&found[0]));
+        let differing = |solution: &SolvedBoard| {
+            by_brick(solution)
+                .iter()
+                .zip(by_brick(&found[0]))
+                .filter(|(a, b)| **a != *b)
+                .count()
+        };
+        assert!(
+            found[1..]
+                .iter()
+                .all(|solution| differing(solution) <= differing(&diverse[1]))
+        );
+    }
+
+    #[test]
+    fn reflected_placements() {
+        let s = Brick::all_bricks()[0].shape();
+        assert!(!is_reflected(s, s >> 10));
+        let turned_over = Brick::from_ascii(&["XX.", ".X.", ".XX"]).unwrap().shape();
+        assert!(is_reflected(s, turned_over >> 9));
+        let square = Brick::all_bricks()[1].shape();
+        assert!(!is_reflected(square, square));
+    }
+}
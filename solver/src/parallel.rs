@@ -0,0 +1,161 @@
+use crate::{Board, Brick, SolveIterator, SolvedBoard};
+use std::sync::mpsc::{self, IntoIter};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// What one thread of [`solve_par_threads`] contributed to the search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadStats {
+    pub solutions: usize,
+    pub test_count: u64,
+}
+
+/// The solutions of [`solve_par_threads`], in the order the threads find them.
+pub struct ParallelSearch {
+    solutions: IntoIter<SolvedBoard>,
+    threads: Arc<Mutex<Vec<ThreadStats>>>,
+}
+
+impl ParallelSearch {
+    /// The solutions and boards tested per thread so far. Complete once every solution is taken.
+    pub fn thread_stats(&self) -> Vec<ThreadStats> {
+        self.threads.lock().unwrap().clone()
+    }
+}
+
+impl Iterator for ParallelSearch {
+    type Item = SolvedBoard;
+
+    fn next(&mut self) -> Option<SolvedBoard> {
+        self.solutions.next()
+    }
+}
+
+// Every child of the root is an independent subtree, which the workers take turns picking from a
+// shared queue. Solutions arrive in the order they are found, and their test
+// count is the count of the worker that found them.
+pub fn solve_par(initial_board: Board, bricks: &[Brick]) -> IntoIter<SolvedBoard> {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    solve_par_threads(initial_board, bricks, threads).solutions
+}
+
+/// Like [`solve_par`], searching with the given number of threads, at least one.
+pub fn solve_par_threads(initial_board: Board, bricks: &[Brick], threads: usize) -> ParallelSearch {
+    let threads = threads.max(1);
+    let bricks: Arc<[Brick]> = bricks.into();
+    let (sender, receiver) = mpsc::channel();
+    let stats = Arc::new(Mutex::new(vec![ThreadStats::default(); threads]));
+    let search = ParallelSearch {
+        solutions: receiver.into_iter(),
+        threads: Arc::clone(&stats),
+    };
+    let root = SolveIterator::new(initial_board.clone(), &bricks);
+    // Bricks already on the board are left out of the split, like the sequential search does.
+    let full = initial_board.unplaced(&bricks);
+    if full == 0 {
+        if let Some(solution) = root.last() {
+            sender.send(solution).unwrap();
+        }
+        return search;
+    }
+    let subtrees: Vec<(Board, u32)> = root
+        .branching
+        .children(initial_board.bitboard, full)
+        .map(|(brick_index, placement)| {
+            (
+                initial_board.place(brick_index, placement),
+                full & !(1 << brick_index),
+            )
+        })
+        .collect();
+    let queue = Arc::new(Mutex::new(subtrees));
+    for thread_index in 0..threads {
+        let sender = sender.clone();
+        let queue = Arc::clone(&queue);
+        let stats = Arc::clone(&stats);
+        let bricks = Arc::clone(&bricks);
+        let initial_board = initial_board.clone();
+        thread::spawn(move || {
+            let mut solver = SolveIterator::new(initial_board, &bricks);
+            solver.stack.clear();
+            loop {
+                // Separate statement so the lock is released while the subtree is searched.
+                let subtree = queue.lock().unwrap().pop();
+                let Some(subtree) = subtree else {
+                    break;
+                };
+                solver.stack.push(subtree);
+                for solution in &mut solver {
+                    let mut stats = stats.lock().unwrap();
+                    stats[thread_index].solutions += 1;
+                    stats[thread_index].test_count = solution.stats.test_count;
+                    drop(stats);
+                    if sender.send(solution).is_err() {
+                        return;
+                    }
+                }
+            }
+            // Counted before the sender is dropped, so the counts are final when the last
+            // solution is taken.
+            stats.lock().unwrap()[thread_index].test_count = solver.stats.test_count;
+        });
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn parallel_finds_same_solutions() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let mut sequential: Vec<Vec<u64>> = solve(board.clone(), &bricks)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        let mut parallel: Vec<Vec<u64>> = solve_par(board, &bricks)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        sequential.sort_unstable();
+        parallel.sort_unstable();
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn parallel_solves_a_partial_board() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let first = solve(board.clone(), &bricks).next().unwrap();
+        let placed = first.placements(&bricks).unwrap();
+        let partial = board.with_placements(&placed[..3]).unwrap();
+        let mut sequential: Vec<Vec<u64>> = solve(partial.clone(), &bricks)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        let mut parallel: Vec<Vec<u64>> = solve_par_threads(partial, &bricks, 3)
+            .map(|solution| solution.placed_bricks)
+            .collect();
+        assert!(!sequential.is_empty());
+        sequential.sort_unstable();
+        parallel.sort_unstable();
+        assert_eq!(parallel, sequential);
+        let full = board.with_placements(&placed).unwrap();
+        assert_eq!(solve_par(full, &bricks).count(), 1);
+    }
+
+    #[test]
+    fn thread_stats_add_up() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let mut search = solve_par_threads(board.clone(), &bricks, 3);
+        let found = search.by_ref().count();
+        let threads = search.thread_stats();
+        assert_eq!(threads.len(), 3);
+        assert_eq!(
+            threads.iter().map(|thread| thread.solutions).sum::<usize>(),
+            found
+        );
+        assert_eq!(found, solve(board, &bricks).count());
+    }
+}
@@ -0,0 +1,331 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, BoardBuilder, Brick, Set, SolvedBoard, SolverError, hints};
+
+/// A brick variant moved to a spot on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub brick: usize,
+    pub variant: usize,
+    /// How many cells the variant is moved right from the top left corner, wrapping at row ends.
+    pub shift: u32,
+    pub mask: u64,
+}
+
+impl Placement {
+    // Finds the variant of the brick that makes the mask, if any.
+    pub(crate) fn decode(mask: u64, brick_index: usize, brick: &Brick) -> Option<Placement> {
+        brick
+            .brick_variants
+            .iter()
+            .enumerate()
+            .find_map(|(variant_index, variant)| {
+                let shift = mask
+                    .leading_zeros()
+                    .checked_sub(variant.bit_pattern.leading_zeros())?;
+                (variant.bit_pattern >> shift == mask).then_some(Placement {
+                    brick: brick_index,
+                    variant: variant_index,
+                    shift,
+                    mask,
+                })
+            })
+    }
+
+    /// The (row, column) of each covered cell, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (u8, u8)> + use<> {
+        let mask = self.mask;
+        (0..64u8)
+            .filter(move |index| mask & 1 << 63 >> index != 0)
+            .map(|index| (index / 8, index % 8))
+    }
+
+    /// Decodes masks into placements of different bricks, like the bricks of a board someone is
+    /// part way through, for [`Board::with_placements`]. Returns None if no set of different
+    /// bricks makes them.
+    pub fn assign(masks: &[u64], bricks: &[Brick]) -> Option<Vec<Placement>> {
+        let mut placements = Vec::with_capacity(masks.len());
+        assign(masks, bricks, &mut placements).then_some(placements)
+    }
+}
+
+// The placements of each brick that fit on a board, and the same placements grouped by their
+// first cell, which are the ones an anchored search tries when that cell is the first free one.
+// Searches from the board, or from boards with more cells taken, can look them up instead of
+// shifting every variant at every node.
+pub(crate) struct PlacementTable {
+    placements: Vec<Vec<u64>>,
+    by_first_cell: Vec<Vec<Vec<u64>>>,
+}
+
+impl PlacementTable {
+    pub(crate) fn new(board: &Board, bricks: &[Brick]) -> PlacementTable {
+        let placements: Vec<Vec<u64>> = bricks
+            .iter()
+            .map(|brick| board.valid_placements(brick).collect())
+            .collect();
+        // Each variant has at most one placement starting at a cell, so the variant order is kept.
+        let by_first_cell = placements
+            .iter()
+            .map(|placements| {
+                let mut by_first_cell = vec![Vec::new(); 64];
+                for &placement in placements {
+                    by_first_cell[placement.leading_zeros() as usize].push(placement);
+                }
+                by_first_cell
+            })
+            .collect();
+        PlacementTable {
+            placements,
+            by_first_cell,
+        }
+    }
+
+    // The placements of every brick, indexed like the bricks.
+    pub(crate) fn all(&self) -> &[Vec<u64>] {
+        &self.placements
+    }
+
+    pub(crate) fn starting_at(&self, brick_index: usize, cell: u32) -> &[u64] {
+        self.by_first_cell[brick_index]
+            .get(cell as usize)
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl Board {
+    /// Every spot on the free cells the brick fits, in each of its orientations, whether or not a
+    /// solution uses it. The placements are labeled with `brick_index`, the brick's place in the
+    /// set. No solver search is run, so this is cheap enough to call on every click.
+    pub fn placements_of<'a>(
+        &self,
+        brick_index: usize,
+        brick: &'a Brick,
+    ) -> impl Iterator<Item = Placement> + use<'a> {
+        self.valid_placements(brick)
+            .filter_map(move |placement| Placement::decode(placement, brick_index, brick))
+    }
+}
+
+/// Every way a brick not yet on the board can be placed covering the cell, the question behind
+/// "what could go here?". Empty if the cell is blocked or covered already.
+pub fn placements_covering(
+    board: &Board,
+    bricks: &[Brick],
+    row: u8,
+    column: u8,
+) -> Result<Vec<Placement>, SolverError> {
+    let cell = BoardBuilder::cell(row, column).ok_or(SolverError::InvalidCell {
+        row: row.into(),
+        column: column.into(),
+    })?;
+    let unplaced = board.unplaced(bricks);
+    Ok(bricks
+        .iter()
+        .enumerate()
+        .filter(|(brick_index, _)| unplaced & (1 << brick_index) != 0)
+        .flat_map(|(brick_index, brick)| {
+            board
+                .placements_of(brick_index, brick)
+                .filter(|placement| placement.mask & cell != 0)
+        })
+        .collect())
+}
+
+/// Every placement of a brick not yet on the board that is part of no solution, the ones not worth
+/// trying. Found from all the solutions, so it costs as much as [`hints`](crate::hints).
+pub fn dead_placements(board: &Board, bricks: &[Brick]) -> Vec<Placement> {
+    let live: Set<u64> = hints(board.clone(), bricks)
+        .iter()
+        .map(|hint| hint.brick)
+        .collect();
+    let unplaced = board.unplaced(bricks);
+    bricks
+        .iter()
+        .enumerate()
+        .filter(|(brick_index, _)| unplaced & (1 << brick_index) != 0)
+        .flat_map(|(brick_index, brick)| {
+            board
+                .placements_of(brick_index, brick)
+                .filter(|placement| !live.contains(&placement.mask))
+        })
+        .collect()
+}
+
+impl SolvedBoard {
+    /// The placed bricks decoded into the bricks and variants making them, in the same order.
+    /// Returns None if the solution can't be made with the bricks.
+    pub fn placements(&self, bricks: &[Brick]) -> Option<Vec<Placement>> {
+        if self.bricks.len() == self.placed_bricks.len() {
+            return self
+                .placed_bricks
+                .iter()
+                .zip(&self.bricks)
+                .map(|(&mask, &brick_index)| {
+                    Placement::decode(mask, brick_index, bricks.get(brick_index)?)
+                })
+                .collect();
+        }
+        Placement::assign(&self.placed_bricks, bricks)
+    }
+}
+
+// Matches each mask to a different brick, backtracking when bricks share an orientation.
+fn assign(masks: &[u64], bricks: &[Brick], placements: &mut Vec<Placement>) -> bool {
+    let Some((mask, rest)) = masks.split_first() else {
+        return true;
+    };
+    for (brick_index, brick) in bricks.iter().enumerate() {
+        if placements
+            .iter()
+            .any(|placement| placement.brick == brick_index)
+        {
+            continue;
+        }
+        if let Some(placement) = Placement::decode(*mask, brick_index, brick) {
+            placements.push(placement);
+            if assign(rest, bricks, placements) {
+                return true;
+            }
+            placements.pop();
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, solve};
+
+    #[test]
+    fn placements_covering_a_cell() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let covering = placements_covering(&board, &bricks, 6, 0).unwrap();
+        assert!(!covering.is_empty());
+        assert!(covering.iter().all(|placement| {
+            placement.cells().any(|cell| cell == (6, 0))
+                && bricks[placement.brick].matches(placement.mask)
+        }));
+        // Every solution covers the cell with one of them.
+        for solution in solve(board.clone(), &bricks) {
+            let placements = solution.placements(&bricks).unwrap();
+            assert!(
+                placements
+                    .iter()
+                    .any(|placement| covering.contains(placement))
+            );
+        }
+        let placed = board.with_placements(&[covering[0]]).unwrap();
+        assert!(
+            placements_covering(&placed, &bricks, 6, 0)
+                .unwrap()
+                .is_empty()
+        );
+        // September is taken by the date.
+        assert!(
+            placements_covering(&board, &bricks, 1, 2)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(placements_covering(&board, &bricks, 0, 7).is_err());
+    }
+
+    #[test]
+    fn placements_of_one_brick() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let placements: Vec<Placement> = board.placements_of(2, &bricks[2]).collect();
+        assert_eq!(placements.len(), board.valid_placements(&bricks[2]).count());
+        assert!(placements.iter().all(|placement| {
+            placement.brick == 2
+                && bricks[2].matches(placement.mask)
+                && placement
+                    .cells()
+                    .all(|(row, column)| board.is_free(row * 8 + column))
+        }));
+        let first = solve(board.clone(), &bricks).next().unwrap();
+        let used = first.placements(&bricks).unwrap();
+        let used = used.iter().find(|placement| placement.brick == 2).unwrap();
+        assert!(placements.contains(used));
+        // Once the brick is on the board, the spots overlapping it are gone.
+        let placed = board.with_placements(&[*used]).unwrap();
+        assert!(placed.placements_of(2, &bricks[2]).count() < placements.len());
+    }
+
+    #[test]
+    fn placements_rebuild_the_solution() {
+        let bricks = Brick::all_bricks();
+        let solution = solve(Board::for_date(1, 1).unwrap(), &bricks)
+            .next()
+            .unwrap();
+        let placements = solution.placements(&bricks).unwrap();
+        let mut used: Vec<usize> = placements.iter().map(|placement| placement.brick).collect();
+        used.sort();
+        assert_eq!(used, (0..bricks.len()).collect::<Vec<_>>());
+        for (placement, mask) in placements.iter().zip(&solution.placed_bricks) {
+            assert!(bricks[placement.brick].matches(*mask));
+            let variant = bricks[placement.brick].brick_variants[placement.variant].bit_pattern;
+            assert_eq!(variant >> placement.shift, *mask);
+            assert_eq!(placement.cells().count(), mask.count_ones() as usize);
+        }
+        let unlabeled = SolvedBoard {
+            bricks: vec![],
+            ..solution.clone()
+        };
+        assert!(unlabeled.placements(&bricks).is_some());
+        // January is the top left cell, so no brick covers it.
+        assert!(
+            placements
+                .iter()
+                .all(|placement| !placement.cells().any(|cell| cell == (0, 0)))
+        );
+    }
+
+    #[test]
+    fn assigned_placements_finish_a_partial_board() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let solution = solve(board.clone(), &bricks).next().unwrap();
+        let placed = Placement::assign(&solution.placed_bricks[..3], &bricks).unwrap();
+        let partial = board.with_placements(&placed).unwrap();
+        assert!(solve(partial, &bricks).next().is_some());
+        let twice = [solution.placed_bricks[0], solution.placed_bricks[0] >> 8];
+        assert_eq!(Placement::assign(&twice, &bricks), None);
+    }
+
+    #[test]
+    fn dead_placements_are_in_no_solution() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let dead = dead_placements(&board, &bricks);
+        assert!(!dead.is_empty());
+        let solutions: Vec<SolvedBoard> = solve(board.clone(), &bricks).collect();
+        for placement in &dead {
+            assert!(bricks[placement.brick].matches(placement.mask));
+            assert!(
+                solutions
+                    .iter()
+                    .all(|solution| !solution.placed_bricks.contains(&placement.mask))
+            );
+        }
+        let live: usize = (0..bricks.len())
+            .map(|brick_index| {
+                board
+                    .valid_placements(&bricks[brick_index])
+                    .filter(|placement| {
+                        solutions
+                            .iter()
+                            .any(|solution| solution.placed_bricks.contains(placement))
+                    })
+                    .count()
+            })
+            .sum();
+        let all: usize = bricks
+            .iter()
+            .map(|brick| board.valid_placements(brick).count())
+            .sum();
+        assert_eq!(dead.len() + live, all);
+    }
+}
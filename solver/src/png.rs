@@ -0,0 +1,323 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, SolvedBoard};
+
+/// An RGB image written as PNG, with the image data in uncompressed deflate blocks so no
+/// compression library is needed. Fine for the small pictures of boards drawn here.
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    pub fn new(width: u32, height: u32, background: [u8; 3]) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; (width * height) as usize],
+        }
+    }
+
+    /// Fills the rectangle, clipped to the image.
+    pub fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+        for row in y..(y + height).min(self.height) {
+            for column in x..(x + width).min(self.width) {
+                self.pixels[(row * self.width + column) as usize] = color;
+            }
+        }
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.pixels.len() * 3 + self.height as usize);
+        for row in self.pixels.chunks(self.width as usize) {
+            // Filter type 0, the bytes as they are.
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        let mut header = Vec::with_capacity(13);
+        header.extend(self.width.to_be_bytes());
+        header.extend(self.height.to_be_bytes());
+        // 8 bits per channel, RGB, deflate, no filter variants, not interlaced.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Reads back an image written by `to_png`. Other PNGs, compressed or not RGB, give None.
+    pub fn from_png(png: &[u8]) -> Option<Image> {
+        let mut rest = png.strip_prefix(SIGNATURE)?;
+        let (mut width, mut height, mut stream) = (0, 0, vec![]);
+        while rest.len() >= 12 {
+            let length = u32::from_be_bytes(rest[..4].try_into().ok()?) as usize;
+            let data = rest.get(8..8 + length)?;
+            match &rest[4..8] {
+                b"IHDR" => {
+                    width = u32::from_be_bytes(data[..4].try_into().ok()?);
+                    height = u32::from_be_bytes(data[4..8].try_into().ok()?);
+                    if data[8..10] != [8, 2] {
+                        return None;
+                    }
+                }
+                b"IDAT" => stream.extend(data),
+                _ => {}
+            }
+            rest = &rest[12 + length..];
+        }
+        // Stored deflate blocks after the two byte zlib header.
+        let mut raw = vec![];
+        let mut blocks = stream.get(2..)?;
+        loop {
+            let last = *blocks.first()? & 1 == 1;
+            let length = u16::from_le_bytes(blocks.get(1..3)?.try_into().ok()?) as usize;
+            raw.extend(blocks.get(5..5 + length)?);
+            blocks = &blocks[5 + length..];
+            if last {
+                break;
+            }
+        }
+        let pixels = raw
+            .chunks(width as usize * 3 + 1)
+            .flat_map(|row| {
+                row[1..]
+                    .chunks(3)
+                    .map(|pixel| [pixel[0], pixel[1], pixel[2]])
+            })
+            .collect::<Vec<_>>();
+        (pixels.len() == (width * height) as usize).then_some(Image {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// The largest difference of any channel of any pixel, or None if the sizes differ.
+    pub fn max_difference(&self, other: &Image) -> Option<u8> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return None;
+        }
+        self.pixels
+            .iter()
+            .zip(&other.pixels)
+            .flat_map(|(a, b)| a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)))
+            .max()
+            .or(Some(0))
+    }
+}
+
+/// The colors of a board drawn by [`SolvedBoard::to_png_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// Around and between the cells, and the holes marking the date cells.
+    pub background: [u8; 3],
+    /// The date cells, the ones left uncovered.
+    pub free: [u8; 3],
+    /// Brick n gets color n, starting over when there are more bricks than colors.
+    pub bricks: Vec<[u8; 3]>,
+}
+
+impl Default for Palette {
+    // The first colors of the usual terminal palette.
+    fn default() -> Palette {
+        Palette {
+            background: [32, 32, 32],
+            free: [240, 240, 240],
+            bricks: vec![
+                [128, 0, 0],
+                [0, 128, 0],
+                [128, 128, 0],
+                [0, 0, 128],
+                [128, 0, 128],
+                [0, 128, 128],
+                [192, 192, 192],
+                [128, 128, 128],
+            ],
+        }
+    }
+}
+
+impl Palette {
+    /// The color of the brick with the number, counted from 1 like in [`SolvedBoard::to_grid`].
+    pub fn brick(&self, brick_number: u8) -> [u8; 3] {
+        match self.bricks.len() {
+            0 => self.free,
+            colors => self.bricks[(brick_number as usize - 1) % colors],
+        }
+    }
+}
+
+// Pixels per cell, between the cells and around the board, at scale 1.
+const CELL: u32 = 8;
+const GAP: u32 = 1;
+const MARGIN: u32 = 4;
+
+impl SolvedBoard {
+    /// The solution as a PNG image in the default colors, with each cell `8 * scale` pixels wide.
+    pub fn to_png(&self, scale: u32) -> Vec<u8> {
+        self.to_png_with(scale, &Palette::default())
+    }
+
+    pub fn to_png_with(&self, scale: u32, palette: &Palette) -> Vec<u8> {
+        self.to_image(scale, palette).to_png()
+    }
+
+    /// The solution drawn like [`SolvedBoard::to_png_with`], to draw more on.
+    pub fn to_image(&self, scale: u32, palette: &Palette) -> Image {
+        let mut image = Image::new(0, 0, palette.background);
+        self.draw(scale, palette, |x, y, width, height, color| match x + y {
+            0 => image = Image::new(width, height, color),
+            _ => image.fill(x, y, width, height, color),
+        });
+        image
+    }
+
+    /// The solution as an SVG image, drawn the same way as [`SolvedBoard::to_png_with`].
+    pub fn to_svg(&self, scale: u32, palette: &Palette) -> String {
+        let mut svg = String::new();
+        self.draw(scale, palette, |x, y, width, height, [r, g, b]| {
+            if x + y == 0 {
+                svg += &format!(
+                    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+                     viewBox=\"0 0 {width} {height}\">\n"
+                );
+            }
+            svg += &format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" \
+                 fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n"
+            );
+        });
+        svg + "</svg>\n"
+    }
+
+    // Calls `fill` with each rectangle of the picture, the first being the background of the whole
+    // picture at 0, 0.
+    fn draw(
+        &self,
+        scale: u32,
+        palette: &Palette,
+        mut fill: impl FnMut(u32, u32, u32, u32, [u8; 3]),
+    ) {
+        let grid = self.to_grid();
+        // The weekday board is the one with bricks in the last row.
+        let (board, rows) = match grid[7].iter().any(|&cell| cell != 0) {
+            true => (Board::new_weekday(), 8),
+            false => (Board::new(), 7),
+        };
+        let scale = scale.max(1);
+        let (cell, gap, margin) = (CELL * scale, GAP * scale, MARGIN * scale);
+        let width = 2 * margin + 7 * (cell + gap) + gap;
+        let height = 2 * margin + rows * (cell + gap) + gap;
+        fill(0, 0, width, height, palette.background);
+        for (row, cells) in grid.iter().enumerate().take(rows as usize) {
+            for (column, &brick_number) in cells.iter().enumerate() {
+                if brick_number == 0 && !board.is_free((row * 8 + column) as u8) {
+                    continue;
+                }
+                let x = margin + gap + column as u32 * (cell + gap);
+                let y = margin + gap + row as u32 * (cell + gap);
+                if brick_number == 0 {
+                    // The date cells get a hole, so they don't look like a light brick.
+                    fill(x, y, cell, cell, palette.free);
+                    let border = cell / 4;
+                    let hole = cell - 2 * border;
+                    fill(x + border, y + border, hole, hole, palette.background);
+                } else {
+                    fill(x, y, cell, cell, palette.brick(brick_number));
+                }
+            }
+        }
+    }
+}
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// A zlib stream of stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        stream.push(last as u8);
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Brick, solve};
+
+    #[test]
+    fn solution_png() {
+        let bricks = Brick::all_bricks();
+        let solution = solve(Board::for_date(22, 9).unwrap(), &bricks)
+            .next()
+            .unwrap();
+        let image = Image::from_png(&solution.to_png(2)).unwrap();
+        assert_eq!((image.width, image.height), (144, 144));
+        let palette = Palette::default();
+        // The top left cell is covered by a brick, with the background in the margin around it.
+        let pixel = |x: u32, y: u32| image.pixels[(y * image.width + x) as usize];
+        let brick = solution.to_grid()[0][0];
+        assert_eq!(pixel(20, 20), palette.brick(brick));
+        assert_eq!(pixel(2, 2), palette.background);
+    }
+
+    #[test]
+    fn solution_svg() {
+        let bricks = Brick::all_bricks();
+        let solution = solve(Board::for_date(22, 9).unwrap(), &bricks)
+            .next()
+            .unwrap();
+        let svg = solution.to_svg(2, &Palette::default());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"144\""));
+        // The background, 41 cells covered by bricks and the 2 date cells with their holes.
+        assert_eq!(svg.matches("<rect").count(), 1 + 41 + 2 * 2);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Brick;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+// The order of the standard bricks with the fewest boards tested to a first solution over all
+// dates, found by running the `first_solution` bench over every order.
+const INTERACTIVE_ORDER: [usize; 8] = [0, 7, 2, 5, 1, 3, 4, 6];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchProfile {
+    /// Tries the bricks in the given order. Best for full enumeration.
+    #[default]
+    Exhaustive,
+    /// Tuned for time to first solution: the standard bricks are tried in the order that finds one
+    /// soonest. Other brick sets are tried in the given order.
+    Interactive,
+    /// Tries the brick with the fewest placements first at every step. When the bricks don't have
+    /// to cover every cell, a brick that no longer fits anywhere ends the branch right away.
+    FailFirst,
+}
+
+impl SearchProfile {
+    pub(crate) fn brick_order(&self, bricks: &[Brick]) -> Vec<usize> {
+        match self {
+            SearchProfile::Interactive if is_standard(bricks) => INTERACTIVE_ORDER.to_vec(),
+            _ => (0..bricks.len()).collect(),
+        }
+    }
+}
+
+fn is_standard(bricks: &[Brick]) -> bool {
+    let standard = Brick::all_bricks();
+    bricks.len() == standard.len()
+        && bricks
+            .iter()
+            .zip(standard.iter())
+            .all(|(brick, standard)| brick.shape() == standard.shape())
+}
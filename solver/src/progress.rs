@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+// What the search is looking at right now, passed to progress callbacks.
+pub struct SearchProgress<'a> {
+    pub placed_bricks: &'a [u64],
+    pub test_count: u64,
+    pub solutions: usize,
+    /// Time since the search started.
+    pub elapsed: Duration,
+}
+
+impl SearchProgress<'_> {
+    /// The number of bricks placed on the board being tested.
+    pub fn depth(&self) -> usize {
+        self.placed_bricks.len()
+    }
+}
+
+pub(crate) struct ProgressReporter<'a> {
+    callback: Box<dyn FnMut(&SearchProgress) + 'a>,
+    interval: Duration,
+    started: Instant,
+    last_report: Option<Instant>,
+}
+
+impl<'a> ProgressReporter<'a> {
+    pub(crate) fn new(interval: Duration, callback: impl FnMut(&SearchProgress) + 'a) -> Self {
+        ProgressReporter {
+            callback: Box::new(callback),
+            interval,
+            started: Instant::now(),
+            last_report: None,
+        }
+    }
+
+    // Calls the callback unless it was called less than the interval ago.
+    pub(crate) fn report(&mut self, placed_bricks: &[u64], test_count: u64, solutions: usize) {
+        let now = Instant::now();
+        if self
+            .last_report
+            .is_none_or(|last_report| now - last_report >= self.interval)
+        {
+            self.last_report = Some(now);
+            (self.callback)(&SearchProgress {
+                placed_bricks,
+                test_count,
+                solutions,
+                elapsed: now - self.started,
+            });
+        }
+    }
+}
+
+/// A handle to poll the progress of a search running with [`crate::SolveOptions::progress`],
+/// for example from another thread showing a progress line. Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct Progress(Arc<ProgressState>);
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    test_count: AtomicU64,
+    solutions: AtomicUsize,
+    depth: AtomicUsize,
+    started: OnceLock<Instant>,
+}
+
+impl Progress {
+    pub fn new() -> Progress {
+        Progress::default()
+    }
+
+    pub fn test_count(&self) -> u64 {
+        self.0.test_count.load(Ordering::Relaxed)
+    }
+
+    pub fn solutions(&self) -> usize {
+        self.0.solutions.load(Ordering::Relaxed)
+    }
+
+    /// The number of bricks placed on the board being tested.
+    pub fn depth(&self) -> usize {
+        self.0.depth.load(Ordering::Relaxed)
+    }
+
+    /// Time since the search started, zero before it has.
+    pub fn elapsed(&self) -> Duration {
+        self.0
+            .started
+            .get()
+            .map_or(Duration::ZERO, Instant::elapsed)
+    }
+
+    pub(crate) fn update(&self, depth: usize, test_count: u64, solutions: usize) {
+        self.0.started.get_or_init(Instant::now);
+        self.0.test_count.store(test_count, Ordering::Relaxed);
+        self.0.solutions.store(solutions, Ordering::Relaxed);
+        self.0.depth.store(depth, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::shape::{canonical, cells};
+use crate::{Board, Brick, COLUMN_0, COLUMN_7, connected_region, solve};
+
+/// Shapes that could replace a brick while every date stays solvable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redundancy {
+    pub brick: usize,
+    pub replacements: Vec<u64>,
+}
+
+// Removing a brick, or swapping it for a smaller one, leaves more cells open than the two date
+// cells, so the board would no longer show just the date. The only freedom left is the shapes: a
+// brick is redundant if another shape of the same area, made by moving one of its cells, solves
+// every date as well. Bricks without such a replacement are left out.
+pub fn brick_redundancy(bricks: &[Brick]) -> Vec<Redundancy> {
+    let taken: Vec<u64> = bricks
+        .iter()
+        .map(|brick| canonical(brick.shape()))
+        .collect();
+    (0..bricks.len())
+        .filter_map(|brick_index| {
+            let mut set = bricks.to_vec();
+            let replacements: Vec<u64> = neighbour_shapes(bricks[brick_index].shape())
+                .into_iter()
+                .filter(|shape| !taken.contains(&canonical(*shape)))
+                .filter(|shape| {
+                    set[brick_index] = Brick::from_shape(*shape);
+                    solves_every_date(&set)
+                })
+                .collect();
+            (!replacements.is_empty()).then_some(Redundancy {
+                brick: brick_index,
+                replacements,
+            })
+        })
+        .collect()
+}
+
+fn solves_every_date(bricks: &[Brick]) -> bool {
+    (1..=12).all(|month| {
+        (1..=31).all(|day| {
+            solve(Board::for_date(day, month).unwrap(), bricks)
+                .next()
+                .is_some()
+        })
+    })
+}
+
+// Connected shapes made by moving one cell of the shape somewhere else, one of each up to rotation
+// and reflection.
+fn neighbour_shapes(shape: u64) -> Vec<u64> {
+    let mut shapes = Vec::new();
+    for cell in cells(shape) {
+        // Leave room above and to the left so the moved cell can go there too.
+        let rest = (shape & !cell) >> 9;
+        if connected_region(rest & rest.wrapping_neg(), rest) != rest {
+            continue;
+        }
+        let around = (rest << 8 | rest >> 8 | (rest << 1) & !COLUMN_7 | (rest >> 1) & !COLUMN_0)
+            & !rest
+            & !COLUMN_7;
+        for new_cell in cells(around) {
+            let candidate = canonical(rest | new_cell);
+            if candidate != canonical(shape) && !shapes.contains(&candidate) {
+                shapes.push(candidate);
+            }
+        }
+    }
+    shapes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbour_shapes_of_l_tetromino() {
+        // The L tetromino can become the T, S, O and I tetromino by moving one cell.
+        let l = 0b10000000_10000000_11000000u64 << (5 * 8);
+        assert_eq!(neighbour_shapes(l).len(), 4);
+        assert!(
+            neighbour_shapes(l)
+                .iter()
+                .all(|shape| shape.count_ones() == 4)
+        );
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::rng::Rng;
+use crate::{Board, Brick, Set, SolveIterator, SolvedBoard};
+
+// Restarts allowed per requested solution before giving up, e.g. when the date has fewer than n solutions.
+const RESTARTS_PER_SOLUTION: usize = 8;
+
+/// One solution, found by trying the placements at every step in an order shuffled by the seed.
+/// The same seed gives the same solution. Each solution has a fair chance of being picked, though
+/// not exactly the same one: solutions in parts of the search with few others are a bit more
+/// likely.
+pub fn solve_random(board: Board, bricks: &[Brick], seed: u64) -> Option<SolvedBoard> {
+    SolveIterator::randomized(board, bricks, seed).next()
+}
+
+/// Up to `n` distinct solutions, each found by a restart of the search seeded from `seed`. The
+/// same seed gives the same solutions. After 8 restarts per requested solution it gives up, so
+/// it returns fewer than `n` when the date has fewer solutions, or when the restarts keep
+/// finding ones it already has.
+pub fn sample_solutions(board: Board, bricks: &[Brick], n: usize, seed: u64) -> Vec<SolvedBoard> {
+    let mut rng = Rng::new(seed);
+    let mut seen: Set<Vec<u64>> = Set::new();
+    let mut samples = Vec::new();
+    for _ in 0..n.saturating_mul(RESTARTS_PER_SOLUTION) {
+        if samples.len() == n {
+            break;
+        }
+        let mut restart = SolveIterator::randomized(board.clone(), bricks, rng.next_u64());
+        let Some(solution) = restart.next() else {
+            break;
+        };
+        let mut key = solution.placed_bricks.clone();
+        key.sort_unstable();
+        if seen.insert(key) {
+            samples.push(solution);
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn samples_are_distinct_solutions() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let samples = sample_solutions(board.clone(), &bricks, 5, 1);
+        assert_eq!(samples.len(), 5);
+        let all: Vec<Vec<u64>> = solve(board, &bricks)
+            .map(|solution| {
+                let mut placed = solution.placed_bricks;
+                placed.sort_unstable();
+                placed
+            })
+            .collect();
+        for sample in samples {
+            let mut placed = sample.placed_bricks;
+            placed.sort_unstable();
+            assert!(all.contains(&placed));
+        }
+    }
+
+    #[test]
+    fn sampling_is_reproducible() {
+        let board = Board::for_date(29, 7).unwrap();
+        let bricks = Brick::all_bricks();
+        let first = sample_solutions(board.clone(), &bricks, 3, 42);
+        let second = sample_solutions(board, &bricks, 3, 42);
+        let masks = |samples: Vec<SolvedBoard>| {
+            samples
+                .into_iter()
+                .map(|sample| sample.placed_bricks)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(masks(first), masks(second));
+    }
+
+    #[test]
+    fn random_solutions_vary_with_the_seed() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = Brick::all_bricks();
+        let pick = |seed| {
+            let mut placed = solve_random(board.clone(), &bricks, seed)
+                .unwrap()
+                .placed_bricks;
+            placed.sort_unstable();
+            placed
+        };
+        assert_eq!(pick(7), pick(7));
+        let picks: Set<Vec<u64>> = (0..10).map(pick).collect();
+        assert!(picks.len() > 1);
+    }
+
+    #[test]
+    fn samples_without_an_exact_cover_follow_the_seed() {
+        let board = Board::for_date(22, 9).unwrap();
+        let bricks = &Brick::all_bricks()[1..];
+        assert!(!board.is_exact_cover(bricks));
+        let picks: Set<Vec<u64>> = (0..5)
+            .map(|seed| {
+                sample_solutions(board.clone(), bricks, 1, seed)[0]
+                    .placed_bricks
+                    .clone()
+            })
+            .collect();
+        assert!(picks.len() > 1);
+    }
+
+    #[test]
+    fn stops_when_date_has_fewer_solutions() {
+        let board = Board::for_date(29, 7).unwrap();
+        let samples = sample_solutions(board, &Brick::all_bricks(), 1000, 1);
+        assert!(samples.len() < 1000);
+    }
+
+    #[test]
+    fn any_number_of_samples_can_be_asked_for() {
+        let board = Board::for_date(22, 9).unwrap();
+        // As many cells as the board has free, but too wide to go anywhere.
+        let bricks = [Brick::from_shape(!0 << 23)];
+        assert!(sample_solutions(board, &bricks, usize::MAX, 1).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use crate::COLUMN_0;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use core::fmt;
+
+/// Why a brick couldn't be made from ASCII art.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    Empty,
+    TooWide { width: usize },
+    TooTall { height: usize },
+    UnexpectedCharacter(char),
+    Disconnected,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::Empty => write!(f, "The shape has no cells"),
+            ShapeError::TooWide { width } => {
+                write!(f, "The shape is {width} cells wide, the board only 7")
+            }
+            ShapeError::TooTall { height } => {
+                write!(f, "The shape is {height} cells tall, the board only 7")
+            }
+            ShapeError::UnexpectedCharacter(character) => write!(
+                f,
+                "Unexpected `{character}` in shape, use X or # for cells and . or space for gaps"
+            ),
+            ShapeError::Disconnected => write!(f, "The cells of the shape aren't connected"),
+        }
+    }
+}
+
+impl core::error::Error for ShapeError {}
+
+// Rows of the shape, with X or # for cells and . or space for gaps, as a pattern in the top left
+// corner.
+pub(crate) fn parse_ascii(rows: &[&str]) -> Result<u64, ShapeError> {
+    let height = rows.len();
+    if height > 7 {
+        return Err(ShapeError::TooTall { height });
+    }
+    if let Some(width) = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .find(|&width| width > 7)
+    {
+        return Err(ShapeError::TooWide { width });
+    }
+    Ok(normalize(pattern_of(&parse_cells(rows)?)))
+}
+
+// The (row, column) of each cell in the rows, for boards of any width.
+pub(crate) fn parse_cells(rows: &[&str]) -> Result<Vec<(u32, u32)>, ShapeError> {
+    let mut cells = vec![];
+    for (row, line) in rows.iter().enumerate() {
+        for (column, character) in line.chars().enumerate() {
+            match character {
+                'X' | 'x' | '#' => cells.push((row as u32, column as u32)),
+                '.' | ' ' => {}
+                _ => return Err(ShapeError::UnexpectedCharacter(character)),
+            }
+        }
+    }
+    if cells.is_empty() {
+        return Err(ShapeError::Empty);
+    }
+    if !is_connected(&cells) {
+        return Err(ShapeError::Disconnected);
+    }
+    Ok(cells)
+}
+
+fn is_connected(cells: &[(u32, u32)]) -> bool {
+    let mut reached = vec![cells[0]];
+    let mut index = 0;
+    while let Some(&(row, column)) = reached.get(index) {
+        for &cell in cells {
+            if row.abs_diff(cell.0) + column.abs_diff(cell.1) == 1 && !reached.contains(&cell) {
+                reached.push(cell);
+            }
+        }
+        index += 1;
+    }
+    reached.len() == cells.len()
+}
+
+fn pattern_of(cells: &[(u32, u32)]) -> u64 {
+    cells.iter().fold(0, |pattern, (row, column)| {
+        pattern | 1u64 << 63 >> (row * 8 + column)
+    })
+}
+
+pub(crate) fn cells(pattern: u64) -> impl Iterator<Item = u64> {
+    (0..64)
+        .map(|index| 1u64 << 63 >> index)
+        .filter(move |cell| pattern & cell != 0)
+}
+
+// Moves the pattern to the top left corner.
+fn normalize(pattern: u64) -> u64 {
+    let mut pattern = pattern << (pattern.leading_zeros() / 8 * 8);
+    while pattern & COLUMN_0 == 0 {
+        pattern <<= 1;
+    }
+    pattern
+}
+
+// A pattern with every cell (row, column) moved to `transform(row, column)`.
+fn transformed(pattern: u64, transform: impl Fn(u32, u32) -> (u32, u32)) -> u64 {
+    normalize(cells(pattern).fold(0, |result, cell| {
+        let index = cell.leading_zeros();
+        let (row, column) = transform(index / 8, index % 8);
+        result | 1u64 << 63 >> (row * 8 + column)
+    }))
+}
+
+// The rotations and reflections of the pattern that fit on the board, in the top left corner.
+pub(crate) fn orientations(pattern: u64) -> Vec<u64> {
+    let cells: Vec<(u32, u32)> = cells(pattern)
+        .map(|cell| {
+            let index = cell.leading_zeros();
+            (index / 8, index % 8)
+        })
+        .collect();
+    cell_orientations(&cells)
+        .iter()
+        .filter(|cells| cells.iter().all(|&(_, column)| column < 7))
+        .map(|cells| pattern_of(cells))
+        .collect()
+}
+
+// The rotations and reflections of the cells, in the top left corner and sorted, without the
+// duplicates of symmetric shapes. Each quarter turn comes before its mirror image.
+pub(crate) fn cell_orientations(cells: &[(u32, u32)]) -> Vec<Vec<(u32, u32)>> {
+    let mut orientations: Vec<Vec<(u32, u32)>> = Vec::new();
+    let mut rotated: Vec<(i64, i64)> = cells
+        .iter()
+        .map(|&(row, column)| (row.into(), column.into()))
+        .collect();
+    for _ in 0..4 {
+        let reflected = rotated.iter().map(|&(row, column)| (row, -column));
+        for orientation in [
+            normalize_cells(rotated.iter().copied()),
+            normalize_cells(reflected),
+        ] {
+            if !orientations.contains(&orientation) {
+                orientations.push(orientation);
+            }
+        }
+        rotated = rotated
+            .iter()
+            .map(|&(row, column)| (column, -row))
+            .collect();
+    }
+    orientations
+}
+
+// The cells moved to the top left corner and sorted, so equal shapes compare equal.
+fn normalize_cells(cells: impl Iterator<Item = (i64, i64)>) -> Vec<(u32, u32)> {
+    let cells: Vec<(i64, i64)> = cells.collect();
+    let top = cells.iter().map(|(row, _)| *row).min().unwrap();
+    let left = cells.iter().map(|(_, column)| *column).min().unwrap();
+    let mut cells: Vec<(u32, u32)> = cells
+        .iter()
+        .map(|(row, column)| ((row - top) as u32, (column - left) as u32))
+        .collect();
+    cells.sort();
+    cells
+}
+
+pub(crate) fn canonical(pattern: u64) -> u64 {
+    orientations(pattern).into_iter().max().unwrap()
+}
+
+// Whether the placement is the shape turned over, rather than only rotated. Always false for shapes
+// looking the same turned over.
+pub(crate) fn is_reflected(shape: u64, placement: u64) -> bool {
+    let placement = normalize(placement);
+    let mut rotated = normalize(shape);
+    for _ in 0..4 {
+        if rotated == placement {
+            return false;
+        }
+        rotated = transformed(rotated, |row, column| (column, 7 - row));
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Brick;
+
+    #[test]
+    fn orientations_skip_symmetric_duplicates() {
+        let counts: Vec<usize> = Brick::all_bricks()
+            .iter()
+            .map(|brick| brick.brick_variants.len())
+            .collect();
+        assert_eq!(counts, [4, 2, 4, 4, 8, 8, 8, 8]);
+        let l_tetromino = orientations(0b10000000_10000000_11000000 << (5 * 8));
+        assert_eq!(l_tetromino.len(), 8);
+        assert!(l_tetromino.contains(&(0b11100000_10000000 << (6 * 8))));
+    }
+
+    #[test]
+    fn parse_ascii_shapes() {
+        let z = Brick::from_ascii(&[".XX", ".X.", "XX."]).unwrap();
+        assert_eq!(z.shape(), Brick::all_bricks()[0].shape());
+        assert_eq!(parse_ascii(&["..", ".#"]), Ok(1 << 63));
+        assert_eq!(parse_ascii(&["...", ""]), Err(ShapeError::Empty));
+        assert_eq!(
+            parse_ascii(&["XXXXXXXX"]),
+            Err(ShapeError::TooWide { width: 8 })
+        );
+        assert_eq!(
+            parse_ascii(&["X"; 8]),
+            Err(ShapeError::TooTall { height: 8 })
+        );
+        assert_eq!(
+            parse_ascii(&["XO"]),
+            Err(ShapeError::UnexpectedCharacter('O'))
+        );
+        assert_eq!(parse_ascii(&["X.X"]), Err(ShapeError::Disconnected));
+    }
+}
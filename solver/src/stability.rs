@@ -0,0 +1,82 @@
+use crate::{Board, Brick, solve};
+use std::collections::HashMap;
+
+/// A brick placement and the dates with a solution using it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StablePlacement {
+    pub brick: usize,
+    pub placement: u64,
+    /// As (day, month), in the order given.
+    pub dates: Vec<(u8, u8)>,
+}
+
+// Every placement that is part of some solution for at least one of the dates, the ones working
+// for the most dates first. Placements working equally often keep the order of the bricks, then
+// the placements from the top left. Dates the board can't show are skipped.
+pub fn placement_stability(dates: &[(u8, u8)], bricks: &[Brick]) -> Vec<StablePlacement> {
+    let mut by_placement: HashMap<(usize, u64), Vec<(u8, u8)>> = HashMap::new();
+    for &(day, month) in dates {
+        let Ok(board) = Board::for_date(day, month) else {
+            continue;
+        };
+        let mut used: Vec<(usize, u64)> = solve(board, bricks)
+            .flat_map(|solution| solution.bricks.into_iter().zip(solution.placed_bricks))
+            .collect();
+        used.sort_unstable();
+        used.dedup();
+        for placement in used {
+            by_placement
+                .entry(placement)
+                .or_default()
+                .push((day, month));
+        }
+    }
+    let mut stable: Vec<StablePlacement> = by_placement
+        .into_iter()
+        .map(|((brick, placement), dates)| StablePlacement {
+            brick,
+            placement,
+            dates,
+        })
+        .collect();
+    stable.sort_by_key(|stable| {
+        (
+            std::cmp::Reverse(stable.dates.len()),
+            stable.brick,
+            std::cmp::Reverse(stable.placement),
+        )
+    });
+    stable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placements_shared_by_dates() {
+        let bricks = Brick::all_bricks();
+        let dates = [(22, 9), (23, 9), (32, 1)];
+        let stable = placement_stability(&dates, &bricks);
+        assert!(stable[0].dates.len() == 2);
+        assert!(stable.iter().all(|stable| !stable.dates.is_empty()));
+        assert!(
+            stable
+                .windows(2)
+                .all(|pair| pair[0].dates.len() >= pair[1].dates.len())
+        );
+        for stable in &stable {
+            let (day, month) = stable.dates[0];
+            let board = Board::for_date(day, month).unwrap();
+            assert!(solve(board, &bricks).any(|solution| {
+                solution
+                    .bricks
+                    .iter()
+                    .zip(&solution.placed_bricks)
+                    .any(|(&brick, &placement)| {
+                        brick == stable.brick && placement == stable.placement
+                    })
+            }));
+        }
+    }
+}
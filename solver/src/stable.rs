@@ -0,0 +1,46 @@
+//! The part of the API that keeps working from one release to the next: making boards and bricks,
+//! solving and hints. Changing any of it waits for a major release, and even then the old form
+//! stays for a release as a deprecated shim.
+//!
+//! Everything else is in [`crate::experimental`] while it settles. Both are also at the crate
+//! root, so depending on `solver::stable` only matters for knowing what may change.
+
+pub use crate::{
+    Board, Brick, Hint, SearchProfile, SearchStats, ShapeError, SolvedBoard, SolverError,
+    count_solutions, hints, solve, solve_with_profile,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{boxed::Box, string::String, string::ToString};
+
+    #[test]
+    #[allow(deprecated)]
+    fn shims_match_the_current_api() {
+        let bricks = Brick::all_bricks();
+        let board = Board::for_date(22, 9).unwrap();
+        let solution = solve(board, &bricks).next().unwrap();
+        assert_eq!(solution.test_count(), solution.stats.test_count);
+    }
+
+    // Written the way code using the first releases was, which has to keep compiling.
+    #[test]
+    fn old_signatures_still_compile() {
+        fn first_solution(day: u8, month: u8) -> Result<SolvedBoard, String> {
+            let board = Board::for_date(day, month)?;
+            let bricks = Brick::all_bricks();
+            let mut solutions: Box<dyn Iterator<Item = SolvedBoard>> =
+                solve_with_profile(board, &bricks, SearchProfile::Interactive);
+            solutions.next().ok_or_else(|| "No solution".into())
+        }
+        assert!(first_solution(22, 9).is_ok());
+        assert_eq!(
+            first_solution(32, 9).err(),
+            Some(SolverError::InvalidDay(32).to_string())
+        );
+    }
+}
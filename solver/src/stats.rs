@@ -0,0 +1,50 @@
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Counters of a search, as they were when the solution they come with was found.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Boards tested, one per node of the search tree.
+    pub test_count: u64,
+    /// Boards tested that were no solution and had no placement left to try.
+    pub backtracks: u64,
+    /// The most bricks placed on a board tested.
+    pub max_depth: usize,
+    /// Placements that fit on the boards tested, before pruning.
+    pub placements_generated: u64,
+    /// Placements dropped by filters or for leaving a region no remaining bricks can fill.
+    pub prunes: u64,
+    /// Boards found to lead to no solution, remembered so the search skips them when it reaches
+    /// the same cells covered by the same bricks in another way.
+    pub dead_states: u64,
+    /// Boards skipped for being one of the dead states.
+    pub dead_state_hits: u64,
+    /// Time since the search started.
+    pub elapsed: Duration,
+    /// Time since the previous solution, or since the start for the first one.
+    pub solution_time: Duration,
+}
+
+// Time since a search started. Without std there is no clock, and no time passes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(feature = "std")]
+    started: Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Stopwatch {
+        Stopwatch {
+            #[cfg(feature = "std")]
+            started: Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(feature = "std")]
+        return self.started.elapsed();
+        #[cfg(not(feature = "std"))]
+        Duration::ZERO
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Board, Brick, SearchStats, SolvedBoard, all_dates};
+
+// Written by build.rs: for each date in the order of `all_dates` the number of solutions before
+// it, as 367 little endian u32s, then the solutions. A solution is the index of the brick covering
+// each free cell of the date board, top to bottom, three bits each in a little endian u128.
+static TABLE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/solutions.bin"));
+const DATES: usize = 366;
+const SOLUTION_BYTES: usize = 16;
+const BRICKS: usize = 8;
+
+fn offset(date_index: usize) -> usize {
+    let at = date_index * 4;
+    u32::from_le_bytes(TABLE[at..at + 4].try_into().unwrap()) as usize
+}
+
+// Where the date of the board is in the table, if it is a date board with nothing placed yet and
+// the bricks are the standard set the table was built with.
+fn date_index(board: &Board, bricks: &[Brick]) -> Option<usize> {
+    let standard = Brick::all_bricks();
+    let same_bricks = bricks.len() == standard.len()
+        && bricks
+            .iter()
+            .zip(&standard)
+            .all(|(a, b)| a.shape() == b.shape());
+    if !same_bricks || !board.placed_bricks.is_empty() {
+        return None;
+    }
+    all_dates().position(|(month, day)| {
+        Board::for_date(day, month).is_ok_and(|date_board| date_board.bitboard == board.bitboard)
+    })
+}
+
+pub(crate) fn count(board: &Board, bricks: &[Brick]) -> Option<usize> {
+    let index = date_index(board, bricks)?;
+    Some(offset(index + 1) - offset(index))
+}
+
+/// The solutions of the date from the table built into the crate with the `table` feature, or
+/// None when the table doesn't have the board and bricks. They come in the order the table was
+/// built in, not the order [`solve`](crate::solve) finds them in, and their stats are zero.
+pub fn table_solutions(
+    board: &Board,
+    bricks: &[Brick],
+) -> Option<impl Iterator<Item = SolvedBoard>> {
+    let index = date_index(board, bricks)?;
+    let free_cells: Vec<u32> = (0..64).filter(|&cell| board.is_free(cell as u8)).collect();
+    let start = (DATES + 1) * 4;
+    let solutions =
+        &TABLE[start + offset(index) * SOLUTION_BYTES..start + offset(index + 1) * SOLUTION_BYTES];
+    Some(solutions.chunks(SOLUTION_BYTES).map(move |bytes| {
+        let mut bits = u128::from_le_bytes(bytes.try_into().unwrap());
+        let mut placed_bricks = vec![0; BRICKS];
+        for cell in &free_cells {
+            placed_bricks[(bits & 0b111) as usize] |= 1 << 63 >> cell;
+            bits >>= 3;
+        }
+        SolvedBoard {
+            bricks: (0..BRICKS).collect(),
+            placed_bricks,
+            stats: SearchStats::default(),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn table_matches_search() {
+        let bricks = Brick::all_bricks();
+        for (day, month) in [(1, 1), (29, 2), (22, 9), (31, 12)] {
+            let board = Board::for_date(day, month).unwrap();
+            // Each solution as its placements in brick order, since the searches place the bricks
+            // in different orders.
+            let by_brick = |solution: SolvedBoard| {
+                let mut placements: Vec<(usize, u64)> = solution
+                    .bricks
+                    .into_iter()
+                    .zip(solution.placed_bricks)
+                    .collect();
+                placements.sort_unstable();
+                placements
+            };
+            let mut searched: Vec<_> = solve(board.clone(), &bricks).map(by_brick).collect();
+            let mut looked_up: Vec<_> = table_solutions(&board, &bricks)
+                .unwrap()
+                .map(by_brick)
+                .collect();
+            searched.sort_unstable();
+            looked_up.sort_unstable();
+            assert_eq!(looked_up, searched);
+            assert_eq!(count(&board, &bricks), Some(searched.len()));
+        }
+    }
+}
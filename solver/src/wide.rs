@@ -0,0 +1,290 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::shape::{cell_orientations, parse_cells};
+use crate::{ShapeError, SolverError};
+use core::fmt::Debug;
+use core::ops::{BitAnd, BitOr, Not, Shr};
+
+/// An unsigned integer used as a bitboard, so boards can be as big as the word allows.
+pub trait BitWord:
+    Copy
+    + Eq
+    + Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const BITS: u32;
+    const ZERO: Self;
+    /// Only the most significant bit set, the top left cell.
+    const TOP: Self;
+    fn leading_zeros(self) -> u32;
+    fn count_ones(self) -> u32;
+}
+
+macro_rules! impl_bit_word {
+    ($($word:ty),*) => {$(
+        impl BitWord for $word {
+            const BITS: u32 = <$word>::BITS;
+            const ZERO: Self = 0;
+            const TOP: Self = 1 << (<$word>::BITS - 1);
+            fn leading_zeros(self) -> u32 {
+                self.leading_zeros()
+            }
+            fn count_ones(self) -> u32 {
+                self.count_ones()
+            }
+        }
+    )*};
+}
+
+impl_bit_word!(u64, u128);
+
+/// A rectangular board of any size fitting in the word, like the 6x10 pentomino rectangle in a
+/// `u128`. Each row gets one extra blocked cell at the end so bricks can't wrap to the next row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WideBoard<W> {
+    width: u32,
+    height: u32,
+    bitboard: W,
+}
+
+pub type Board128 = WideBoard<u128>;
+
+impl<W: BitWord> WideBoard<W> {
+    pub fn new(width: u32, height: u32) -> Result<WideBoard<W>, SolverError> {
+        if width == 0 || height == 0 {
+            return Err(SolverError::NoFreeCells);
+        }
+        if (width + 1) * height > W::BITS {
+            return Err(SolverError::BoardTooLarge {
+                width,
+                height,
+                bits: W::BITS,
+            });
+        }
+        let mut bitboard = W::ZERO;
+        for index in 0..W::BITS {
+            let (row, column) = (index / (width + 1), index % (width + 1));
+            if row >= height || column == width {
+                bitboard = bitboard | W::TOP >> index;
+            }
+        }
+        Ok(WideBoard {
+            width,
+            height,
+            bitboard,
+        })
+    }
+
+    /// Blocks a cell, so no brick can cover it.
+    pub fn block(&mut self, row: u32, column: u32) -> Result<(), SolverError> {
+        if row >= self.height || column >= self.width {
+            return Err(SolverError::InvalidCell { row, column });
+        }
+        self.bitboard = self.bitboard | W::TOP >> (row * (self.width + 1) + column);
+        Ok(())
+    }
+
+    pub fn free_cells(&self) -> u32 {
+        (!self.bitboard).count_ones()
+    }
+
+    /// The (row, column) of each cell in a placement.
+    pub fn cells(&self, placement: W) -> Vec<(u32, u32)> {
+        (0..W::BITS)
+            .filter(|index| placement & W::TOP >> *index != W::ZERO)
+            .map(|index| (index / (self.width + 1), index % (self.width + 1)))
+            .collect()
+    }
+}
+
+/// A brick for a [`WideBoard`], in every rotation and reflection.
+#[derive(Debug, Clone)]
+pub struct WideBrick<W> {
+    variants: Vec<W>,
+}
+
+impl<W: BitWord> WideBrick<W> {
+    /// Like [`crate::Brick::from_ascii`], for a board of the given width.
+    pub fn from_ascii(rows: &[&str], board: &WideBoard<W>) -> Result<WideBrick<W>, ShapeError> {
+        let cells = parse_cells(rows)?;
+        let orientations = cell_orientations(&cells);
+        let stride = board.width + 1;
+        let variants: Vec<W> = orientations
+            .into_iter()
+            .filter(|cells| {
+                cells
+                    .iter()
+                    .all(|&(row, column)| row < board.height && column < board.width)
+            })
+            .map(|cells| {
+                cells.iter().fold(W::ZERO, |pattern, (row, column)| {
+                    pattern | W::TOP >> (row * stride + column)
+                })
+            })
+            .collect();
+        match variants.is_empty() {
+            true if rows.len() as u32 > board.height => {
+                Err(ShapeError::TooTall { height: rows.len() })
+            }
+            true => Err(ShapeError::TooWide {
+                width: rows
+                    .iter()
+                    .map(|row| row.chars().count())
+                    .max()
+                    .unwrap_or(0),
+            }),
+            false => Ok(WideBrick { variants }),
+        }
+    }
+
+    fn area(&self) -> u32 {
+        self.variants[0].count_ones()
+    }
+}
+
+/// Every way to cover all free cells of the board with the bricks, as the placements in the
+/// order of the bricks. Like the main solver, each step covers the first free cell, so the bricks
+/// have to cover exactly the free cells.
+pub fn solve_wide<'a, W: BitWord>(
+    board: &WideBoard<W>,
+    bricks: &'a [WideBrick<W>],
+) -> Result<impl Iterator<Item = Vec<W>> + 'a, SolverError> {
+    assert!(bricks.len() <= 32, "At most 32 bricks are supported");
+    let area = bricks.iter().map(WideBrick::area).sum::<u32>();
+    if area != board.free_cells() {
+        return Err(SolverError::AreaMismatch {
+            bricks: area,
+            free: board.free_cells(),
+        });
+    }
+    let all_bricks = ((1u64 << bricks.len()) - 1) as u32;
+    let mut stack = vec![(board.bitboard, all_bricks, vec![W::ZERO; bricks.len()])];
+    Ok(core::iter::from_fn(move || {
+        while let Some((bitboard, remaining, placements)) = stack.pop() {
+            if remaining == 0 {
+                return Some(placements);
+            }
+            let anchor = (!bitboard).leading_zeros();
+            let mut children = vec![];
+            for (brick_index, brick) in bricks.iter().enumerate() {
+                if remaining & (1 << brick_index) == 0 {
+                    continue;
+                }
+                for variant in &brick.variants {
+                    let first_cell = variant.leading_zeros();
+                    if first_cell > anchor {
+                        continue;
+                    }
+                    let placement = *variant >> (anchor - first_cell);
+                    if placement.count_ones() == variant.count_ones()
+                        && bitboard & placement == W::ZERO
+                    {
+                        let mut placements = placements.clone();
+                        placements[brick_index] = placement;
+                        children.push((
+                            bitboard | placement,
+                            remaining & !(1 << brick_index),
+                            placements,
+                        ));
+                    }
+                }
+            }
+            stack.extend(children.into_iter().rev());
+        }
+        None
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PENTOMINOES: [&[&str]; 12] = [
+        &["XXXXX"],
+        &["XXXX", "X..."],
+        &["XXXX", ".X.."],
+        &["XXX.", "..XX"],
+        &["XXX", "XX."],
+        &["XXX", "X.X"],
+        &["XXX", ".X.", ".X."],
+        &["XXX", "X..", "X.."],
+        &[".XX", ".X.", "XX."],
+        &["XX.", ".XX", "..X"],
+        &[".X.", "XXX", ".X."],
+        &[".XX", "XX.", ".X."],
+    ];
+
+    #[test]
+    fn pentomino_rectangle_6x10() {
+        let mut board = Board128::new(10, 6).unwrap();
+        // Most of a known solution, leaving room for the last four pentominoes.
+        let placed = [
+            "XXXXXXXXXX",
+            "XXX..XXXXX",
+            "XX....X..X",
+            "X....XXX.X",
+            "X....XXX..",
+            "XXX.XXXXXX",
+        ];
+        for (row, line) in placed.iter().enumerate() {
+            for (column, cell) in line.chars().enumerate() {
+                if cell == 'X' {
+                    board.block(row as u32, column as u32).unwrap();
+                }
+            }
+        }
+        let bricks: Vec<WideBrick<u128>> = PENTOMINOES[8..]
+            .iter()
+            .map(|rows| WideBrick::from_ascii(rows, &board).unwrap())
+            .collect();
+        let solutions: Vec<Vec<u128>> = solve_wide(&board, &bricks).unwrap().collect();
+        assert!(!solutions.is_empty());
+        assert_eq!(board.cells(solutions[0][2]).len(), 5);
+    }
+
+    #[test]
+    fn standard_puzzle_on_a_wide_board() {
+        let mut board = WideBoard::<u64>::new(7, 7).unwrap();
+        for (row, column) in [
+            (0, 6),
+            (1, 6),
+            (6, 3),
+            (6, 4),
+            (6, 5),
+            (6, 6),
+            (0, 0),
+            (2, 0),
+        ] {
+            board.block(row, column).unwrap();
+        }
+        let bricks: Vec<WideBrick<u64>> = crate::Brick::all_bricks()
+            .iter()
+            .map(|brick| {
+                let rows: Vec<String> = brick
+                    .shape()
+                    .to_be_bytes()
+                    .iter()
+                    .map(|row| {
+                        (0..8)
+                            .map(|x| if row & 0x80 >> x != 0 { 'X' } else { '.' })
+                            .collect()
+                    })
+                    .collect();
+                let rows: Vec<&str> = rows.iter().map(String::as_str).collect();
+                WideBrick::from_ascii(&rows, &board).unwrap()
+            })
+            .collect();
+        assert_eq!(solve_wide(&board, &bricks).unwrap().count(), 64);
+        assert!(Board128::new(11, 11).is_err());
+        assert_eq!(
+            solve_wide(&board, &bricks[1..]).err(),
+            Some(SolverError::AreaMismatch {
+                bricks: 36,
+                free: 41
+            })
+        );
+    }
+}